@@ -0,0 +1,108 @@
+// Extracts the regions of the pages which are shown as the source of their examples,
+// marked with comments:
+//
+//     // #region simple
+//     button::standard(text::body1("Standard button")),
+//     // #endregion
+//
+// Each page with regions gets a module of `page::source` with a constant per region,
+// such as `source::button::SIMPLE`. The code is dedented and a trailing comma is
+// dropped, as regions usually wrap an argument of `widget_example`.
+
+use std::{env, fmt::Write, fs, path::Path};
+
+const PAGES: &str = "src/page";
+
+fn main() {
+    println!("cargo::rerun-if-changed={PAGES}");
+
+    let mut paths: Vec<_> = fs::read_dir(PAGES)
+        .expect("failed to read the pages")
+        .map(|entry| entry.expect("failed to read the pages").path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "rs"))
+        .collect();
+    paths.sort();
+
+    let mut out = String::new();
+
+    for path in paths {
+        let source = fs::read_to_string(&path).expect("failed to read a page");
+        let regions = regions(&source, &path);
+
+        if regions.is_empty() {
+            continue;
+        }
+
+        let module = path.file_stem().unwrap().to_string_lossy();
+
+        writeln!(out, "pub mod {module} {{").unwrap();
+
+        for (name, code) in regions {
+            writeln!(
+                out,
+                "    pub const {}: &str = {code:?};",
+                name.to_uppercase()
+            )
+            .unwrap();
+        }
+
+        writeln!(out, "}}").unwrap();
+    }
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    fs::write(Path::new(&out_dir).join("source.rs"), out).expect("failed to write source.rs");
+}
+
+fn regions(source: &str, path: &Path) -> Vec<(String, String)> {
+    let mut regions = Vec::new();
+    let mut current: Option<(String, Vec<&str>)> = None;
+
+    for (index, line) in source.lines().enumerate() {
+        let location = format!("{}:{}", path.display(), index + 1);
+
+        if let Some(name) = line.trim().strip_prefix("// #region ") {
+            assert!(current.is_none(), "{location}: regions can't be nested");
+            assert!(
+                name.chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_'),
+                "{location}: region names are snake case"
+            );
+
+            current = Some((name.to_owned(), Vec::new()));
+        } else if line.trim() == "// #endregion" {
+            let (name, lines) = current
+                .take()
+                .unwrap_or_else(|| panic!("{location}: #endregion without a #region"));
+
+            regions.push((name, dedent(&lines)));
+        } else if let Some((_, lines)) = &mut current {
+            lines.push(line);
+        }
+    }
+
+    if let Some((name, _)) = current {
+        panic!("{}: region {name} isn't closed", path.display());
+    }
+
+    regions
+}
+
+fn dedent(lines: &[&str]) -> String {
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    let code = lines
+        .iter()
+        .map(|line| line.get(indent..).unwrap_or_default().trim_end())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    match code.strip_suffix(',') {
+        Some(code) => code.to_owned(),
+        None => code,
+    }
+}
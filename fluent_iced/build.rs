@@ -0,0 +1,918 @@
+// Generates the `FluentIcon` enum from an icon list in the format of the WinUI Gallery one:
+// https://github.com/microsoft/WinUI-Gallery/blob/main/WinUIGallery/DataModel/IconsData.json
+//
+// assets/IconsData.json is not that file yet: it was rebuilt from the old hand-written enum,
+// so its tags are just the words of each name. The words of the Fluent UI System Icons
+// counterpart are added to them below, but the upstream file, which can replace it as is,
+// is still needed for the curated tags.
+//
+// Each icon is also matched by name to its counterpart in Fluent UI System Icons, the
+// bundled fallback font, using the aliases, synonyms, qualifiers and prefixes below. Many
+// matches are the closest icon rather than the same one, such as a battery level for each
+// of the phone, band and vertical battery glyphs. Icons without one render as boxes when
+// Segoe Fluent Icons isn't installed.
+//
+// Lookups go through a single table instead of one match arm per icon so the generated
+// file stays small enough for editors to cope with.
+
+use std::{
+    collections::{HashMap, HashSet},
+    env,
+    fmt::Write,
+    fs,
+    path::Path,
+};
+
+use serde_json::Value;
+
+const ICONS_DATA: &str = "assets/IconsData.json";
+const SYSTEM_ICONS_DATA: &str = "assets/fonts/FluentSystemIcons-Regular.json";
+
+// Sizes Fluent UI System Icons come in, by preference
+const SYSTEM_ICON_SIZES: [u32; 7] = [20, 16, 24, 28, 32, 48, 12];
+
+// Segoe Fluent Icons with a differently named counterpart, by IconsData.json name
+const ALIASES: &[(&str, &str)] = &[
+    ("Back", "arrow_left"),
+    ("ButtonA", "button"),
+    ("Cancel", "dismiss"),
+    ("CheckMark", "checkmark"),
+    ("Checkbox", "checkbox_unchecked"),
+    ("CheckboxComposite", "checkbox_checked"),
+    ("CheckboxIndeterminate", "checkbox_indeterminate"),
+    ("ChevronDownMed", "chevron_down"),
+    ("ChevronLeftMed", "chevron_left"),
+    ("ChevronRightMed", "chevron_right"),
+    ("ChevronUpMed", "chevron_up"),
+    ("DashKey", "subtract"),
+    ("Design", "design_ideas"),
+    ("Draw", "inking_tool"),
+    ("Emoji2", "emoji"),
+    ("Equalizer", "options"),
+    ("Font", "text_font"),
+    ("Forward", "arrow_right"),
+    ("GlobalNavButton", "navigation"),
+    ("Like", "thumb_like"),
+    ("MailReply", "arrow_reply"),
+    ("MailReplyAll", "arrow_reply_all"),
+    ("Message", "chat"),
+    ("OpenFile", "folder_open"),
+    ("OpenPane", "panel_left_expand"),
+    ("PageMarginLandscapeNormal", "document_landscape"),
+    ("Paste", "clipboard_paste"),
+    ("Personalize", "paint_brush"),
+    ("Picture", "image"),
+    ("RadioBtnOn", "radio_button"),
+    ("RadioBullet", "circle"),
+    ("Refresh", "arrow_clockwise"),
+    ("Reply", "arrow_reply"),
+    ("Ringer", "alert"),
+    ("Slideshow", "slide_play"),
+    ("Sync", "arrow_sync"),
+    ("Tiles", "grid"),
+    ("ToggleFilled", "toggle_right"),
+    ("ViewAll", "apps"),
+];
+
+// Words of Segoe Fluent Icons names and what Fluent UI System Icons calls them instead
+const SYNONYMS: &[(&str, &str)] = &[
+    ("accept", "checkmark"),
+    ("accident", "warning"),
+    ("accounts", "people"),
+    ("action_center", "alert"),
+    ("add_friend", "person_add"),
+    ("add_remote_device", "phone_add"),
+    ("add_surface_hub", "board"),
+    ("add_to", "add"),
+    ("adjust_hologram", "cube"),
+    ("admin", "person"),
+    ("align_center", "text_align_center"),
+    ("all_apps", "apps"),
+    ("annotate", "inking_tool"),
+    ("annotation", "note"),
+    ("application_guard", "shield"),
+    ("area_chart", "data_area"),
+    ("aspect_ratio", "ratio_one_to_one"),
+    ("attach_camera", "camera_add"),
+    ("audio", "speaker_2"),
+    ("back", "arrow_left"),
+    ("back_to_window", "full_screen_minimize"),
+    ("background_toggle", "image"),
+    ("bank", "building_bank"),
+    ("beta", "beaker"),
+    ("bidi_ltr", "text_direction_horizontal_ltr"),
+    ("block_contact", "person_prohibited"),
+    ("blocked", "prohibited"),
+    ("blue_light", "weather_moon"),
+    ("body_cam", "camera"),
+    ("bold", "text_bold"),
+    ("bookmarks", "bookmark"),
+    ("brightness", "brightness_high"),
+    ("browse_photos", "image_multiple"),
+    ("brush_size", "line_thickness"),
+    ("building_energy", "building"),
+    ("bulleted_list", "text_bullet_list"),
+    ("bullseye", "target"),
+    ("bus", "vehicle_bus"),
+    ("button_menu", "navigation"),
+    ("button_view", "window"),
+    ("cafe", "drink_coffee"),
+    ("calculator_addition", "add"),
+    ("calculator_backspace", "backspace"),
+    ("calculator_divide", "calculator"),
+    ("calculator_equal_to", "calculator"),
+    ("calculator_multiply", "dismiss"),
+    ("calculator_negate", "calculator"),
+    ("calculator_percentage", "calculator"),
+    ("calculator_squareroot", "calculator"),
+    ("calculator_subtract", "subtract"),
+    ("calendar_week", "calendar_work_week"),
+    ("call_control", "call"),
+    ("call_forwarding", "call_forward"),
+    ("calligraphy", "calligraphy_pen"),
+    ("cancel", "dismiss"),
+    ("caption", "closed_caption"),
+    ("car", "vehicle_car"),
+    ("cash_drawer", "money"),
+    ("cell_phone", "phone"),
+    ("character_appearance", "text_font"),
+    ("characters", "text_font"),
+    ("charging", "charge"),
+    ("chat_bubbles", "chat_multiple"),
+    ("checklist", "task_list_ltr"),
+    ("chip_card_credit_card_reader", "payment"),
+    ("circle_ring", "circle"),
+    ("circle_shape", "circle"),
+    ("city_next", "building_skyscraper"),
+    ("clear", "dismiss"),
+    ("clear_all_ink", "ink_stroke"),
+    ("clear_selection", "select_all_off"),
+    ("click", "cursor_click"),
+    ("clipping_tool", "cut"),
+    ("close", "dismiss"),
+    ("cloud_download", "cloud_arrow_down"),
+    ("cloud_printer", "print"),
+    ("cloud_search", "cloud"),
+    ("collapse_content", "arrow_minimize"),
+    ("command_prompt", "window_console"),
+    ("communications", "chat"),
+    ("companion_app", "phone_laptop"),
+    ("completed", "checkmark_circle"),
+    ("component", "puzzle_piece"),
+    ("connect", "plug_connected"),
+    ("construction", "warning"),
+    ("construction_cone", "warning"),
+    ("contact", "person"),
+    ("contact_presence", "presence_available"),
+    ("copy_to", "copy"),
+    ("courthouse", "gavel"),
+    ("data_sense", "data_usage"),
+    ("data_transfer", "arrow_swap"),
+    ("date", "calendar"),
+    ("decline_call", "call_end"),
+    ("default_apn", "globe"),
+    ("defender", "shield"),
+    ("delete_word", "text_clear_formatting"),
+    ("delivery_optimization", "arrow_download"),
+    ("detachable_pc", "tablet"),
+    ("dev_update", "arrow_sync"),
+    ("developer_tools", "wrench"),
+    ("device_discovery", "search"),
+    ("devices", "phone_laptop"),
+    ("diagnostic", "pulse"),
+    ("dial", "dialpad"),
+    ("dial_up", "call"),
+    ("dictionary", "book"),
+    ("dictionary_cloud", "book"),
+    ("direct_access", "globe"),
+    ("disconnect_display", "plug_disconnected"),
+    ("disconnect_drive", "plug_disconnected"),
+    ("dislike", "thumb_dislike"),
+    ("dock_bottom", "panel_bottom"),
+    ("dock_left", "panel_left"),
+    ("dock_right", "panel_right"),
+    ("document_approval", "document_checkmark"),
+    ("down", "arrow_down"),
+    ("download", "arrow_download"),
+    ("download_map", "map"),
+    ("dpad", "games"),
+    ("driving_mode", "vehicle_car"),
+    ("dual_sim", "sim"),
+    ("dull_sound", "speaker_0"),
+    ("dynamic_lock", "lock_closed"),
+    ("e_sim", "sim"),
+    ("earbud", "headphones"),
+    ("ease_of_access", "accessibility"),
+    ("education", "hat_graduation"),
+    ("eject", "arrow_eject"),
+    ("emoji_swatch", "emoji"),
+    ("end_point", "location"),
+    ("erase", "eraser"),
+    ("error", "error_circle"),
+    ("ethernet", "plug_connected"),
+    ("ethernet_error", "plug_disconnected"),
+    ("ethernet_warning", "plug_disconnected"),
+    ("event", "calendar"),
+    ("expand_tile", "arrow_maximize"),
+    ("exploit_protection", "shield"),
+    ("explore_content", "arrow_maximize"),
+    ("export", "arrow_export"),
+    ("expressive_input_entry", "emoji"),
+    ("eye_gaze", "eye_tracking"),
+    ("family", "people"),
+    ("favicon", "globe"),
+    ("favorite_list", "star_line_horizontal_3"),
+    ("feedback", "person_feedback"),
+    ("ferry", "vehicle_ship"),
+    ("file_explorer", "folder"),
+    ("finger_inking", "inking_tool"),
+    ("fit_page", "page_fit"),
+    ("folder_select", "folder_open"),
+    ("font_color", "text_color"),
+    ("font_size", "text_font_size"),
+    ("forward", "arrow_right"),
+    ("four_bars", "cellular_data_4"),
+    ("free_form_clipping", "cut"),
+    ("frigid", "weather_snowflake"),
+    ("full_circle_mask", "circle"),
+    ("full_screen", "full_screen_maximize"),
+    ("fuzzy_reading", "reading_mode_mobile"),
+    ("game", "games"),
+    ("game_console", "xbox_console"),
+    ("gateway_router", "router"),
+    ("generic_scan", "scan"),
+    ("giftbox_open", "gift_open"),
+    ("go", "arrow_right"),
+    ("go_to_message", "chat"),
+    ("grid_view", "grid"),
+    ("gripper", "re_order_dots_vertical"),
+    ("groceries", "food_grains"),
+    ("guest_user", "person"),
+    ("handwriting", "inking_tool"),
+    ("hang_up", "call_end"),
+    ("headless_device", "server"),
+    ("headphone", "headphones"),
+    ("health", "heart_pulse"),
+    ("help", "question_circle"),
+    ("hide", "eye_off"),
+    ("hmd", "headset_vr"),
+    ("holo_lens", "headset_vr"),
+    ("home_group", "home_person"),
+    ("horizontal_tab_key", "keyboard_tab"),
+    ("id_badge", "contact_card"),
+    ("image_export", "image_arrow_forward"),
+    ("import", "arrow_import"),
+    ("in_private", "tab_in_private"),
+    ("incident_triangle", "warning"),
+    ("incoming_call", "call_inbound"),
+    ("input", "keyboard"),
+    ("insider_hub", "person_star"),
+    ("interactive_dashboard", "board"),
+    ("internet_sharing", "share"),
+    ("italic", "text_italic"),
+    ("keyboard_brightness", "brightness_high"),
+    ("keyboard_classic", "keyboard"),
+    ("keyboard_dismiss", "keyboard"),
+    ("keyboard_left_handed", "keyboard_layout_one_handed_left"),
+    ("keyboard_narrow", "keyboard"),
+    ("keyboard_one_handed", "keyboard_layout_one_handed_left"),
+    ("keyboard_settings", "keyboard"),
+    ("keyboard_shortcut", "keyboard"),
+    ("keyboard_split", "keyboard_layout_split"),
+    ("keyboard_standard", "keyboard"),
+    ("keyboard_undock", "keyboard_layout_float"),
+    ("keyboardsettings", "keyboard"),
+    ("knowledge_article", "book_information"),
+    ("label", "tag"),
+    ("landscape_orientation", "document_landscape"),
+    ("laptop_secure", "laptop_shield"),
+    ("large_erase", "eraser"),
+    ("leaf", "leaf_one"),
+    ("leave_chat", "chat_dismiss"),
+    ("led_light", "lightbulb"),
+    ("left", "arrow_left"),
+    ("left_stick", "games"),
+    ("lexicon", "book"),
+    ("light", "lightbulb"),
+    ("lightning_bolt", "flash"),
+    ("line_display", "text_description"),
+    ("locale_language", "local_language"),
+    ("lock", "lock_closed"),
+    ("lock_feedback", "lock_closed"),
+    ("lock_screen_glance", "lock_closed"),
+    ("lower_brightness", "brightness_low"),
+    ("mag_stripe_reader", "payment"),
+    ("mail_forward", "mail_arrow_forward"),
+    ("manage", "settings"),
+    ("map_directions", "directions"),
+    ("map_layers", "layer"),
+    ("map_pin", "location"),
+    ("marker", "pen"),
+    ("market", "shopping_bag"),
+    ("marquee", "select_object"),
+    ("media", "video_clip"),
+    ("media_storage_tower", "server"),
+    ("memo", "note"),
+    ("merge_call", "call_add"),
+    ("mic_clipping", "mic"),
+    ("mic_error", "mic_prohibited"),
+    ("mic_sleep", "mic_off"),
+    ("microphone", "mic"),
+    ("microphone_listening", "mic_pulse"),
+    ("mini_contract", "arrow_minimize"),
+    ("mini_expand", "arrow_maximize"),
+    ("minimize", "subtract"),
+    ("miracast", "cast"),
+    ("mix_volumes", "speaker_2"),
+    ("mixed_media", "video_clip"),
+    ("mobile", "phone"),
+    ("mobile_locked", "phone_lock"),
+    ("more", "more_horizontal"),
+    ("mouse", "cursor"),
+    ("move", "arrow_move"),
+    ("movies", "movies_and_tv"),
+    ("multi_select", "multiselect_ltr"),
+    ("music_album", "album"),
+    ("music_info", "music_note_1"),
+    ("music_note", "music_note_1"),
+    ("music_sharing", "music_note_1"),
+    ("mute", "speaker_mute"),
+    ("my_network", "globe"),
+    ("narrator", "person_voice"),
+    ("narrator_forward", "person_voice"),
+    ("nearby_sharing", "share"),
+    ("network", "network_check"),
+    ("network_offline", "globe_off"),
+    ("network_printer", "print"),
+    ("network_sharing", "share"),
+    ("network_tower", "cellular_data_1"),
+    ("new_folder", "folder_add"),
+    ("new_window", "window_new"),
+    ("noise_cancelation", "headphones_sound_wave"),
+    ("nui_face", "person"),
+    ("nui_iris", "eye"),
+    ("one_bar", "cellular_data_1"),
+    ("one_handed_left", "keyboard_layout_one_handed_left"),
+    ("one_handed_right", "keyboard_layout_one_handed_left"),
+    ("open_in_new_window", "window_new"),
+    ("open_with", "open"),
+    ("other_user", "person"),
+    ("package", "box"),
+    ("page", "document"),
+    ("pan_mode", "hand_left"),
+    ("parking_location", "vehicle_car"),
+    ("party_leader", "crown"),
+    ("passive_authentication", "shield_checkmark"),
+    ("password_key_hide", "eye_off"),
+    ("password_key_show", "eye"),
+    ("payment_card", "payment"),
+    ("pc", "desktop"),
+    ("pdf", "document_pdf"),
+    ("pen_palette", "color"),
+    ("pen_tips", "pen"),
+    ("pencil", "pen"),
+    ("permissions", "shield_lock"),
+    ("personal_folder", "folder_person"),
+    ("phone_book", "book_contacts"),
+    ("photo", "image"),
+    ("pie_single", "data_pie"),
+    ("pin_pad", "dialpad"),
+    ("pinned", "pin"),
+    ("pinyin_ime", "keyboard"),
+    ("plap", "cast"),
+    ("player_settings", "settings"),
+    ("poi", "location"),
+    ("point_erase", "eraser"),
+    ("pointer_hand", "hand_left"),
+    ("police_car", "vehicle_car"),
+    ("post_update", "send"),
+    ("power_button_update", "power"),
+    ("presence_chicklet", "presence_available"),
+    ("print_all_pages", "print"),
+    ("print_custom_range", "print"),
+    ("print_default", "print"),
+    ("printfax_printer_file", "print"),
+    ("priority", "important"),
+    ("private_call", "call"),
+    ("process", "flow"),
+    ("processing", "arrow_sync"),
+    ("productivity_mode", "briefcase"),
+    ("progress_ring_dots", "spinner_ios"),
+    ("protected_document", "document_lock"),
+    ("protractor", "ruler"),
+    ("provisioning_package", "box"),
+    ("puzzle", "puzzle_piece"),
+    ("quarentined_items", "shield_error"),
+    ("quick_note", "note"),
+    ("quiet_hours", "weather_moon"),
+    ("radio_btn_off", "radio_button"),
+    ("radio_bullet", "circle"),
+    ("read", "book_open"),
+    ("reading_mode", "reading_mode_mobile"),
+    ("receipt_printer", "receipt"),
+    ("recent", "history"),
+    ("rectangular_clipping", "screenshot"),
+    ("red_eye", "eye"),
+    ("redo", "arrow_redo"),
+    ("relationship", "people"),
+    ("remembered_device", "phone"),
+    ("reminder", "alert"),
+    ("remove", "subtract"),
+    ("remove_from", "subtract"),
+    ("repair", "wrench"),
+    ("repeat_all", "arrow_repeat_all"),
+    ("repeat_off", "arrow_repeat_all_off"),
+    ("repeat_one", "arrow_repeat_1"),
+    ("reply", "arrow_reply"),
+    ("report_document", "document_text"),
+    ("report_hacked", "shield_error"),
+    ("reset_device", "arrow_reset"),
+    ("resize_mouse", "arrow_maximize"),
+    ("restart_update", "arrow_clockwise"),
+    ("restore", "square_multiple"),
+    ("return_key", "arrow_enter_left"),
+    ("return_to_call", "call"),
+    ("reveal_password", "eye"),
+    ("right", "arrow_right"),
+    ("right_arrow_key_time", "arrow_right"),
+    ("right_stick", "games"),
+    ("ringer", "alert"),
+    ("ringer_silent", "alert_off"),
+    ("robot", "bot"),
+    ("rotate", "arrow_rotate_clockwise"),
+    ("rotate_camera", "camera_switch"),
+    ("rotation_lock", "lock_closed"),
+    ("rtt_logo", "keyboard"),
+    ("safe", "lock_shield"),
+    ("save_as", "save_edit"),
+    ("save_local", "save"),
+    ("screen_time", "phone_screen_time"),
+    ("scroll_mode", "arrow_sort"),
+    ("scroll_up_down", "arrow_sort"),
+    ("search_and_apps", "search"),
+    ("select_all", "select_all_on"),
+    ("sensor", "scan"),
+    ("set_history_status", "history"),
+    ("set_tile", "pin"),
+    ("setlock_screen", "lock_closed"),
+    ("settings_display_sound", "settings"),
+    ("sglte", "cellular_data_1"),
+    ("share_broadband", "share"),
+    ("shop", "shopping_bag"),
+    ("shopping_cart", "cart"),
+    ("show_results", "text_bullet_list"),
+    ("shuffle", "arrow_shuffle"),
+    ("signal_bars", "cellular_data"),
+    ("signal_error", "cellular_warning"),
+    ("signal_not_connected", "cellular_off"),
+    ("signal_roaming", "globe"),
+    ("signature_capture", "signature"),
+    ("slow_motion", "video_clip"),
+    ("small_erase", "eraser_small"),
+    ("smart_screen", "shield"),
+    ("smartcard", "contact_card"),
+    ("smartcard_virtual", "contact_card"),
+    ("sort", "arrow_sort"),
+    ("spatial_volume", "speaker_2"),
+    ("speakers", "speaker_2"),
+    ("special_effect_size", "text_font_size"),
+    ("speech", "person_voice"),
+    ("speed_high", "top_speed"),
+    ("speed_medium", "top_speed"),
+    ("speed_off", "top_speed"),
+    ("spelling", "text_proofing_tools"),
+    ("split", "keyboard_layout_split"),
+    ("star_left_half", "star_half"),
+    ("star_right_half", "star_half"),
+    ("start_point", "location"),
+    ("status_secured", "shield_checkmark"),
+    ("stock_down", "arrow_trending_down"),
+    ("stock_up", "arrow_trending"),
+    ("stop_point", "location"),
+    ("stopwatch", "timer"),
+    ("storage_optical", "cd"),
+    ("streaming", "live"),
+    ("street", "street_sign"),
+    ("strikethrough", "text_strikethrough"),
+    ("stroke_erase", "eraser"),
+    ("subscription_add", "add_circle"),
+    ("sustainable", "leaf_one"),
+    ("swipe", "hand_left"),
+    ("swipe_reveal_art", "hand_left"),
+    ("switch", "arrow_swap"),
+    ("switch_user", "person_swap"),
+    ("sync_error", "arrow_sync_dismiss"),
+    ("sync_folder", "folder_sync"),
+    ("task_view", "app_folder"),
+    ("task_view_settings", "settings"),
+    ("taskbar_phone", "phone"),
+    ("this_pc", "desktop"),
+    ("thought_bubble", "chat_bubbles_question"),
+    ("three_bars", "cellular_data_3"),
+    ("tilt_down", "arrow_down"),
+    ("tilt_up", "arrow_up"),
+    ("time", "clock"),
+    ("time_language", "local_language"),
+    ("toll", "money"),
+    ("tool_tip", "comment"),
+    ("touch", "hand_left"),
+    ("touch_pointer", "hand_left"),
+    ("touchpad", "tablet"),
+    ("touchscreen", "hand_draw"),
+    ("trackers", "eye_tracking"),
+    ("train", "vehicle_subway"),
+    ("trim", "cut"),
+    ("tv_monitor", "tv"),
+    ("two_bars", "cellular_data_2"),
+    ("two_page", "book_open"),
+    ("type", "text_font"),
+    ("underline", "text_underline"),
+    ("underscore_space", "keyboard_shift"),
+    ("undo", "arrow_undo"),
+    ("unfavorite", "star_off"),
+    ("unknown", "question"),
+    ("unlock", "lock_open"),
+    ("unpin", "pin_off"),
+    ("up", "arrow_up"),
+    ("update_restore", "arrow_sync"),
+    ("update_status_dot", "circle"),
+    ("upload", "arrow_upload"),
+    ("usb", "usb_plug"),
+    ("usb_safe_connect", "usb_plug"),
+    ("user_apn", "globe"),
+    ("vibrate", "phone_vibrate"),
+    ("video_capture", "video"),
+    ("view", "eye"),
+    ("view_dashboard", "board"),
+    ("voice_call", "call"),
+    ("volume", "speaker_2"),
+    ("volume_bars", "speaker_2"),
+    ("vpn", "shield"),
+    ("walk", "person_walking"),
+    ("web_search", "globe_search"),
+    ("webcam", "camera"),
+    ("website", "globe"),
+    ("wi_fi", "wifi_1"),
+    ("wi_fi_call", "call"),
+    ("wi_fi_error", "wifi_warning"),
+    ("wi_fi_ethernet", "plug_connected"),
+    ("wi_fi_hotspot", "wifi_1"),
+    ("wi_fi_warning", "wifi_warning"),
+    ("window_snipping", "screenshot"),
+    ("windows_insider", "person_star"),
+    ("wire", "plug_connected"),
+    ("wired_usb", "usb_plug"),
+    ("wireless_usb", "usb_plug"),
+    ("work", "briefcase"),
+    ("world", "globe"),
+    ("zero_bars", "cellular_off"),
+    ("zip_folder", "folder_zip"),
+    ("zoom", "zoom_in"),
+];
+
+// Trailing words of Segoe Fluent Icons names which are dropped when there is no exact match
+const QUALIFIERS: &[&str] = &[
+    "1",
+    "12",
+    "14",
+    "2",
+    "20",
+    "3",
+    "32",
+    "36",
+    "7",
+    "8",
+    "all",
+    "app",
+    "badge",
+    "bold",
+    "button",
+    "combo",
+    "contrast",
+    "expanded",
+    "fill",
+    "full",
+    "horizontal",
+    "icon",
+    "key",
+    "lg",
+    "list",
+    "logo",
+    "medium",
+    "mirrored",
+    "mode",
+    "off",
+    "on",
+    "selected",
+    "single",
+    "sm",
+    "small",
+    "solid",
+    "tool",
+];
+
+// Leading words of Segoe Fluent Icons names which are dropped when there is no match with them
+const PREFIXES: &[&str] = &[
+    "band",
+    "chrome",
+    "mob",
+    "outline",
+    "status",
+    "tree_folder",
+    "vertical",
+];
+
+struct Icon {
+    /// As written in IconsData.json.
+    name: String,
+    variant: String,
+    codepoint: u32,
+    tags: Vec<String>,
+    /// Codepoint of the counterpart in Fluent UI System Icons.
+    system_icon: Option<u32>,
+}
+
+fn main() {
+    println!("cargo::rerun-if-changed={ICONS_DATA}");
+    println!("cargo::rerun-if-changed={SYSTEM_ICONS_DATA}");
+
+    let json = fs::read_to_string(ICONS_DATA).expect("failed to read IconsData.json");
+    let system_json = fs::read_to_string(SYSTEM_ICONS_DATA)
+        .expect("failed to read FluentSystemIcons-Regular.json");
+    let icons = parse(&json, &system_icons(&system_json));
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    fs::write(Path::new(&out_dir).join("fluent_icon.rs"), generate(&icons))
+        .expect("failed to write fluent_icon.rs");
+}
+
+fn parse(json: &str, system_icons: &HashMap<String, u32>) -> Vec<Icon> {
+    let data: Value = serde_json::from_str(json).expect("IconsData.json is not valid JSON");
+
+    let mut icons: Vec<Icon> = data
+        .as_array()
+        .expect("IconsData.json should be an array")
+        .iter()
+        .map(|entry| {
+            let name = entry["Name"].as_str().expect("icon without a Name");
+            let code = entry["Code"].as_str().expect("icon without a Code");
+            let codepoint = u32::from_str_radix(code, 16)
+                .unwrap_or_else(|_| panic!("{name} has an invalid Code: {code}"));
+
+            let mut tags: Vec<String> = entry["Tags"]
+                .as_array()
+                .map(|tags| {
+                    tags.iter()
+                        .filter_map(Value::as_str)
+                        .map(str::to_lowercase)
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            let system_icon = system_icon(name, system_icons);
+
+            // The counterpart is often named with the more common word, such as dismiss
+            // for Cancel, so its name is searchable too
+            if let Some((system_name, _)) = system_icon {
+                for word in system_name.split('_') {
+                    if !word.starts_with(|c: char| c.is_ascii_digit())
+                        && !tags.iter().any(|tag| tag == word)
+                    {
+                        tags.push(word.to_owned());
+                    }
+                }
+            }
+
+            Icon {
+                name: name.to_owned(),
+                variant: variant_name(name),
+                codepoint,
+                tags,
+                system_icon: system_icon.map(|(_, codepoint)| codepoint),
+            }
+        })
+        .collect();
+
+    icons.sort_by_key(|icon| icon.codepoint);
+
+    let mut names = HashSet::new();
+    for icon in &icons {
+        assert!(
+            names.insert(&icon.variant),
+            "duplicate icon name {}",
+            icon.variant
+        );
+    }
+
+    icons
+}
+
+// Names in IconsData.json are already PascalCase but may not be valid identifiers.
+fn variant_name(name: &str) -> String {
+    let name: String = name.chars().filter(char::is_ascii_alphanumeric).collect();
+
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("Icon{name}")
+    } else {
+        name
+    }
+}
+
+// Codepoints of Fluent UI System Icons by snake_case name, in the preferred size
+fn system_icons(json: &str) -> HashMap<String, u32> {
+    let data: HashMap<String, u32> =
+        serde_json::from_str(json).expect("FluentSystemIcons-Regular.json is not valid JSON");
+
+    let mut sizes: HashMap<String, Vec<(u32, u32)>> = HashMap::new();
+    for (key, codepoint) in data {
+        let (name, size) = key
+            .strip_prefix("ic_fluent_")
+            .and_then(|key| key.strip_suffix("_regular"))
+            .and_then(|key| key.rsplit_once('_'))
+            .unwrap_or_else(|| panic!("unexpected Fluent UI System Icons name {key}"));
+        let size = size
+            .parse()
+            .unwrap_or_else(|_| panic!("{key} has an invalid size"));
+
+        sizes
+            .entry(name.to_owned())
+            .or_default()
+            .push((size, codepoint));
+    }
+
+    sizes
+        .into_iter()
+        .filter_map(|(name, sizes)| {
+            SYSTEM_ICON_SIZES
+                .iter()
+                .find_map(|preferred| sizes.iter().find(|(size, _)| size == preferred))
+                .map(|(_, codepoint)| (name, *codepoint))
+        })
+        .collect()
+}
+
+// Tries the name as is and without a leading prefix.
+fn system_icon<'a>(name: &str, system_icons: &'a HashMap<String, u32>) -> Option<(&'a str, u32)> {
+    if let Some((_, alias)) = ALIASES.iter().find(|(segoe, _)| *segoe == name) {
+        let (name, codepoint) = system_icons
+            .get_key_value(*alias)
+            .unwrap_or_else(|| panic!("alias of {name} is not an icon: {alias}"));
+
+        return Some((name, *codepoint));
+    }
+
+    let words = words(name);
+
+    find_system_icon(words.clone(), system_icons).or_else(|| {
+        (1..words.len())
+            .filter(|&end| PREFIXES.contains(&words[..end].join("_").as_str()))
+            .find_map(|end| find_system_icon(words[end..].to_vec(), system_icons))
+    })
+}
+
+// Tries the words as is and with synonyms, then again without trailing qualifiers.
+fn find_system_icon(
+    mut words: Vec<String>,
+    system_icons: &HashMap<String, u32>,
+) -> Option<(&str, u32)> {
+    while !words.is_empty() {
+        let found = system_icons
+            .get_key_value(&words.join("_"))
+            .or_else(|| system_icons.get_key_value(&with_synonyms(&words)));
+
+        if let Some((name, codepoint)) = found {
+            return Some((name, *codepoint));
+        }
+
+        if !QUALIFIERS.contains(&words[words.len() - 1].as_str()) {
+            break;
+        }
+
+        words.pop();
+    }
+
+    None
+}
+
+// Lowercase words of a PascalCase name, with numbers as words of their own.
+fn words(name: &str) -> Vec<String> {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words = vec![String::new()];
+
+    for (i, &c) in chars.iter().enumerate() {
+        if i > 0 {
+            let previous = chars[i - 1];
+            let next = chars.get(i + 1);
+
+            let boundary = ((previous.is_ascii_lowercase() || previous.is_ascii_digit())
+                && c.is_ascii_uppercase())
+                || (previous.is_ascii_uppercase()
+                    && c.is_ascii_uppercase()
+                    && next.is_some_and(char::is_ascii_lowercase))
+                || (previous.is_ascii_alphabetic() && c.is_ascii_digit());
+
+            if boundary {
+                words.push(String::new());
+            }
+        }
+
+        words.last_mut().unwrap().push(c.to_ascii_lowercase());
+    }
+
+    words
+}
+
+// Replaces the longest phrases found in SYNONYMS.
+fn with_synonyms(words: &[String]) -> String {
+    let mut replaced = Vec::new();
+    let mut i = 0;
+
+    while i < words.len() {
+        let synonym = (i + 1..=words.len()).rev().find_map(|end| {
+            let phrase = words[i..end].join("_");
+
+            SYNONYMS
+                .iter()
+                .find(|(word, _)| *word == phrase)
+                .map(|(_, synonym)| (end, *synonym))
+        });
+
+        match synonym {
+            Some((end, synonym)) => {
+                replaced.push(synonym.to_owned());
+                i = end;
+            }
+            None => {
+                replaced.push(words[i].clone());
+                i += 1;
+            }
+        }
+    }
+
+    replaced.join("_")
+}
+
+fn generate(icons: &[Icon]) -> String {
+    let mut out = String::new();
+
+    out.push_str("// @generated by build.rs from assets/IconsData.json\n\n");
+
+    out.push_str("/// Glyphs of the Segoe Fluent Icons font.\n");
+    out.push_str("#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]\n");
+    out.push_str("#[repr(u16)]\n");
+    out.push_str("pub enum FluentIcon {\n");
+    for icon in icons {
+        writeln!(out, "    /// `U+{:04X}`", icon.codepoint).unwrap();
+        writeln!(out, "    {},", icon.variant).unwrap();
+    }
+    out.push_str("}\n\n");
+
+    out.push_str("impl FluentIcon {\n");
+    out.push_str("    /// Every icon, ordered by codepoint.\n");
+    writeln!(out, "    pub const ALL: [FluentIcon; {}] = [", icons.len()).unwrap();
+    for icon in icons {
+        writeln!(out, "        FluentIcon::{},", icon.variant).unwrap();
+    }
+    out.push_str("    ];\n}\n\n");
+
+    // Indexed by discriminant
+    out.push_str("const ICONS: &[IconData] = &[\n");
+    for icon in icons {
+        let tags = icon
+            .tags
+            .iter()
+            .map(|tag| format!("{tag:?}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let system_icon = match icon.system_icon {
+            Some(codepoint) => format!("Some('\\u{{{codepoint:04X}}}')"),
+            None => "None".to_owned(),
+        };
+
+        writeln!(
+            out,
+            "    IconData {{ name: {:?}, codepoint: '\\u{{{:04X}}}', tags: &[{tags}], system_icon: {system_icon} }},",
+            icon.name, icon.codepoint
+        )
+        .unwrap();
+    }
+    out.push_str("];\n\n");
+
+    // Sorted by name for FromStr
+    let mut by_name: Vec<&Icon> = icons.iter().collect();
+    by_name.sort_by(|a, b| a.name.cmp(&b.name));
+
+    writeln!(
+        out,
+        "static BY_NAME: [(&str, FluentIcon); {}] = [",
+        icons.len()
+    )
+    .unwrap();
+    for icon in by_name {
+        writeln!(out, "    ({:?}, FluentIcon::{}),", icon.name, icon.variant).unwrap();
+    }
+    out.push_str("];\n");
+
+    out
+}
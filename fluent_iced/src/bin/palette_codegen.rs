@@ -0,0 +1,563 @@
+//! Updates `Palette::DARK`, `Palette::LIGHT` and the contrast palettes from a local
+//! copy of the WinUI theme resources, `Common_themeresources_any.xaml`:
+//!
+//! ```text
+//! cargo run -p fluent_iced --features codegen --bin palette_codegen -- <xaml> [--write]
+//! ```
+//!
+//! The changes are printed, and only written to `src/theme.rs` and
+//! `src/theme/high_contrast.rs` with `--write`. Resources of the XAML which have no
+//! token in `Palette` are listed so they can be added by hand.
+
+use fluent_iced::theme::Palette;
+
+use std::{
+    collections::{BTreeSet, HashMap},
+    env, fs,
+    process::ExitCode,
+};
+
+const THEME_RS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/theme.rs");
+const HIGH_CONTRAST_RS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/theme/high_contrast.rs");
+
+// WinUI reads these from the system, use the shades of the default Windows blue
+// https://github.com/microsoft/microsoft-ui-xaml/blob/63671e055eadfd74806f9800382b8bd6c9999b4a/dxaml/xcp/components/theminginterop/SystemThemingInterop.cpp#L226
+const ACCENT_COLOURS: [(&str, u32); 7] = [
+    ("SystemAccentColor", 0xFF0078D7),
+    ("SystemAccentColorLight1", 0xFF429CE3),
+    ("SystemAccentColorLight2", 0xFF76B9ED),
+    ("SystemAccentColorLight3", 0xFFA6D8FF),
+    ("SystemAccentColorDark1", 0xFF005A9E),
+    ("SystemAccentColorDark2", 0xFF004275),
+    ("SystemAccentColorDark3", 0xFF002642),
+];
+
+// Resources of the contrast themes and the matching fields of `SystemColours`
+const SYSTEM_COLOURS: [(&str, &str); 8] = [
+    ("SystemColorWindowColor", "window"),
+    ("SystemColorWindowTextColor", "window_text"),
+    ("SystemColorHotlightColor", "hotlight"),
+    ("SystemColorGrayTextColor", "gray_text"),
+    ("SystemColorHighlightColor", "highlight"),
+    ("SystemColorHighlightTextColor", "highlight_text"),
+    ("SystemColorButtonFaceColor", "button_face"),
+    ("SystemColorButtonTextColor", "button_text"),
+];
+
+// Local names `SystemColours::palette` gives to some of its fields
+const LOCALS: [(&str, &str); 2] = [("window", "window"), ("text", "window_text")];
+
+/// A `Color`, `StaticResource` or `SolidColorBrush` of a theme dictionary.
+#[derive(Debug)]
+struct Resource {
+    value: Value,
+    opacity: f32,
+}
+
+#[derive(Debug)]
+enum Value {
+    Argb(u32),
+    Reference(String),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Resolved {
+    Argb(u32),
+    /// Field of `SystemColours`.
+    System(&'static str),
+}
+
+type Dictionary = HashMap<String, Resource>;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let write = args.iter().any(|arg| arg == "--write");
+
+    let Some(path) = args.iter().find(|arg| !arg.starts_with("--")) else {
+        eprintln!("usage: palette_codegen <Common_themeresources_any.xaml> [--write]");
+        return ExitCode::FAILURE;
+    };
+
+    let source = match fs::read_to_string(path) {
+        Ok(source) => source,
+        Err(error) => {
+            eprintln!("{path}: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let dictionaries = match theme_dictionaries(&source) {
+        Ok(dictionaries) => dictionaries,
+        Err(error) => {
+            eprintln!("{path}: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut theme_rs = fs::read_to_string(THEME_RS).expect("theme.rs is readable");
+    let mut high_contrast_rs =
+        fs::read_to_string(HIGH_CONTRAST_RS).expect("high_contrast.rs is readable");
+
+    let mut unknown = BTreeSet::new();
+    let changes = update_palettes(
+        &dictionaries,
+        &mut theme_rs,
+        &mut high_contrast_rs,
+        &mut unknown,
+    );
+
+    if !unknown.is_empty() {
+        println!("\nResources without a Palette token:");
+
+        for resource in &unknown {
+            println!("    {resource}");
+        }
+    }
+
+    if changes == 0 {
+        println!("\nPalette is up to date");
+    } else if write {
+        fs::write(THEME_RS, theme_rs).expect("theme.rs is writable");
+        fs::write(HIGH_CONTRAST_RS, high_contrast_rs).expect("high_contrast.rs is writable");
+        println!("\nWrote {changes} changes");
+    } else {
+        println!("\n{changes} changes, run with --write to apply them");
+    }
+
+    ExitCode::SUCCESS
+}
+
+// Rewrites the colours of `Palette::DARK` and `Palette::LIGHT` in `theme_rs`, and of
+// the contrast palettes in `high_contrast_rs`, which differ from the theme dictionaries.
+// Returns the number of lines changed.
+fn update_palettes(
+    dictionaries: &HashMap<String, Dictionary>,
+    theme_rs: &mut String,
+    high_contrast_rs: &mut String,
+    unknown: &mut BTreeSet<String>,
+) -> usize {
+    let mut changes = 0;
+
+    for (key, constant) in [("Default", "DARK"), ("Light", "LIGHT")] {
+        let Some(dictionary) = dictionaries.get(key) else {
+            eprintln!("warning: no \"{key}\" theme dictionary");
+            continue;
+        };
+
+        let tokens = tokens(dictionary, unknown);
+        let block = format!("pub const {constant}: Self = Self {{");
+
+        changes += update_block(theme_rs, &block, constant, |token, value| {
+            let Some(Resolved::Argb(argb)) = tokens.get(token) else {
+                return None;
+            };
+
+            (parse_argb(value) != Some(*argb)).then(|| format_argb(*argb))
+        });
+    }
+
+    match dictionaries.get("HighContrast") {
+        None => eprintln!("warning: no \"HighContrast\" theme dictionary"),
+        Some(dictionary) => {
+            let tokens = tokens(dictionary, unknown);
+
+            changes += update_block(
+                high_contrast_rs,
+                "fn palette(&self) -> Palette {",
+                "SystemColours::palette",
+                |token, value| {
+                    let Some(Resolved::System(field)) = tokens.get(token) else {
+                        return None;
+                    };
+
+                    (system_field(value) != Some(field)).then(|| format_system(field))
+                },
+            );
+        }
+    }
+
+    changes
+}
+
+// Resources of each ResourceDictionary in ResourceDictionary.ThemeDictionaries, by key
+fn theme_dictionaries(source: &str) -> Result<HashMap<String, Dictionary>, String> {
+    let document = roxmltree::Document::parse(source).map_err(|error| error.to_string())?;
+
+    let theme_dictionaries = document
+        .descendants()
+        .find(|node| node.has_tag_name("ResourceDictionary.ThemeDictionaries"))
+        .ok_or("no ResourceDictionary.ThemeDictionaries element")?;
+
+    Ok(theme_dictionaries
+        .children()
+        .filter(|node| node.has_tag_name("ResourceDictionary"))
+        .filter_map(|node| {
+            let resources = node
+                .children()
+                .filter_map(|node| Some((key(node)?.to_owned(), resource(node)?)))
+                .collect();
+
+            Some((key(node)?.to_owned(), resources))
+        })
+        .collect())
+}
+
+fn key<'a>(node: roxmltree::Node<'a, '_>) -> Option<&'a str> {
+    node.attributes()
+        .find(|attribute| attribute.name() == "Key")
+        .map(|attribute| attribute.value())
+}
+
+fn resource(node: roxmltree::Node) -> Option<Resource> {
+    let value = match node.tag_name().name() {
+        "Color" => Value::Argb(parse_hex(node.text()?.trim())?),
+        "StaticResource" => Value::Reference(node.attribute("ResourceKey")?.to_owned()),
+        "SolidColorBrush" => {
+            let colour = node.attribute("Color")?;
+
+            match markup_reference(colour) {
+                Some(reference) => Value::Reference(reference.to_owned()),
+                None => Value::Argb(parse_hex(colour)?),
+            }
+        }
+        _ => return None,
+    };
+
+    let opacity = node
+        .attribute("Opacity")
+        .and_then(|opacity| opacity.parse().ok())
+        .unwrap_or(1.0);
+
+    Some(Resource { value, opacity })
+}
+
+// `{StaticResource Key}` or `{ThemeResource Key}`
+fn markup_reference(value: &str) -> Option<&str> {
+    let inner = value.strip_prefix('{')?.strip_suffix('}')?;
+    let (kind, key) = inner.split_once(' ')?;
+
+    matches!(kind, "StaticResource" | "ThemeResource").then_some(key.trim())
+}
+
+// XAML colours are #AARRGGBB, or #RRGGBB when opaque
+fn parse_hex(value: &str) -> Option<u32> {
+    let hex = value
+        .strip_prefix('#')
+        .filter(|hex| hex.bytes().all(|byte| byte.is_ascii_hexdigit()))?;
+    let argb = u32::from_str_radix(hex, 16).ok()?;
+
+    match hex.len() {
+        6 => Some(0xFF000000 | argb),
+        8 => Some(argb),
+        _ => None,
+    }
+}
+
+// Resolved colour of every resource which has a Palette token. Colours take
+// precedence over brushes of the same name, which only add an opacity.
+fn tokens(dictionary: &Dictionary, unknown: &mut BTreeSet<String>) -> HashMap<String, Resolved> {
+    let mut tokens = HashMap::new();
+    let mut keys: Vec<&String> = dictionary.keys().collect();
+    keys.sort_by_key(|key| (key.ends_with("Brush"), key.as_str()));
+
+    for key in keys {
+        let token = snake_case(key.strip_suffix("Brush").unwrap_or(key));
+
+        if !Palette::TOKENS.contains(&token.as_str()) {
+            if !key.ends_with("Brush") {
+                unknown.insert(key.clone());
+            }
+            continue;
+        }
+
+        if tokens.contains_key(&token) {
+            continue;
+        }
+
+        match resolve(dictionary, key, 0) {
+            Some(resolved) => {
+                tokens.insert(token, resolved);
+            }
+            None => eprintln!("warning: could not resolve {key}"),
+        }
+    }
+
+    tokens
+}
+
+fn resolve(dictionary: &Dictionary, key: &str, depth: usize) -> Option<Resolved> {
+    if depth > 16 {
+        return None;
+    }
+
+    let Some(resource) = dictionary.get(key) else {
+        return ACCENT_COLOURS
+            .iter()
+            .find(|(name, _)| *name == key)
+            .map(|(_, argb)| Resolved::Argb(*argb))
+            .or_else(|| {
+                SYSTEM_COLOURS
+                    .iter()
+                    .find(|(name, _)| *name == key)
+                    .map(|(_, field)| Resolved::System(field))
+            });
+    };
+
+    let resolved = match &resource.value {
+        Value::Argb(argb) => Resolved::Argb(*argb),
+        Value::Reference(reference) => resolve(dictionary, reference, depth + 1)?,
+    };
+
+    match resolved {
+        _ if resource.opacity == 1.0 => Some(resolved),
+        Resolved::Argb(argb) => {
+            let alpha = ((argb >> 24) as f32 * resource.opacity).round() as u32;
+            Some(Resolved::Argb(alpha << 24 | argb & 0x00FFFFFF))
+        }
+        // Contrast themes have no translucent colours to fall back on
+        Resolved::System(_) => None,
+    }
+}
+
+fn snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 8);
+
+    for (index, character) in name.char_indices() {
+        if character.is_ascii_uppercase() && index > 0 {
+            snake.push('_');
+        }
+        snake.push(character.to_ascii_lowercase());
+    }
+
+    snake
+}
+
+// Rewrites the `token: value,` lines of the struct literal which starts on the line
+// containing `start`, with the value returned by `update`. Returns the number of
+// lines changed.
+fn update_block(
+    source: &mut String,
+    start: &str,
+    name: &str,
+    update: impl Fn(&str, &str) -> Option<String>,
+) -> usize {
+    let Some(start) = source.find(start) else {
+        eprintln!("warning: {name} not found");
+        return 0;
+    };
+
+    let mut changes = 0;
+    let mut output = String::with_capacity(source.len());
+    output.push_str(&source[..start]);
+
+    let mut lines = source[start..].split_inclusive('\n');
+    output.push_str(lines.next().unwrap_or_default());
+
+    let mut seen = Vec::new();
+
+    for line in lines.by_ref() {
+        if line.trim_start().starts_with('}') {
+            output.push_str(line);
+            break;
+        }
+
+        let Some((indent, token, value)) = field(line) else {
+            output.push_str(line);
+            continue;
+        };
+
+        seen.push(token);
+
+        if is_argb_without_alpha(value) {
+            eprintln!("warning: {name}.{token} is transparent, {value} has no alpha digits");
+        }
+
+        match update(token, value) {
+            Some(new) => {
+                println!("{name}.{token}: {value} -> {new}");
+                output.push_str(&format!("{indent}{token}: {new},\n"));
+                changes += 1;
+            }
+            None => output.push_str(line),
+        }
+    }
+
+    for line in lines {
+        output.push_str(line);
+    }
+
+    let missing: Vec<_> = Palette::TOKENS
+        .iter()
+        .filter(|token| !seen.contains(token))
+        .collect();
+
+    if !missing.is_empty() {
+        eprintln!("warning: {name} does not set {missing:?}");
+    }
+
+    *source = output;
+    changes
+}
+
+fn field(line: &str) -> Option<(&str, &str, &str)> {
+    let trimmed = line.trim_start();
+    let indent = &line[..line.len() - trimmed.len()];
+    let (token, value) = trimmed.trim_end().strip_suffix(',')?.split_once(": ")?;
+
+    token
+        .chars()
+        .all(|character| {
+            character.is_ascii_lowercase() || character.is_ascii_digit() || character == '_'
+        })
+        .then_some((indent, token, value))
+}
+
+// Value of a `color!(0xRRGGBB)` or `from_argb!(0xAARRGGBB)` field. Like the macro,
+// a `from_argb!` without the alpha digits is read as transparent.
+fn parse_argb(value: &str) -> Option<u32> {
+    if let Some(hex) = value
+        .strip_prefix("color!(0x")
+        .and_then(|hex| hex.strip_suffix(')'))
+    {
+        return Some(0xFF000000 | u32::from_str_radix(hex, 16).ok()?);
+    }
+
+    let hex = value.strip_prefix("from_argb!(0x")?.strip_suffix(')')?;
+    u32::from_str_radix(hex, 16).ok()
+}
+
+// `from_argb!(0xRRGGBB)`, which is almost always meant to be `color!(0xRRGGBB)`
+fn is_argb_without_alpha(value: &str) -> bool {
+    value
+        .strip_prefix("from_argb!(0x")
+        .and_then(|hex| hex.strip_suffix(')'))
+        .is_some_and(|hex| hex.len() <= 6)
+}
+
+fn format_argb(argb: u32) -> String {
+    if argb >> 24 == 0xFF {
+        format!("color!(0x{:06X})", argb & 0x00FFFFFF)
+    } else {
+        format!("from_argb!(0x{argb:08X})")
+    }
+}
+
+// `SystemColours` field used by a `self.field` or local variable value
+fn system_field(value: &str) -> Option<&str> {
+    value.strip_prefix("self.").or_else(|| {
+        LOCALS
+            .iter()
+            .find(|(local, _)| *local == value)
+            .map(|(_, field)| *field)
+    })
+}
+
+fn format_system(field: &str) -> String {
+    LOCALS
+        .iter()
+        .find(|(_, local_field)| *local_field == field)
+        .map_or_else(|| format!("self.{field}"), |(local, _)| (*local).to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("../../tests/fixtures/theme_resources.xaml");
+
+    const THEME_RS: &str = "\
+impl Palette {
+    pub const DARK: Self = Self {
+        accent_fill_color_default: from_argb!(0xFF76B9ED),
+        accent_fill_color_secondary: from_argb!(0xE676B9ED),
+        accent_text_fill_color_primary: color!(0x000000),
+        text_fill_color_primary: from_argb!(0xFFFFFF),
+        text_fill_color_secondary: from_argb!(0xC0FFFFFF),
+    };
+
+    pub const LIGHT: Self = Self {
+        accent_fill_color_default: color!(0x005A9E),
+        accent_fill_color_secondary: from_argb!(0xE6005A9E),
+        accent_text_fill_color_primary: color!(0x004275),
+        text_fill_color_primary: from_argb!(0xE4000000),
+        text_fill_color_secondary: from_argb!(0x9E000000),
+    };
+}
+";
+
+    const HIGH_CONTRAST_RS: &str = "\
+impl SystemColours {
+    pub const fn palette(&self) -> Palette {
+        let window = self.window;
+        let text = self.window_text;
+
+        Palette {
+            accent_fill_color_default: self.highlight,
+            accent_fill_color_secondary: self.highlight,
+            accent_text_fill_color_primary: self.hotlight,
+            text_fill_color_primary: text,
+            text_fill_color_secondary: self.gray_text,
+        }
+    }
+}
+";
+
+    #[test]
+    fn updates_palettes_from_theme_resources() {
+        let dictionaries = theme_dictionaries(FIXTURE).unwrap();
+        let mut theme_rs = THEME_RS.to_owned();
+        let mut high_contrast_rs = HIGH_CONTRAST_RS.to_owned();
+        let mut unknown = BTreeSet::new();
+
+        let changes = update_palettes(
+            &dictionaries,
+            &mut theme_rs,
+            &mut high_contrast_rs,
+            &mut unknown,
+        );
+
+        // Colours win over brushes of the same name, the accent colours and
+        // opacities of brushes are resolved, and opaque colours which were written
+        // without alpha digits, and so transparent, are fixed
+        assert_eq!(
+            theme_rs,
+            THEME_RS
+                .replace(
+                    "accent_text_fill_color_primary: color!(0x000000)",
+                    "accent_text_fill_color_primary: color!(0xA6D8FF)",
+                )
+                .replace(
+                    "text_fill_color_primary: from_argb!(0xFFFFFF)",
+                    "text_fill_color_primary: color!(0xFFFFFF)",
+                )
+                .replace(
+                    "text_fill_color_secondary: from_argb!(0xC0FFFFFF)",
+                    "text_fill_color_secondary: from_argb!(0xC5FFFFFF)",
+                ),
+        );
+
+        // Translucent system colours can't be resolved and are left alone
+        assert_eq!(
+            high_contrast_rs,
+            HIGH_CONTRAST_RS
+                .replace(
+                    "accent_text_fill_color_primary: self.hotlight",
+                    "accent_text_fill_color_primary: self.highlight",
+                )
+                .replace(
+                    "text_fill_color_secondary: self.gray_text",
+                    "text_fill_color_secondary: text",
+                ),
+        );
+
+        assert_eq!(changes, 5);
+        assert_eq!(unknown, BTreeSet::from(["ControlFillColorNew".to_owned()]));
+    }
+
+    #[test]
+    fn rejects_resources_without_theme_dictionaries() {
+        let error = theme_dictionaries("<ResourceDictionary />").unwrap_err();
+
+        assert_eq!(error, "no ResourceDictionary.ThemeDictionaries element");
+    }
+}
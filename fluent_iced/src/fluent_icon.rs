@@ -0,0 +1,105 @@
+//! Every glyph of the Segoe Fluent Icons font.
+//!
+//! The enum is generated by `build.rs` from `assets/IconsData.json`, which has the
+//! format of the [WinUI Gallery icon list]. Its tags are the words of each name and
+//! of its Fluent UI System Icons counterpart rather than the curated tags of the
+//! WinUI Gallery.
+//!
+//! [WinUI Gallery icon list]: https://github.com/microsoft/WinUI-Gallery/blob/main/WinUIGallery/DataModel/IconsData.json
+//!
+//! ```
+//! use fluent_iced::FluentIcon;
+//!
+//! let icon: FluentIcon = "Home".parse().unwrap();
+//!
+//! assert_eq!(icon.codepoint(), '\u{E80F}');
+//! assert!(icon.tags().contains(&"home"));
+//!
+//! let cancel: FluentIcon = "Cancel".parse().unwrap();
+//! assert!(cancel.tags().contains(&"dismiss"));
+//! assert!(icon.fluent_system_icon().is_some());
+//! ```
+
+use std::{fmt, str::FromStr};
+
+include!(concat!(env!("OUT_DIR"), "/fluent_icon.rs"));
+
+struct IconData {
+    name: &'static str,
+    codepoint: char,
+    tags: &'static [&'static str],
+    system_icon: Option<char>,
+}
+
+impl FluentIcon {
+    /// Character to render with the icon font, see [`crate::widget::text::icon`].
+    pub const fn codepoint(&self) -> char {
+        ICONS[*self as usize].codepoint
+    }
+
+    /// Name as written in `IconsData.json`, which is also the name of the variant
+    /// unless it isn't a valid identifier.
+    pub const fn name(&self) -> &'static str {
+        ICONS[*self as usize].name
+    }
+
+    /// Icon drawn by `codepoint` in Segoe Fluent Icons.
+    pub fn from_codepoint(codepoint: char) -> Option<Self> {
+        ICONS
+            .binary_search_by_key(&codepoint, |icon| icon.codepoint)
+            .ok()
+            .map(|index| Self::ALL[index])
+    }
+
+    /// Lowercase keywords describing the icon, useful for search.
+    pub const fn tags(&self) -> &'static [&'static str] {
+        ICONS[*self as usize].tags
+    }
+
+    /// Codepoint of the same icon in the bundled Fluent UI System Icons font, if it
+    /// has one, see [`crate::font::FLUENT_SYSTEM_ICONS`].
+    ///
+    /// Most icons have one, the rest are mostly printing, input method and device glyphs:
+    ///
+    /// ```
+    /// use fluent_iced::FluentIcon;
+    ///
+    /// let covered = FluentIcon::ALL
+    ///     .iter()
+    ///     .filter(|icon| icon.fluent_system_icon().is_some())
+    ///     .count();
+    ///
+    /// assert!(covered >= 990, "only {covered} icons have a counterpart");
+    /// ```
+    pub const fn fluent_system_icon(&self) -> Option<char> {
+        ICONS[*self as usize].system_icon
+    }
+}
+
+impl fmt::Display for FluentIcon {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseFluentIconError(String);
+
+impl fmt::Display for ParseFluentIconError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown Fluent icon: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseFluentIconError {}
+
+impl FromStr for FluentIcon {
+    type Err = ParseFluentIconError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        BY_NAME
+            .binary_search_by(|(name, _)| (*name).cmp(s))
+            .map(|index| BY_NAME[index].1)
+            .map_err(|_| ParseFluentIconError(s.to_owned()))
+    }
+}
@@ -0,0 +1,234 @@
+//! Fonts used by the text and icon widgets.
+//!
+//! Segoe UI and Segoe Fluent Icons only ship with Windows. Call [`detect`] at
+//! startup and run the task returned by [`load`] so that text falls back to the
+//! bundled Inter font when Segoe UI is missing. Icons fall back to the icon font
+//! passed to [`detect`], usually the bundled [`FLUENT_SYSTEM_ICONS`].
+
+use crate::fluent_icon::FluentIcon;
+
+use std::{borrow::Cow, sync::RwLock};
+
+use iced::{
+    advanced::graphics::text,
+    font::{self, Family, Weight},
+    Font, Task,
+};
+
+pub const SEGOE: Font = Font::with_name("en Segoe UI");
+
+pub const SEGOE_SEMIBOLD: Font = Font {
+    family: Family::Name("en Segoe UI Semibold"),
+    weight: Weight::Semibold,
+    ..Font::DEFAULT
+};
+
+pub const SEGOE_BOLD: Font = Font {
+    family: Family::Name("en Segoe UI Bold"),
+    weight: Weight::Bold,
+    ..Font::DEFAULT
+};
+
+pub const SEGOE_FLUENT_ICONS: Font = Font::with_name("en Segoe Fluent Icons");
+
+pub const INTER: Font = Font::with_name("Inter");
+
+pub const INTER_SEMIBOLD: Font = Font {
+    weight: Weight::Semibold,
+    ..INTER
+};
+
+pub const INTER_BOLD: Font = Font {
+    weight: Weight::Bold,
+    ..INTER
+};
+
+const INTER_REGULAR_BYTES: &[u8] = include_bytes!("../assets/fonts/Inter-Regular.ttf");
+const INTER_BOLD_BYTES: &[u8] = include_bytes!("../assets/fonts/Inter-Bold.ttf");
+
+/// Bundled Fluent UI System Icons, licensed under the MIT License. Icons without
+/// a counterpart in it render as boxes, see [`has_glyph`].
+pub const FLUENT_SYSTEM_ICONS: IconFallback = IconFallback {
+    name: "Fluent UI System Icons (bundled)",
+    font: Font::with_name("Fluent UI Regular"),
+    bytes: include_bytes!("../assets/fonts/FluentSystemIcons-Regular.ttf"),
+    map: |icon| icon.fluent_system_icon(),
+};
+
+static ACTIVE: RwLock<Fonts> = RwLock::new(Fonts::SEGOE);
+
+/// Family used for all text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextFont {
+    Segoe,
+    /// Bundled, licensed under the SIL Open Font License.
+    Inter,
+}
+
+impl TextFont {
+    pub fn name(&self) -> &'static str {
+        match self {
+            TextFont::Segoe => "Segoe UI",
+            TextFont::Inter => "Inter (bundled)",
+        }
+    }
+}
+
+/// Family used by [`crate::widget::text::icon`] and the icons drawn inside widgets.
+#[derive(Clone, Copy, Debug)]
+pub enum IconFont {
+    SegoeFluentIcons,
+    Fallback(IconFallback),
+    /// Segoe Fluent Icons is not installed and no fallback was given, icons render as boxes.
+    Missing,
+}
+
+impl IconFont {
+    pub fn name(&self) -> &'static str {
+        match self {
+            IconFont::SegoeFluentIcons => "Segoe Fluent Icons",
+            IconFont::Fallback(fallback) => fallback.name,
+            IconFont::Missing => "Segoe Fluent Icons (not installed)",
+        }
+    }
+}
+
+/// An icon font to use when Segoe Fluent Icons is not installed.
+///
+/// Icon fonts don't share codepoints so `map` translates the Segoe Fluent Icons
+/// codepoint of a [`FluentIcon`] to the one in this font.
+#[derive(Clone, Copy, Debug)]
+pub struct IconFallback {
+    pub name: &'static str,
+    pub font: Font,
+    pub bytes: &'static [u8],
+    pub map: fn(FluentIcon) -> Option<char>,
+}
+
+/// The text and icon fonts in use.
+#[derive(Clone, Copy, Debug)]
+pub struct Fonts {
+    pub text: TextFont,
+    pub icons: IconFont,
+}
+
+impl Fonts {
+    pub const SEGOE: Self = Self {
+        text: TextFont::Segoe,
+        icons: IconFont::SegoeFluentIcons,
+    };
+}
+
+/// Checks which of the Segoe families are installed and picks fallbacks for the others.
+pub fn detect(icon_fallback: Option<IconFallback>) -> Fonts {
+    let mut database = fontdb::Database::new();
+    database.load_system_fonts();
+
+    let installed = |family: &str| {
+        database.faces().any(|face| {
+            face.families
+                .iter()
+                .any(|(name, _)| name.eq_ignore_ascii_case(family))
+        })
+    };
+
+    let text = if installed("Segoe UI") {
+        TextFont::Segoe
+    } else {
+        TextFont::Inter
+    };
+
+    let icons = if installed("Segoe Fluent Icons") {
+        IconFont::SegoeFluentIcons
+    } else {
+        icon_fallback.map_or(IconFont::Missing, IconFont::Fallback)
+    };
+
+    Fonts { text, icons }
+}
+
+/// Makes `fonts` the active fonts and loads the bundled ones they need.
+pub fn load(fonts: Fonts) -> Task<Result<(), font::Error>> {
+    *ACTIVE.write().expect("font lock poisoned") = fonts;
+
+    Task::batch(bundled(fonts).into_iter().map(font::load))
+}
+
+/// Like [`load`] but loads the fonts right away, for rendering without a running
+/// application.
+pub fn load_now(fonts: Fonts) {
+    *ACTIVE.write().expect("font lock poisoned") = fonts;
+
+    let mut font_system = text::font_system()
+        .write()
+        .expect("font system lock poisoned");
+
+    for bytes in bundled(fonts) {
+        font_system.load_font(Cow::Borrowed(bytes));
+    }
+}
+
+fn bundled(fonts: Fonts) -> Vec<&'static [u8]> {
+    let mut bytes = Vec::new();
+
+    if fonts.text == TextFont::Inter {
+        bytes.extend([INTER_REGULAR_BYTES, INTER_BOLD_BYTES]);
+    }
+
+    if let IconFont::Fallback(fallback) = fonts.icons {
+        bytes.push(fallback.bytes);
+    }
+
+    bytes
+}
+
+pub fn active() -> Fonts {
+    *ACTIVE.read().expect("font lock poisoned")
+}
+
+pub fn regular() -> Font {
+    match active().text {
+        TextFont::Segoe => SEGOE,
+        TextFont::Inter => INTER,
+    }
+}
+
+pub fn semibold() -> Font {
+    match active().text {
+        TextFont::Segoe => SEGOE_SEMIBOLD,
+        TextFont::Inter => INTER_SEMIBOLD,
+    }
+}
+
+pub fn bold() -> Font {
+    match active().text {
+        TextFont::Segoe => SEGOE_BOLD,
+        TextFont::Inter => INTER_BOLD,
+    }
+}
+
+pub fn icons() -> Font {
+    match active().icons {
+        IconFont::SegoeFluentIcons | IconFont::Missing => SEGOE_FLUENT_ICONS,
+        IconFont::Fallback(fallback) => fallback.font,
+    }
+}
+
+/// Character to draw with [`icons`] for the Segoe Fluent Icons `codepoint`.
+pub fn glyph(codepoint: char) -> char {
+    match active().icons {
+        IconFont::SegoeFluentIcons | IconFont::Missing => codepoint,
+        IconFont::Fallback(fallback) => FluentIcon::from_codepoint(codepoint)
+            .and_then(fallback.map)
+            .unwrap_or(codepoint),
+    }
+}
+
+/// Whether [`icons`] can draw `icon`, otherwise it renders as a box.
+pub fn has_glyph(icon: FluentIcon) -> bool {
+    match active().icons {
+        IconFont::SegoeFluentIcons => true,
+        IconFont::Fallback(fallback) => (fallback.map)(icon).is_some(),
+        IconFont::Missing => false,
+    }
+}
@@ -0,0 +1,45 @@
+//! Widgets, styles and a theme for building [Iced] apps in the style of
+//! [Fluent 2] and WinUI 3.
+//!
+//! The [`theme::Theme`] implements the style catalogs of the Iced widgets, so
+//! the constructors in [`widget`] can be mixed freely with the ones from
+//! `iced::widget` as long as the application uses [`theme::Theme`].
+//!
+//! ```no_run
+//! use fluent_iced::{theme::Theme, widget::{button, text, Element}};
+//!
+//! fn view(_state: &()) -> Element<'_, ()> {
+//!     button::standard(text::body1("Hello")).on_press(()).into()
+//! }
+//!
+//! iced::application("Fluent", |_: &mut (), _: ()| {}, view)
+//!     .theme(|_| Theme::Dark)
+//!     .run();
+//! ```
+//!
+//! Widgets backed by [iced_aw] sit behind cargo features so apps which don't
+//! need them can avoid that dependency:
+//!
+//! - `drop_down`: [`widget::button::split`] and the `DropDown` alias
+//! - `menu`: [`widget::menu`] and the menu bar style
+//! - `number_input`: [`widget::number_input`]
+//! - `wrap`: the `Wrap` alias
+//! - `ribbon`: [`widget::ribbon`] (enables `drop_down`)
+//! - `side_nav`: [`widget::side_nav`] (enables `drop_down`)
+//!
+//! Loading themes from files with [`theme::file`] needs the `theme_file` feature.
+//!
+//! All of them are enabled by default.
+//!
+//! [Iced]: https://github.com/iced-rs/iced
+//! [Fluent 2]: https://fluent2.microsoft.design
+//! [iced_aw]: https://github.com/iced-rs/iced_aw
+
+pub mod fluent_icon;
+pub mod font;
+pub mod style;
+pub mod theme;
+pub mod widget;
+
+pub use fluent_icon::FluentIcon;
+pub use theme::{Palette, Theme};
@@ -0,0 +1,25 @@
+//! Style functions and catalog implementations for [`crate::theme::Theme`].
+//!
+//! Each module mirrors a widget and exposes plain `fn(&Theme, Status) -> Style`
+//! functions which can be passed to the widget's `style` method.
+
+pub mod button;
+pub mod checkbox;
+pub mod code_block;
+pub mod combo_box;
+pub mod container;
+pub mod drag_handle;
+pub mod menu;
+#[cfg(feature = "menu")]
+pub mod menu_bar;
+#[cfg(feature = "number_input")]
+pub mod number_input;
+pub mod pick_list;
+pub mod quad;
+pub mod radio;
+pub mod scrollable;
+pub mod slider;
+pub mod svg;
+pub mod text_input;
+pub mod toggler;
+pub mod underline;
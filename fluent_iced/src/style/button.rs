@@ -1,4 +1,4 @@
-use crate::theme::Theme;
+use crate::theme::{SystemColours, Theme};
 
 use iced::widget::button::{self, Status, Style, StyleFn};
 use iced::Color;
@@ -29,6 +29,19 @@ pub fn primary(theme: &Theme, status: Status) -> Style {
         shadow: Shadow::default(),
     };
 
+    if let Some(colours) = theme.high_contrast() {
+        // Accent buttons swap the highlight colours instead of using the highlight fill
+        return match status {
+            Status::Hovered | Status::Pressed => Style {
+                background: Some(colours.highlight_text.into()),
+                text_color: colours.highlight,
+                border: base.border.color(colours.highlight),
+                ..base
+            },
+            status => high_contrast(colours, status, base),
+        };
+    }
+
     match status {
         Status::Active => base,
         Status::Hovered => base.with_background(palette.accent_fill_color_secondary),
@@ -60,6 +73,17 @@ pub fn secondary(theme: &Theme, status: Status) -> Style {
         shadow: Shadow::default(),
     };
 
+    if let Some(colours) = theme.high_contrast() {
+        return high_contrast(
+            colours,
+            status,
+            Style {
+                text_color: colours.button_text,
+                ..base
+            },
+        );
+    }
+
     match status {
         Status::Active => base,
         Status::Hovered => base.with_background(palette.control_fill_color_secondary),
@@ -113,6 +137,10 @@ pub fn transparent(theme: &Theme, status: Status) -> Style {
         shadow: Shadow::default(),
     };
 
+    if let Some(colours) = theme.high_contrast() {
+        return high_contrast(colours, status, base);
+    }
+
     match status {
         Status::Active => base,
         Status::Hovered => Style {
@@ -131,6 +159,41 @@ pub fn transparent(theme: &Theme, status: Status) -> Style {
     }
 }
 
+/// The halves of the large split buttons of ribbons, outlined while hovered.
+pub fn ribbon_split(theme: &Theme, status: Status) -> Style {
+    let palette = theme.palette();
+    let base = Style {
+        background: Some(palette.subtle_fill_color_transparent.into()),
+        text_color: palette.text_fill_color_primary,
+        border: Border {
+            color: palette.control_stroke_color_default,
+            radius: Radius::new(4),
+            width: 1.0,
+        },
+        shadow: Shadow::default(),
+    };
+
+    match status {
+        Status::Active => Style {
+            border: Border::default(),
+            ..base
+        },
+        Status::Hovered => Style {
+            background: Some(palette.subtle_fill_color_secondary.into()),
+            ..base
+        },
+        Status::Pressed => Style {
+            background: Some(palette.subtle_fill_color_tertiary.into()),
+            ..base
+        },
+        Status::Disabled => Style {
+            background: Some(palette.subtle_fill_color_disabled.into()),
+            text_color: palette.text_fill_color_disabled,
+            ..base
+        },
+    }
+}
+
 pub fn flyout(theme: &Theme, status: Status) -> Style {
     let palette = theme.palette();
     let base = secondary(theme, status);
@@ -158,6 +221,10 @@ pub fn menu_item(theme: &Theme, status: Status) -> Style {
         shadow: Shadow::default(),
     };
 
+    if let Some(colours) = theme.high_contrast() {
+        return high_contrast(colours, status, base);
+    }
+
     match status {
         Status::Active => base,
         Status::Hovered => Style {
@@ -192,10 +259,67 @@ pub fn nav_item(theme: &Theme, status: Status) -> Style {
         Status::Disabled => palette.text_fill_color_disabled,
     };
 
-    Style {
+    let base = Style {
         background,
         text_color,
         border: Border::default().rounded(4),
         shadow: Shadow::default(),
+    };
+
+    match theme.high_contrast() {
+        Some(colours) => high_contrast(colours, status, base),
+        None => base,
+    }
+}
+
+pub fn nav_item_selected(theme: &Theme, status: Status) -> Style {
+    let palette = theme.palette();
+
+    if let Some(colours) = theme.high_contrast() {
+        return match status {
+            Status::Disabled => high_contrast(colours, status, nav_item(theme, status)),
+            _ => Style {
+                background: Some(colours.highlight.into()),
+                text_color: colours.highlight_text,
+                border: Border::default().rounded(4),
+                shadow: Shadow::default(),
+            },
+        };
+    }
+
+    let background = match status {
+        Status::Active => Some(palette.subtle_fill_color_secondary.into()),
+        Status::Hovered => Some(palette.subtle_fill_color_tertiary.into()),
+        Status::Pressed => Some(palette.subtle_fill_color_secondary.into()),
+        Status::Disabled => None,
+    };
+
+    Style {
+        background,
+        ..nav_item(theme, status)
+    }
+}
+
+// Contrast themes draw hovered and pressed controls with the highlight colours and
+// disabled ones in grey, `active` being the style of the control at rest.
+fn high_contrast(colours: &SystemColours, status: Status, active: Style) -> Style {
+    match status {
+        Status::Active => active,
+        Status::Hovered | Status::Pressed => Style {
+            background: Some(colours.highlight.into()),
+            text_color: colours.highlight_text,
+            border: Border {
+                color: colours.highlight,
+                width: 1.0,
+                ..active.border
+            },
+            ..active
+        },
+        Status::Disabled => Style {
+            background: Some(colours.window.into()),
+            text_color: colours.gray_text,
+            border: active.border.color(colours.gray_text),
+            ..active
+        },
     }
 }
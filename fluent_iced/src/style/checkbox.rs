@@ -23,7 +23,7 @@ impl Catalog for Theme {
 pub fn primary(theme: &Theme, status: Status) -> Style {
     let palette = theme.palette();
 
-    match status {
+    let style = match status {
         Status::Active { is_checked } => Style {
             background: if is_checked {
                 palette.accent_fill_color_default.into()
@@ -74,5 +74,17 @@ pub fn primary(theme: &Theme, status: Status) -> Style {
             },
             text_color: Some(palette.text_fill_color_disabled),
         },
+    };
+
+    match (theme.high_contrast(), status) {
+        (Some(colours), Status::Hovered { .. }) => Style {
+            border: style.border.color(colours.highlight),
+            ..style
+        },
+        (Some(colours), Status::Disabled { is_checked: true }) => Style {
+            background: colours.gray_text.into(),
+            ..style
+        },
+        _ => style,
     }
 }
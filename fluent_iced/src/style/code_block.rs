@@ -0,0 +1,73 @@
+use crate::theme::Theme;
+
+use iced::{border::Radius, color, Background, Border, Color};
+
+pub type StyleFn<'a> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+pub struct Style {
+    pub background: Background,
+    pub border: Border,
+    /// Identifiers and punctuation.
+    pub text: Color,
+    pub keyword: Color,
+    pub type_name: Color,
+    pub function: Color,
+    /// Macros and attributes.
+    pub macro_name: Color,
+    pub string: Color,
+    pub number: Color,
+    pub comment: Color,
+}
+
+// Colours of the default Visual Studio themes, a few of them nudged to have a contrast
+// ratio of 4.5 on the card background of either theme
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.palette();
+
+    let base = Style {
+        background: palette.card_background_fill_color_default.into(),
+        border: Border {
+            color: palette.card_stroke_color_default,
+            width: 1.0,
+            radius: Radius::new(8),
+        },
+        text: palette.text_fill_color_primary,
+        keyword: color!(0x569CD6),
+        type_name: color!(0x4EC9B0),
+        function: color!(0xDCDCAA),
+        macro_name: color!(0xC586C0),
+        string: color!(0xCE9178),
+        number: color!(0xB5CEA8),
+        comment: color!(0x6FA05A),
+    };
+
+    if let Some(colours) = theme.high_contrast() {
+        return Style {
+            background: colours.window.into(),
+            border: base.border.color(colours.window_text),
+            text: colours.window_text,
+            keyword: colours.hotlight,
+            type_name: colours.window_text,
+            function: colours.window_text,
+            macro_name: colours.hotlight,
+            string: colours.window_text,
+            number: colours.window_text,
+            comment: colours.gray_text,
+        };
+    }
+
+    if theme.is_dark() {
+        base
+    } else {
+        Style {
+            keyword: color!(0x0000FF),
+            type_name: color!(0x267C96),
+            function: color!(0x795E26),
+            macro_name: color!(0xAF00DB),
+            string: color!(0xA31515),
+            number: color!(0x09835A),
+            comment: color!(0x008000),
+            ..base
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{
+    theme::{contrast, Theme},
+    widget::info_badge::Severity,
+};
+
+use iced::{
+    border::Radius,
+    widget::{
+        container,
+        container::{Style, StyleFn},
+    },
+    Border, Color, Shadow, Vector,
+};
+
+impl container::Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(transparent)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+pub fn transparent(_theme: &Theme) -> Style {
+    Style::default()
+}
+
+pub fn card(theme: &Theme) -> Style {
+    let palette = theme.palette();
+
+    Style::default()
+        .background(palette.card_background_fill_color_default)
+        .border(Border {
+            color: palette.card_stroke_color_default,
+            radius: Radius::new(4.0),
+            width: 1.0,
+        })
+}
+
+pub fn overlay(theme: &Theme) -> Style {
+    let palette = theme.palette();
+
+    Style {
+        text_color: Some(palette.text_fill_color_primary),
+        background: Some(palette.solid_background_fill_color_quarternary.into()),
+        border: Border {
+            color: palette.surface_stroke_color_flyout,
+            width: 1.0,
+            radius: Radius::new(8),
+        },
+        shadow: shadow(
+            theme,
+            Shadow {
+                color: Color {
+                    r: 0.0,
+                    g: 0.0,
+                    b: 0.0,
+                    a: 0.28,
+                },
+                offset: Vector { x: 0.0, y: 4.0 },
+                blur_radius: 8.0,
+            },
+        ),
+    }
+}
+
+pub fn dialog(theme: &Theme) -> Style {
+    let palette = theme.palette();
+
+    Style {
+        text_color: Some(palette.text_fill_color_primary),
+        background: Some(palette.solid_background_fill_color_base.into()),
+        border: Border {
+            color: palette.surface_stroke_color_default,
+            width: 1.0,
+            radius: Radius::new(12),
+        },
+        shadow: shadow(
+            theme,
+            Shadow {
+                color: Color {
+                    r: 0.0,
+                    g: 0.0,
+                    b: 0.0,
+                    a: 0.28,
+                },
+                offset: Vector { x: 0.0, y: 32.0 },
+                blur_radius: 64.0,
+            },
+        ),
+    }
+}
+
+pub fn nav_indicator(theme: &Theme) -> Style {
+    let colour = match theme.high_contrast() {
+        Some(colours) => colours.highlight_text,
+        None => theme.palette().accent_fill_color_default,
+    };
+
+    Style::default()
+        .background(colour)
+        .border(Border::default().rounded(1.5))
+}
+
+pub fn info_badge(theme: &Theme, severity: Severity) -> Style {
+    let palette = theme.palette();
+
+    let background = match severity {
+        Severity::Attention => palette.system_fill_color_attention,
+        Severity::Informational => palette.system_fill_color_solid_neutral,
+        Severity::Success => palette.system_fill_color_success,
+        Severity::Critical => palette.system_fill_color_critical,
+    };
+
+    // Status colours are window text in contrast themes, so the text is the window.
+    // Otherwise WinUI draws text on accent, but only the attention fill follows the
+    // accent, and text on accent is too faint on the neutral fill of the light theme
+    // or on the other fills when a light accent makes it dark. The primary or
+    // inverse text, one of which is light and the other dark, is used where either
+    // stands out more.
+    let text_color = match theme.high_contrast() {
+        Some(colours) => colours.window,
+        None => {
+            let ratio = |text| contrast::ratio(contrast::over(text, background), background);
+
+            [
+                palette.text_on_accent_fill_color_primary,
+                palette.text_fill_color_primary,
+                palette.text_fill_color_inverse,
+            ]
+            .into_iter()
+            .reduce(|best, text| {
+                if ratio(text) > ratio(best) {
+                    text
+                } else {
+                    best
+                }
+            })
+            .expect("there are text colours")
+        }
+    };
+
+    Style {
+        text_color: Some(text_color),
+        background: Some(background.into()),
+        border: Border::default().rounded(8),
+        shadow: Shadow::default(),
+    }
+}
+
+// Contrast themes outline surfaces with a solid border instead of a shadow
+fn shadow(theme: &Theme, shadow: Shadow) -> Shadow {
+    if theme.high_contrast().is_some() {
+        Shadow::default()
+    } else {
+        shadow
+    }
+}
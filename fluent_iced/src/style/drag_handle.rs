@@ -0,0 +1,60 @@
+use crate::theme;
+
+use iced::Color;
+
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+pub enum Status {
+    Active,
+    Hovered,
+    Dragged,
+}
+
+/// A line along the middle of the handle.
+pub struct Style {
+    pub colour: Color,
+    pub width: f32,
+}
+
+pub trait Catalog {
+    type Class<'a>;
+
+    fn default<'a>() -> Self::Class<'a>;
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+impl Catalog for theme::Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+pub fn default(theme: &theme::Theme, status: Status) -> Style {
+    let palette = theme.palette();
+
+    match (status, theme.high_contrast()) {
+        (Status::Active, _) => Style {
+            colour: Color::TRANSPARENT,
+            width: 0.0,
+        },
+        (Status::Hovered | Status::Dragged, Some(colours)) => Style {
+            colour: colours.highlight,
+            width: 3.0,
+        },
+        (Status::Hovered, None) => Style {
+            colour: palette.control_strong_stroke_color_default,
+            width: 3.0,
+        },
+        (Status::Dragged, None) => Style {
+            colour: palette.accent_fill_color_default,
+            width: 3.0,
+        },
+    }
+}
@@ -2,7 +2,7 @@ use crate::theme::Theme;
 
 use iced::{
     widget::overlay::menu::{Catalog, Style},
-    Border
+    Border,
 };
 
 pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
@@ -21,7 +21,21 @@ impl Catalog for Theme {
 
 pub fn default(theme: &Theme) -> Style {
     let palette = theme.palette();
-    
+
+    if let Some(colours) = theme.high_contrast() {
+        return Style {
+            background: colours.window.into(),
+            border: Border {
+                color: colours.window_text,
+                width: 1.0,
+                radius: 4.0.into(),
+            },
+            text_color: colours.window_text,
+            selected_background: colours.highlight.into(),
+            selected_text_color: colours.highlight_text,
+        };
+    }
+
     Style {
         background: palette.acrylic_in_app_fill_color_default_fallback.into(),
         border: Border {
@@ -33,4 +47,4 @@ pub fn default(theme: &Theme) -> Style {
         selected_background: palette.subtle_fill_color_secondary.into(),
         selected_text_color: palette.text_fill_color_primary,
     }
-}
\ No newline at end of file
+}
@@ -39,15 +39,20 @@ pub fn default(theme: &Theme, status: Status) -> Style {
             width: 1.0,
             radius: Radius::new(8),
         },
-        menu_shadow: Shadow {
-            color: Color {
-                r: 0.0,
-                g: 0.0,
-                b: 0.0,
-                a: 0.28,
-            },
-            offset: Vector { x: 0.0, y: 4.0 },
-            blur_radius: 8.0,
+        // Contrast themes outline the menu with a solid border instead of a shadow
+        menu_shadow: if theme.high_contrast().is_some() {
+            Shadow::default()
+        } else {
+            Shadow {
+                color: Color {
+                    r: 0.0,
+                    g: 0.0,
+                    b: 0.0,
+                    a: 0.28,
+                },
+                offset: Vector { x: 0.0, y: 4.0 },
+                blur_radius: 8.0,
+            }
         },
         menu_background_expand: Padding::default(),
         path: Color::TRANSPARENT.into(),
@@ -63,6 +68,19 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         ..base
     };
 
+    if let Some(colours) = theme.high_contrast() {
+        if let Status::Hovered | Status::Pressed = status {
+            return Style {
+                bar_border: Border {
+                    color: colours.highlight,
+                    width: 1.0,
+                    radius: Radius::new(4),
+                },
+                ..base
+            };
+        }
+    }
+
     match status {
         Status::Active => base,
         Status::Hovered => Style {
@@ -30,6 +30,15 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         icon_color: palette.text_fill_color_secondary,
     };
 
+    if let Some(colours) = theme.high_contrast() {
+        if let Status::Hovered | Status::Pressed = status {
+            return Style {
+                button_background: Some(colours.highlight.into()),
+                icon_color: colours.highlight_text,
+            };
+        }
+    }
+
     match status {
         Status::Active => base,
         Status::Hovered => Style {
@@ -40,7 +49,10 @@ pub fn default(theme: &Theme, status: Status) -> Style {
             button_background: Some(palette.subtle_fill_color_tertiary.into()),
             ..base
         },
-        Status::Disabled => base,
+        Status::Disabled => Style {
+            icon_color: palette.text_fill_color_disabled,
+            ..base
+        },
         Status::Focused => base,
         Status::Selected => base,
     }
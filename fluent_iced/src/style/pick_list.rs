@@ -2,7 +2,7 @@ use crate::theme::Theme;
 
 use iced::{
     widget::pick_list::{Catalog, Status, Style},
-    Border
+    Border,
 };
 
 pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
@@ -34,6 +34,22 @@ pub fn default(theme: &Theme, status: Status) -> Style {
         },
     };
 
+    if let Some(colours) = theme.high_contrast() {
+        let base = Style {
+            text_color: colours.button_text,
+            handle_color: colours.button_text,
+            ..base
+        };
+
+        return match status {
+            Status::Active => base,
+            Status::Hovered | Status::Opened => Style {
+                border: base.border.color(colours.highlight),
+                ..base
+            },
+        };
+    }
+
     match status {
         Status::Active => base,
         Status::Hovered => Style {
@@ -48,6 +48,9 @@ pub fn default(theme: &Theme, status: Status) -> Style {
             } else {
                 Style {
                     background: palette.control_alt_fill_color_tertiary.into(),
+                    border_color: theme
+                        .high_contrast()
+                        .map_or(base.border_color, |colours| colours.highlight),
                     ..base
                 }
             }
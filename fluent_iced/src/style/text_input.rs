@@ -31,10 +31,26 @@ fn default(theme: &Theme, status: Status) -> Style {
         },
         icon: palette.text_fill_color_primary,
         placeholder: palette.text_fill_color_secondary,
-        selection: palette.accent_fill_color_default,
+        selection: palette.accent_fill_color_selected_text_background,
         value: palette.text_fill_color_primary,
     };
 
+    if let Some(colours) = theme.high_contrast() {
+        return match status {
+            Status::Active => base,
+            Status::Hovered | Status::Focused => Style {
+                border: base.border.color(colours.highlight),
+                ..base
+            },
+            Status::Disabled => Style {
+                border: base.border.color(colours.gray_text),
+                placeholder: colours.gray_text,
+                value: colours.gray_text,
+                ..base
+            },
+        };
+    }
+
     match status {
         Status::Active => base,
         Status::Hovered => Style {
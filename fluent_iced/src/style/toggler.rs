@@ -48,12 +48,15 @@ pub fn default(theme: &Theme, status: Status) -> Style {
                 Style {
                     background: palette.accent_fill_color_secondary,
                     background_border_color: palette.accent_fill_color_secondary,
-                    foreground: palette.text_on_accent_fill_color_secondary,
+                    foreground: palette.text_on_accent_fill_color_primary,
                     ..base
                 }
             } else {
                 Style {
                     background: palette.control_alt_fill_color_tertiary,
+                    background_border_color: theme
+                        .high_contrast()
+                        .map_or(base.background_border_color, |colours| colours.highlight),
                     ..base
                 }
             }
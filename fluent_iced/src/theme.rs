@@ -0,0 +1,497 @@
+//! The Fluent [`Theme`] and the colour tokens of its [`Palette`].
+
+mod accent;
+pub mod contrast;
+#[cfg(feature = "theme_file")]
+pub mod file;
+pub mod high_contrast;
+
+pub use accent::AccentRamp;
+pub use high_contrast::{Contrast, SystemColours};
+
+use std::sync::Arc;
+
+use iced::{application, color, Color};
+
+/// Fluent theme used as the `Theme` of every widget in this crate.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub enum Theme {
+    Light,
+    #[default]
+    Dark,
+    HighContrast(Contrast),
+    Custom(Arc<Palette>),
+}
+
+impl Theme {
+    pub fn palette(&self) -> &Palette {
+        match self {
+            Theme::Dark => &Palette::DARK,
+            Theme::Light => &Palette::LIGHT,
+            Theme::HighContrast(contrast) => contrast.palette(),
+            Theme::Custom(palette) => palette,
+        }
+    }
+
+    pub fn is_dark(&self) -> bool {
+        self.palette().is_dark()
+    }
+
+    /// System colours of a contrast theme, which style functions use in place of
+    /// the translucent fills of the palette.
+    pub fn high_contrast(&self) -> Option<&'static SystemColours> {
+        match self {
+            Theme::HighContrast(contrast) => Some(contrast.system_colours()),
+            _ => None,
+        }
+    }
+
+    /// Copy of this theme with the accent colours derived from `accent`.
+    ///
+    /// Contrast themes have no accent colour and are returned unchanged.
+    pub fn with_accent(&self, accent: Color) -> Theme {
+        match self {
+            Theme::HighContrast(_) => self.clone(),
+            _ => Theme::Custom(Arc::new(self.palette().with_accent(accent))),
+        }
+    }
+}
+
+impl std::fmt::Display for Theme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Theme::Light => write!(f, "Light"),
+            Theme::Dark => write!(f, "Dark"),
+            Theme::HighContrast(contrast) => write!(f, "{contrast}"),
+            Theme::Custom(_) => write!(f, "Custom"),
+        }
+    }
+}
+
+impl application::DefaultStyle for Theme {
+    fn default_style(&self) -> application::Appearance {
+        let palette = self.palette();
+
+        application::Appearance {
+            background_color: palette.solid_background_fill_color_base,
+            text_color: palette.text_fill_color_primary,
+        }
+    }
+}
+
+macro_rules! from_argb {
+    ($hex:expr) => {{
+        let hex = $hex as u32;
+
+        let a = ((hex & 0xff000000) >> 24) as f32 / 255.0;
+        let r = (hex & 0x00ff0000) >> 16;
+        let g = (hex & 0x0000ff00) >> 8;
+        let b = (hex & 0x000000ff);
+
+        color!(r, g, b, a)
+    }};
+}
+
+// Defines the Palette struct along with a table of its token names so that
+// theme files can refer to tokens by name.
+macro_rules! palette {
+    (
+        $(#[$meta:meta])*
+        pub struct Palette {
+            $(pub $token:ident: Color,)*
+        }
+    ) => {
+        $(#[$meta])*
+        pub struct Palette {
+            $(pub $token: Color,)*
+        }
+
+        impl Palette {
+            /// Names of every colour token, as used in theme files.
+            pub const TOKENS: &'static [&'static str] = &[$(stringify!($token)),*];
+
+            /// Colour of the token called `name`.
+            pub fn token(&self, name: &str) -> Option<Color> {
+                match name {
+                    $(stringify!($token) => Some(self.$token),)*
+                    _ => None,
+                }
+            }
+
+            /// Mutable colour of the token called `name`.
+            pub fn token_mut(&mut self, name: &str) -> Option<&mut Color> {
+                match name {
+                    $(stringify!($token) => Some(&mut self.$token),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+// Requires "web-colors" feature to better match WinUI 3 colours
+// https://github.com/iced-rs/iced/pull/1888
+// There are still some slight colour variation but the biggest issue is text renderering
+// https://github.com/iced-rs/iced/issues/2254
+
+// Windows theme colour stored in HKEY_CURRENT_USER\Software\Microsoft\Windows\CurrentVersion\Explorer\Accent
+
+palette! {
+    /// Colour tokens named after the WinUI 3 theme resources.
+    ///
+    /// Only the fills WinUI defines as `#00FFFFFF` are fully transparent in the
+    /// built-in palettes:
+    ///
+    /// ```
+    /// use fluent_iced::theme::Palette;
+    ///
+    /// let transparent = |token: &str| {
+    ///     token.ends_with("_transparent")
+    ///         || token == "subtle_fill_color_disabled"
+    ///         || token == "control_alt_fill_color_disabled"
+    ///         || token == "control_on_image_fill_color_disabled"
+    /// };
+    ///
+    /// for palette in [Palette::DARK, Palette::LIGHT] {
+    ///     for token in Palette::TOKENS.iter().filter(|token| !transparent(token)) {
+    ///         assert!(palette.token(token).unwrap().a > 0.0, "{token} is transparent");
+    ///     }
+    /// }
+    /// ```
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Palette {
+        // Accent colours are hard-coded as Iced does not provide system theme colours (yet).
+        // https://github.com/microsoft/microsoft-ui-xaml/blob/63671e055eadfd74806f9800382b8bd6c9999b4a/dxaml/xcp/components/theminginterop/SystemThemingInterop.cpp#L226
+        pub accent_fill_color_default: Color,
+        pub accent_fill_color_secondary: Color,
+        pub accent_fill_color_tertiary: Color,
+
+        // Acrylic effects are not implemented so use fallback colours:
+        // https://github.com/microsoft/microsoft-ui-xaml/blob/4c50e610e537aca92afc950c4be1ffb60c2f99d5/dev/Materials/Acrylic/AcrylicBrush_rs3_themeresources.xaml
+        pub acrylic_in_app_fill_color_default_fallback: Color,
+
+        // Colors from WinUI3 source code:
+        // https://github.com/microsoft/microsoft-ui-xaml/blob/winui3/release/1.5-stable/controls/dev/CommonStyles/Common_themeresources_any.xaml
+        // The values in DARK and LIGHT are updated from a copy of it with src/bin/palette_codegen.rs
+        pub text_fill_color_primary: Color,
+        pub text_fill_color_secondary: Color,
+        pub text_fill_color_tertiary: Color,
+        pub text_fill_color_disabled: Color,
+        pub text_fill_color_inverse: Color,
+        pub accent_text_fill_color_primary: Color,
+        pub accent_text_fill_color_secondary: Color,
+        pub accent_text_fill_color_tertiary: Color,
+        pub accent_text_fill_color_disabled: Color,
+        pub text_on_accent_fill_color_selected_text: Color,
+        pub text_on_accent_fill_color_primary: Color,
+        pub text_on_accent_fill_color_secondary: Color,
+        pub text_on_accent_fill_color_disabled: Color,
+        pub control_fill_color_default: Color,
+        pub control_fill_color_secondary: Color,
+        pub control_fill_color_tertiary: Color,
+        pub control_fill_color_disabled: Color,
+        pub control_fill_color_transparent: Color,
+        pub control_fill_color_input_active: Color,
+        pub control_strong_fill_color_default: Color,
+        pub control_strong_fill_color_disabled: Color,
+        pub control_solid_fill_color_default: Color,
+        pub subtle_fill_color_transparent: Color,
+        pub subtle_fill_color_secondary: Color,
+        pub subtle_fill_color_tertiary: Color,
+        pub subtle_fill_color_disabled: Color,
+        pub control_alt_fill_color_transparent: Color,
+        pub control_alt_fill_color_secondary: Color,
+        pub control_alt_fill_color_tertiary: Color,
+        pub control_alt_fill_color_quarternary: Color,
+        pub control_alt_fill_color_disabled: Color,
+        pub control_on_image_fill_color_default: Color,
+        pub control_on_image_fill_color_secondary: Color,
+        pub control_on_image_fill_color_tertiary: Color,
+        pub control_on_image_fill_color_disabled: Color,
+        pub accent_fill_color_disabled: Color,
+        pub accent_fill_color_selected_text_background: Color,
+        pub control_stroke_color_default: Color,
+        pub control_stroke_color_secondary: Color,
+        pub control_stroke_color_on_accent_default: Color,
+        pub control_stroke_color_on_accent_secondary: Color,
+        pub control_stroke_color_on_accent_tertiary: Color,
+        pub control_stroke_color_on_accent_disabled: Color,
+        pub control_stroke_color_for_strong_fill_when_on_image: Color,
+        pub card_stroke_color_default: Color,
+        pub card_stroke_color_default_solid: Color,
+        pub control_strong_stroke_color_default: Color,
+        pub control_strong_stroke_color_disabled: Color,
+        pub surface_stroke_color_default: Color,
+        pub surface_stroke_color_flyout: Color,
+        pub surface_stroke_color_inverse: Color,
+        pub divider_stroke_color_default: Color,
+        pub focus_stroke_color_outer: Color,
+        pub focus_stroke_color_inner: Color,
+        pub card_background_fill_color_default: Color,
+        pub card_background_fill_color_secondary: Color,
+        pub smoke_fill_color_default: Color,
+        pub layer_fill_color_default: Color,
+        pub layer_fill_color_alt: Color,
+        pub layer_on_acrylic_fill_color_default: Color,
+        pub layer_on_accent_acrylic_fill_color_default: Color,
+        pub layer_on_mica_base_alt_fill_color_default: Color,
+        pub layer_on_mica_base_alt_fill_color_secondary: Color,
+        pub layer_on_mica_base_alt_fill_color_tertiary: Color,
+        pub layer_on_mica_base_alt_fill_color_transparent: Color,
+        pub solid_background_fill_color_base: Color,
+        pub solid_background_fill_color_secondary: Color,
+        pub solid_background_fill_color_tertiary: Color,
+        pub solid_background_fill_color_quarternary: Color,
+        pub solid_background_fill_color_transparent: Color,
+        pub solid_background_fill_color_base_alt: Color,
+        pub system_fill_color_attention: Color,
+        pub system_fill_color_success: Color,
+        pub system_fill_color_caution: Color,
+        pub system_fill_color_critical: Color,
+        pub system_fill_color_neutral: Color,
+        pub system_fill_color_solid_neutral: Color,
+        pub system_fill_color_attention_background: Color,
+        pub system_fill_color_success_background: Color,
+        pub system_fill_color_caution_background: Color,
+        pub system_fill_color_critical_background: Color,
+        pub system_fill_color_neutral_background: Color,
+        pub system_fill_color_solid_attention_background: Color,
+        pub system_fill_color_solid_neutral_background: Color,
+    }
+}
+
+impl Palette {
+    /// Whether text and controls are drawn on a dark background.
+    pub fn is_dark(&self) -> bool {
+        contrast::relative_luminance(self.solid_background_fill_color_base) < 0.5
+    }
+
+    /// WCAG contrast ratio of `foreground` drawn over `background`, with both
+    /// blended over the window background first as most tokens are translucent.
+    pub fn contrast_ratio(&self, foreground: Color, background: Color) -> f32 {
+        let background = contrast::over(background, self.solid_background_fill_color_base);
+
+        contrast::ratio(contrast::over(foreground, background), background)
+    }
+
+    /// Copy of this palette with the accent fills and accent text derived from
+    /// `accent`, and text on accent switched between black and white to keep it
+    /// readable.
+    pub fn with_accent(&self, accent: Color) -> Self {
+        let ramp = AccentRamp::new(accent);
+
+        // Same shades as WinUI, lighter ones on the dark theme and darker ones on the light
+        let (fill, [accent_primary, accent_secondary, accent_tertiary]) = if self.is_dark() {
+            (ramp.light2, [ramp.light3, ramp.light3, ramp.light2])
+        } else {
+            (ramp.dark1, [ramp.dark2, ramp.dark3, ramp.dark1])
+        };
+
+        let (text_primary, text_secondary) =
+            if contrast::ratio(fill, Color::BLACK) > contrast::ratio(fill, Color::WHITE) {
+                (color!(0x000000), from_argb!(0x80000000))
+            } else {
+                (color!(0xFFFFFF), from_argb!(0xB3FFFFFF))
+            };
+
+        Self {
+            accent_fill_color_default: fill,
+            accent_fill_color_secondary: Color { a: 0.9, ..fill },
+            accent_fill_color_tertiary: Color { a: 0.8, ..fill },
+            accent_fill_color_selected_text_background: ramp.base,
+            accent_text_fill_color_primary: accent_primary,
+            accent_text_fill_color_secondary: accent_secondary,
+            accent_text_fill_color_tertiary: accent_tertiary,
+            text_on_accent_fill_color_primary: text_primary,
+            text_on_accent_fill_color_secondary: text_secondary,
+            system_fill_color_attention: fill,
+            ..self.clone()
+        }
+    }
+
+    pub const DARK: Self = Self {
+        accent_fill_color_default: from_argb!(0xFF76B9ED),
+        accent_fill_color_secondary: from_argb!(0xE676B9ED),
+        accent_fill_color_tertiary: from_argb!(0xCC76B9ED),
+
+        acrylic_in_app_fill_color_default_fallback: color!(0x2C2C2C),
+
+        text_fill_color_primary: color!(0xFFFFFF),
+        text_fill_color_secondary: from_argb!(0xC5FFFFFF),
+        text_fill_color_tertiary: from_argb!(0x87FFFFFF),
+        text_fill_color_disabled: from_argb!(0x5DFFFFFF),
+        text_fill_color_inverse: from_argb!(0xE4000000),
+        accent_text_fill_color_primary: color!(0xA6D8FF),
+        accent_text_fill_color_secondary: color!(0xA6D8FF),
+        accent_text_fill_color_tertiary: color!(0x76B9ED),
+        accent_text_fill_color_disabled: from_argb!(0x5DFFFFFF),
+        text_on_accent_fill_color_selected_text: color!(0xFFFFFF),
+        text_on_accent_fill_color_primary: color!(0x000000),
+        text_on_accent_fill_color_secondary: from_argb!(0x80000000),
+        text_on_accent_fill_color_disabled: from_argb!(0x87FFFFFF),
+        control_fill_color_default: from_argb!(0x0FFFFFFF),
+        control_fill_color_secondary: from_argb!(0x15FFFFFF),
+        control_fill_color_tertiary: from_argb!(0x0BFFFFFF),
+        control_fill_color_disabled: from_argb!(0x0BFFFFFF),
+        control_fill_color_transparent: from_argb!(0x00FFFFFF),
+        control_fill_color_input_active: from_argb!(0xB31E1E1E),
+        control_strong_fill_color_default: from_argb!(0x8BFFFFFF),
+        control_strong_fill_color_disabled: from_argb!(0x3FFFFFFF),
+        control_solid_fill_color_default: color!(0x454545),
+        subtle_fill_color_transparent: from_argb!(0x00FFFFFF),
+        subtle_fill_color_secondary: from_argb!(0x0FFFFFFF),
+        subtle_fill_color_tertiary: from_argb!(0x0AFFFFFF),
+        subtle_fill_color_disabled: from_argb!(0x00FFFFFF),
+        control_alt_fill_color_transparent: from_argb!(0x00FFFFFF),
+        control_alt_fill_color_secondary: from_argb!(0x19000000),
+        control_alt_fill_color_tertiary: from_argb!(0x0BFFFFFF),
+        control_alt_fill_color_quarternary: from_argb!(0x12FFFFFF),
+        control_alt_fill_color_disabled: from_argb!(0x00FFFFFF),
+        control_on_image_fill_color_default: from_argb!(0xB31C1C1C),
+        control_on_image_fill_color_secondary: color!(0x1A1A1A),
+        control_on_image_fill_color_tertiary: color!(0x131313),
+        control_on_image_fill_color_disabled: color!(0x1E1E1E),
+        accent_fill_color_disabled: from_argb!(0x28FFFFFF),
+        accent_fill_color_selected_text_background: color!(0x0078D7),
+        control_stroke_color_default: from_argb!(0x12FFFFFF),
+        control_stroke_color_secondary: from_argb!(0x18FFFFFF),
+        control_stroke_color_on_accent_default: from_argb!(0x14FFFFFF),
+        control_stroke_color_on_accent_secondary: from_argb!(0x23000000),
+        control_stroke_color_on_accent_tertiary: from_argb!(0x37000000),
+        control_stroke_color_on_accent_disabled: from_argb!(0x33000000),
+        control_stroke_color_for_strong_fill_when_on_image: from_argb!(0x6B000000),
+        card_stroke_color_default: from_argb!(0x19000000),
+        card_stroke_color_default_solid: color!(0x1C1C1C),
+        control_strong_stroke_color_default: from_argb!(0x8BFFFFFF),
+        control_strong_stroke_color_disabled: from_argb!(0x28FFFFFF),
+        surface_stroke_color_default: from_argb!(0x66757575),
+        surface_stroke_color_flyout: from_argb!(0x33000000),
+        surface_stroke_color_inverse: from_argb!(0x0F000000),
+        divider_stroke_color_default: from_argb!(0x15FFFFFF),
+        focus_stroke_color_outer: color!(0xFFFFFF),
+        focus_stroke_color_inner: from_argb!(0xB3000000),
+        card_background_fill_color_default: from_argb!(0x0DFFFFFF),
+        card_background_fill_color_secondary: from_argb!(0x08FFFFFF),
+        smoke_fill_color_default: from_argb!(0x4D000000),
+        layer_fill_color_default: from_argb!(0x4C3A3A3A),
+        layer_fill_color_alt: from_argb!(0x0DFFFFFF),
+        layer_on_acrylic_fill_color_default: from_argb!(0x09FFFFFF),
+        layer_on_accent_acrylic_fill_color_default: from_argb!(0x09FFFFFF),
+        layer_on_mica_base_alt_fill_color_default: from_argb!(0x733A3A3A),
+        layer_on_mica_base_alt_fill_color_secondary: from_argb!(0x0FFFFFFF),
+        layer_on_mica_base_alt_fill_color_tertiary: color!(0x2C2C2C),
+        layer_on_mica_base_alt_fill_color_transparent: from_argb!(0x00FFFFFF),
+        solid_background_fill_color_base: color!(0x202020),
+        solid_background_fill_color_secondary: color!(0x1C1C1C),
+        solid_background_fill_color_tertiary: color!(0x282828),
+        solid_background_fill_color_quarternary: color!(0x2C2C2C),
+        solid_background_fill_color_transparent: from_argb!(0x00202020),
+        solid_background_fill_color_base_alt: color!(0x0A0A0A),
+        system_fill_color_attention: color!(0x60CDFF),
+        system_fill_color_success: color!(0x6CCB5F),
+        system_fill_color_caution: color!(0xFCE100),
+        system_fill_color_critical: color!(0xFF99A4),
+        system_fill_color_neutral: from_argb!(0x8BFFFFFF),
+        system_fill_color_solid_neutral: color!(0x9D9D9D),
+        system_fill_color_attention_background: from_argb!(0x08FFFFFF),
+        system_fill_color_success_background: color!(0x393D1B),
+        system_fill_color_caution_background: color!(0x433519),
+        system_fill_color_critical_background: color!(0x442726),
+        system_fill_color_neutral_background: from_argb!(0x08FFFFFF),
+        system_fill_color_solid_attention_background: color!(0x2E2E2E),
+        system_fill_color_solid_neutral_background: color!(0x2E2E2E),
+    };
+
+    pub const LIGHT: Self = Self {
+        accent_fill_color_default: from_argb!(0xFF005A9E),
+        accent_fill_color_secondary: from_argb!(0xE6005A9E),
+        accent_fill_color_tertiary: from_argb!(0xCC005A9E),
+
+        acrylic_in_app_fill_color_default_fallback: color!(0xF9F9F9),
+
+        text_fill_color_primary: from_argb!(0xE4000000),
+        text_fill_color_secondary: from_argb!(0x9E000000),
+        text_fill_color_tertiary: from_argb!(0x72000000),
+        text_fill_color_disabled: from_argb!(0x5C000000),
+        text_fill_color_inverse: color!(0xFFFFFF),
+        accent_text_fill_color_primary: color!(0x004275),
+        accent_text_fill_color_secondary: color!(0x002642),
+        accent_text_fill_color_tertiary: color!(0x005A9E),
+        accent_text_fill_color_disabled: from_argb!(0x5C000000),
+        text_on_accent_fill_color_selected_text: color!(0xFFFFFF),
+        text_on_accent_fill_color_primary: color!(0xFFFFFF),
+        text_on_accent_fill_color_secondary: from_argb!(0xB3FFFFFF),
+        text_on_accent_fill_color_disabled: color!(0xFFFFFF),
+        control_fill_color_default: from_argb!(0xB3FFFFFF),
+        control_fill_color_secondary: from_argb!(0x80F9F9F9),
+        control_fill_color_tertiary: from_argb!(0x4DF9F9F9),
+        control_fill_color_disabled: from_argb!(0x4DF9F9F9),
+        control_fill_color_transparent: from_argb!(0x00FFFFFF),
+        control_fill_color_input_active: color!(0xFFFFFF),
+        control_strong_fill_color_default: from_argb!(0x72000000),
+        control_strong_fill_color_disabled: from_argb!(0x51000000),
+        control_solid_fill_color_default: color!(0xFFFFFF),
+        subtle_fill_color_transparent: from_argb!(0x00FFFFFF),
+        subtle_fill_color_secondary: from_argb!(0x09000000),
+        subtle_fill_color_tertiary: from_argb!(0x06000000),
+        subtle_fill_color_disabled: from_argb!(0x00FFFFFF),
+        control_alt_fill_color_transparent: from_argb!(0x00FFFFFF),
+        control_alt_fill_color_secondary: from_argb!(0x06000000),
+        control_alt_fill_color_tertiary: from_argb!(0x0F000000),
+        control_alt_fill_color_quarternary: from_argb!(0x18000000),
+        control_alt_fill_color_disabled: from_argb!(0x00FFFFFF),
+        control_on_image_fill_color_default: from_argb!(0xC9FFFFFF),
+        control_on_image_fill_color_secondary: color!(0xF3F3F3),
+        control_on_image_fill_color_tertiary: color!(0xEBEBEB),
+        control_on_image_fill_color_disabled: from_argb!(0x00FFFFFF),
+        accent_fill_color_disabled: from_argb!(0x37000000),
+        accent_fill_color_selected_text_background: color!(0x0078D7),
+        control_stroke_color_default: from_argb!(0x0F000000),
+        control_stroke_color_secondary: from_argb!(0x29000000),
+        control_stroke_color_on_accent_default: from_argb!(0x14FFFFFF),
+        control_stroke_color_on_accent_secondary: from_argb!(0x66000000),
+        control_stroke_color_on_accent_tertiary: from_argb!(0x37000000),
+        control_stroke_color_on_accent_disabled: from_argb!(0x0F000000),
+        control_stroke_color_for_strong_fill_when_on_image: from_argb!(0x59FFFFFF),
+        card_stroke_color_default: from_argb!(0x0F000000),
+        card_stroke_color_default_solid: color!(0xEBEBEB),
+        control_strong_stroke_color_default: from_argb!(0x72000000),
+        control_strong_stroke_color_disabled: from_argb!(0x37000000),
+        surface_stroke_color_default: from_argb!(0x66757575),
+        surface_stroke_color_flyout: from_argb!(0x0F000000),
+        surface_stroke_color_inverse: from_argb!(0x15FFFFFF),
+        divider_stroke_color_default: from_argb!(0x0F000000),
+        focus_stroke_color_outer: from_argb!(0xE4000000),
+        focus_stroke_color_inner: from_argb!(0xB3FFFFFF),
+        card_background_fill_color_default: from_argb!(0xB3FFFFFF),
+        card_background_fill_color_secondary: from_argb!(0x80F6F6F6),
+        smoke_fill_color_default: from_argb!(0x4D000000),
+        layer_fill_color_default: from_argb!(0x80FFFFFF),
+        layer_fill_color_alt: color!(0xFFFFFF),
+        layer_on_acrylic_fill_color_default: from_argb!(0x40FFFFFF),
+        layer_on_accent_acrylic_fill_color_default: from_argb!(0x40FFFFFF),
+        layer_on_mica_base_alt_fill_color_default: from_argb!(0xB3FFFFFF),
+        layer_on_mica_base_alt_fill_color_secondary: from_argb!(0x0A000000),
+        layer_on_mica_base_alt_fill_color_tertiary: color!(0xF9F9F9),
+        layer_on_mica_base_alt_fill_color_transparent: from_argb!(0x00000000),
+        solid_background_fill_color_base: color!(0xF3F3F3),
+        solid_background_fill_color_secondary: color!(0xEEEEEE),
+        solid_background_fill_color_tertiary: color!(0xF9F9F9),
+        solid_background_fill_color_quarternary: color!(0xFFFFFF),
+        solid_background_fill_color_transparent: from_argb!(0x00F3F3F3),
+        solid_background_fill_color_base_alt: color!(0xDADADA),
+        system_fill_color_attention: color!(0x005FB7),
+        system_fill_color_success: color!(0x0F7B0F),
+        system_fill_color_caution: color!(0x9D5D00),
+        system_fill_color_critical: color!(0xC42B1C),
+        system_fill_color_neutral: from_argb!(0x72000000),
+        system_fill_color_solid_neutral: color!(0x8A8A8A),
+        system_fill_color_attention_background: from_argb!(0x80F6F6F6),
+        system_fill_color_success_background: color!(0xDFF6DD),
+        system_fill_color_caution_background: color!(0xFFF4CE),
+        system_fill_color_critical_background: color!(0xFDE7E9),
+        system_fill_color_neutral_background: from_argb!(0x06000000),
+        system_fill_color_solid_attention_background: color!(0xF7F7F7),
+        system_fill_color_solid_neutral_background: color!(0xF3F3F3),
+    };
+}
@@ -0,0 +1,51 @@
+//! Shades of an accent colour, derived the way the Windows personalisation settings do.
+
+use iced::Color;
+
+// Fractions to mix towards white or black, fitted to the default Windows blue (#0078D7)
+// and its SystemAccentColorLight1-3 and SystemAccentColorDark1-3 shades.
+const STEPS: [f32; 3] = [0.26, 0.46, 0.69];
+
+/// An accent colour and its lighter and darker shades, named after the
+/// `SystemAccentColor` resources of WinUI.
+///
+/// [`Palette::with_accent`](super::Palette::with_accent) derives the accent fills
+/// and accent text from the light shades in dark themes and from the dark shades
+/// in light themes, as WinUI does. No token uses `light1`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AccentRamp {
+    pub base: Color,
+    pub light1: Color,
+    pub light2: Color,
+    pub light3: Color,
+    pub dark1: Color,
+    pub dark2: Color,
+    pub dark3: Color,
+}
+
+impl AccentRamp {
+    pub fn new(base: Color) -> Self {
+        let base = Color { a: 1.0, ..base };
+        let [light1, light2, light3] = STEPS.map(|step| mix(base, Color::WHITE, step));
+        let [dark1, dark2, dark3] = STEPS.map(|step| mix(base, Color::BLACK, step));
+
+        Self {
+            base,
+            light1,
+            light2,
+            light3,
+            dark1,
+            dark2,
+            dark3,
+        }
+    }
+}
+
+fn mix(from: Color, to: Color, amount: f32) -> Color {
+    Color {
+        r: from.r + (to.r - from.r) * amount,
+        g: from.g + (to.g - from.g) * amount,
+        b: from.b + (to.b - from.b) * amount,
+        a: 1.0,
+    }
+}
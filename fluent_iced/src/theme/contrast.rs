@@ -0,0 +1,63 @@
+//! Contrast ratios as defined by WCAG 2.x, for checking that text and controls
+//! stay readable on the fills they are drawn over.
+//!
+//! Most fills of the palette are translucent, so the colours are blended over
+//! the window background before they are compared, as they would be on screen.
+//!
+//! ```
+//! use fluent_iced::theme::{contrast, Palette};
+//!
+//! let palette = Palette::LIGHT;
+//! let ratio = palette.contrast_ratio(
+//!     palette.text_on_accent_fill_color_primary,
+//!     palette.accent_fill_color_default,
+//! );
+//!
+//! assert!(ratio >= contrast::TEXT);
+//! ```
+
+use iced::Color;
+
+/// Minimum ratio of body text to its background, success criterion 1.4.3 (AA).
+pub const TEXT: f32 = 4.5;
+
+/// Minimum ratio of the parts of a control needed to see its state, such as the
+/// check mark of a checkbox, success criterion 1.4.11 (AA).
+pub const NON_TEXT: f32 = 3.0;
+
+/// Colour seen when `top` is drawn over the opaque `bottom`.
+pub fn over(top: Color, bottom: Color) -> Color {
+    let blend = |channel: f32, under: f32| channel * top.a + under * (1.0 - top.a);
+
+    Color {
+        r: blend(top.r, bottom.r),
+        g: blend(top.g, bottom.g),
+        b: blend(top.b, bottom.b),
+        a: 1.0,
+    }
+}
+
+/// [Relative luminance] of an opaque colour, from 0 for black to 1 for white.
+///
+/// [Relative luminance]: https://www.w3.org/TR/WCAG21/#dfn-relative-luminance
+pub fn relative_luminance(color: Color) -> f32 {
+    let linear = |channel: f32| {
+        if channel <= 0.04045 {
+            channel / 12.92
+        } else {
+            ((channel + 0.055) / 1.055).powf(2.4)
+        }
+    };
+
+    0.2126 * linear(color.r) + 0.7152 * linear(color.g) + 0.0722 * linear(color.b)
+}
+
+/// [Contrast ratio] of two opaque colours, from 1 for the same colour to 21 for
+/// black and white. The alpha of both colours is ignored, see [`over`].
+///
+/// [Contrast ratio]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+pub fn ratio(a: Color, b: Color) -> f32 {
+    let (a, b) = (relative_luminance(a), relative_luminance(b));
+
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
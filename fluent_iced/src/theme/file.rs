@@ -0,0 +1,402 @@
+//! Loading a [`Palette`] from a TOML or JSON theme file.
+//!
+//! A theme file maps the token names of [`Palette`] to colours written as
+//! `#RRGGBB` or `#AARRGGBB`, the same order as the WinUI resources. Tokens
+//! missing from the file are taken from the `base` theme, `light` or `dark`,
+//! `dark` by default.
+//!
+//! ```toml
+//! base = "light"
+//! accent_fill_color_default = "#C239B3"
+//! text_fill_color_primary = "#E4000000"
+//! ```
+
+use crate::theme::Palette;
+
+use std::{fmt, fs, path::Path};
+
+use iced::Color;
+
+const BASE_KEY: &str = "base";
+
+/// Themes a file can be based on, by the value of its `base` key.
+const BASES: [(&str, Palette); 2] = [("light", Palette::LIGHT), ("dark", Palette::DARK)];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Toml,
+    Json,
+}
+
+impl Format {
+    /// Format matching the extension of `path`.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "toml" => Some(Format::Toml),
+            "json" => Some(Format::Json),
+            _ => None,
+        }
+    }
+}
+
+/// A successfully parsed theme file.
+#[derive(Clone, Debug)]
+pub struct ThemeFile {
+    pub palette: Palette,
+    /// Tokens which were not in the file and were taken from the base theme.
+    pub missing: Vec<&'static str>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Error {
+    /// 1-based line of the file the error refers to, if any.
+    pub line: Option<usize>,
+    pub kind: ErrorKind,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ErrorKind {
+    Io(String),
+    UnknownFormat,
+    Syntax(String),
+    UnknownBase(String),
+    UnknownToken(String),
+    InvalidColour { token: String, value: String },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(line) = self.line {
+            write!(f, "line {line}: ")?;
+        }
+
+        match &self.kind {
+            ErrorKind::Io(error) => write!(f, "{error}"),
+            ErrorKind::UnknownFormat => write!(f, "expected a .toml or .json file"),
+            ErrorKind::Syntax(error) => write!(f, "{error}"),
+            ErrorKind::UnknownBase(base) => {
+                let bases = BASES.map(|(name, _)| format!("\"{name}\"")).join(", ");
+
+                write!(f, "unknown base theme \"{base}\", expected one of {bases}")
+            }
+            ErrorKind::UnknownToken(token) => write!(f, "unknown token \"{token}\""),
+            ErrorKind::InvalidColour { token, value } => write!(
+                f,
+                "invalid colour {value} for \"{token}\", expected #RRGGBB or #AARRGGBB"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Reads and parses the theme file at `path`, its format given by the extension.
+pub fn load(path: impl AsRef<Path>) -> Result<ThemeFile, Vec<Error>> {
+    let path = path.as_ref();
+
+    let format = Format::from_path(path).ok_or_else(|| {
+        vec![Error {
+            line: None,
+            kind: ErrorKind::UnknownFormat,
+        }]
+    })?;
+
+    let source = fs::read_to_string(path).map_err(|error| {
+        vec![Error {
+            line: None,
+            kind: ErrorKind::Io(error.to_string()),
+        }]
+    })?;
+
+    parse(&source, format)
+}
+
+/// Parses a theme file, returning every error found rather than only the first.
+///
+/// ```
+/// use fluent_iced::theme::file::{self, Format};
+///
+/// let theme = file::parse("base = \"light\"\naccent_fill_color_default = \"#C239B3\"", Format::Toml).unwrap();
+/// assert!(!theme.palette.is_dark());
+///
+/// let errors = file::parse("{\"accent_fill_color_default\": \"blue\"}", Format::Json).unwrap_err();
+/// assert_eq!(errors[0].line, Some(1));
+///
+/// let errors = file::parse("{\"base\": \"light\",}", Format::Json).unwrap_err();
+/// assert_eq!(errors[0].to_string(), "line 1: trailing comma");
+/// ```
+pub fn parse(source: &str, format: Format) -> Result<ThemeFile, Vec<Error>> {
+    let entries = match format {
+        Format::Toml => toml_entries(source),
+        Format::Json => json_entries(source),
+    }
+    .map_err(|error| vec![error])?;
+
+    let mut errors = Vec::new();
+
+    let base = match entries.iter().find(|(key, _)| key == BASE_KEY) {
+        None => Palette::DARK,
+        Some((_, value)) => match BASES.iter().find(|(name, _)| name == value) {
+            Some((_, palette)) => palette.clone(),
+            None => {
+                errors.push(Error {
+                    line: line_of(source, BASE_KEY),
+                    kind: ErrorKind::UnknownBase(value.clone()),
+                });
+                Palette::DARK
+            }
+        },
+    };
+
+    let mut palette = base;
+    let mut found = Vec::new();
+
+    for (key, value) in entries.iter().filter(|(key, _)| key != BASE_KEY) {
+        let Some(token) = palette.token_mut(key) else {
+            errors.push(Error {
+                line: line_of(source, key),
+                kind: ErrorKind::UnknownToken(key.clone()),
+            });
+            continue;
+        };
+
+        match parse_colour(value) {
+            Some(colour) => {
+                *token = colour;
+                found.push(key.as_str());
+            }
+            None => errors.push(Error {
+                line: line_of(source, key),
+                kind: ErrorKind::InvalidColour {
+                    token: key.clone(),
+                    value: value.clone(),
+                },
+            }),
+        }
+    }
+
+    if !errors.is_empty() {
+        errors.sort_by_key(|error| error.line);
+        return Err(errors);
+    }
+
+    let missing = Palette::TOKENS
+        .iter()
+        .copied()
+        .filter(|token| !found.contains(token))
+        .collect();
+
+    Ok(ThemeFile { palette, missing })
+}
+
+// Keys and values of the top level table. Values which aren't strings are kept in
+// their source form so they can be reported as invalid colours.
+fn toml_entries(source: &str) -> Result<Vec<(String, String)>, Error> {
+    let table: toml::Table = source.parse().map_err(|error: toml::de::Error| Error {
+        line: error
+            .span()
+            .map(|span| source[..span.start].matches('\n').count() + 1),
+        kind: ErrorKind::Syntax(error.message().to_owned()),
+    })?;
+
+    Ok(table
+        .into_iter()
+        .map(|(key, value)| match value {
+            toml::Value::String(value) => (key, value),
+            value => (key, value.to_string()),
+        })
+        .collect())
+}
+
+fn json_entries(source: &str) -> Result<Vec<(String, String)>, Error> {
+    let object: serde_json::Map<String, serde_json::Value> =
+        serde_json::from_str(source).map_err(|error| {
+            // The message ends with the position, which is reported as the line instead
+            let position = format!(" at line {} column {}", error.line(), error.column());
+            let message = error.to_string();
+
+            Error {
+                line: Some(error.line()),
+                kind: ErrorKind::Syntax(
+                    message
+                        .strip_suffix(&position)
+                        .unwrap_or(&message)
+                        .to_owned(),
+                ),
+            }
+        })?;
+
+    Ok(object
+        .into_iter()
+        .map(|(key, value)| match value {
+            serde_json::Value::String(value) => (key, value),
+            value => (key, value.to_string()),
+        })
+        .collect())
+}
+
+/// Parses a colour written as `#RRGGBB` or `#AARRGGBB`.
+///
+/// ```
+/// use fluent_iced::theme::file::parse_colour;
+/// use iced::Color;
+///
+/// assert_eq!(parse_colour("#80FFFFFF"), Some(Color { a: 128.0 / 255.0, ..Color::WHITE }));
+/// assert_eq!(parse_colour("#+12345"), None);
+/// ```
+pub fn parse_colour(value: &str) -> Option<Color> {
+    let hex = value
+        .strip_prefix('#')
+        .filter(|hex| hex.bytes().all(|byte| byte.is_ascii_hexdigit()))?;
+    let argb = u32::from_str_radix(hex, 16).ok()?;
+
+    let argb = match hex.len() {
+        6 => 0xFF000000 | argb,
+        8 => argb,
+        _ => return None,
+    };
+
+    let [a, r, g, b] = argb.to_be_bytes();
+
+    Some(Color::from_rgba8(r, g, b, a as f32 / 255.0))
+}
+
+// Neither parser keeps the position of keys so find the first line which defines `key`,
+// either bare at the start of the line or quoted anywhere in it.
+fn line_of(source: &str, key: &str) -> Option<usize> {
+    let quoted = format!("\"{key}\"");
+    let defines = |rest: &str| rest.trim_start().starts_with(['=', ':']);
+
+    source
+        .lines()
+        .position(|line| {
+            line.trim_start().strip_prefix(key).is_some_and(defines)
+                || line
+                    .match_indices(&quoted)
+                    .any(|(index, _)| defines(&line[index + quoted.len()..]))
+        })
+        .map(|index| index + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use iced::color;
+
+    #[test]
+    fn missing_tokens_come_from_the_base_theme() {
+        let theme = parse(
+            "base = \"light\"\naccent_fill_color_default = \"#C239B3\"",
+            Format::Toml,
+        )
+        .unwrap();
+
+        assert_eq!(theme.palette.accent_fill_color_default, color!(0xC239B3));
+        assert_eq!(
+            theme.palette.text_fill_color_primary,
+            Palette::LIGHT.text_fill_color_primary
+        );
+        assert_eq!(theme.missing.len(), Palette::TOKENS.len() - 1);
+        assert!(!theme.missing.contains(&"accent_fill_color_default"));
+    }
+
+    #[test]
+    fn base_defaults_to_dark() {
+        let theme = parse("", Format::Toml).unwrap();
+
+        assert_eq!(theme.palette, Palette::DARK);
+        assert_eq!(theme.missing, Palette::TOKENS);
+    }
+
+    #[test]
+    fn unknown_tokens_are_reported_with_their_line() {
+        let errors = parse(
+            "base = \"light\"\n\naccent_fill_colour = \"#C239B3\"",
+            Format::Toml,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            errors,
+            [Error {
+                line: Some(3),
+                kind: ErrorKind::UnknownToken("accent_fill_colour".to_owned()),
+            }]
+        );
+        assert_eq!(
+            errors[0].to_string(),
+            "line 3: unknown token \"accent_fill_colour\""
+        );
+    }
+
+    #[test]
+    fn every_error_is_reported_in_line_order() {
+        let source = "{\n  \"text_fill_color_primary\": 3,\n  \"base\": \"sepia\",\n  \"nope\": \"#FFFFFF\"\n}";
+        let errors = parse(source, Format::Json).unwrap_err();
+
+        assert_eq!(
+            errors,
+            [
+                Error {
+                    line: Some(2),
+                    kind: ErrorKind::InvalidColour {
+                        token: "text_fill_color_primary".to_owned(),
+                        value: "3".to_owned(),
+                    },
+                },
+                Error {
+                    line: Some(3),
+                    kind: ErrorKind::UnknownBase("sepia".to_owned()),
+                },
+                Error {
+                    line: Some(4),
+                    kind: ErrorKind::UnknownToken("nope".to_owned()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn toml_syntax_error_is_reported_with_its_line() {
+        let errors = parse(
+            "base = \"light\"\naccent_fill_color_default = \"#C239B3",
+            Format::Toml,
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            errors.as_slice(),
+            [Error {
+                line: Some(2),
+                kind: ErrorKind::Syntax(_),
+            }]
+        ));
+    }
+
+    #[test]
+    fn json_syntax_error_is_reported_with_its_line() {
+        let errors = parse("{\n  \"base\": \"light\"\n  \"x\": 1\n}", Format::Json).unwrap_err();
+
+        assert!(matches!(
+            errors.as_slice(),
+            [Error {
+                line: Some(3),
+                kind: ErrorKind::Syntax(_),
+            }]
+        ));
+    }
+
+    #[test]
+    fn files_without_a_known_extension_are_rejected() {
+        let errors = load("theme.yaml").unwrap_err();
+
+        assert_eq!(
+            errors,
+            [Error {
+                line: None,
+                kind: ErrorKind::UnknownFormat,
+            }]
+        );
+    }
+}
@@ -0,0 +1,239 @@
+//! The Windows contrast themes.
+//!
+//! Contrast themes replace every colour with a handful of solid system colours.
+//! [`SystemColours::palette`] maps them to the [`Palette`] tokens the way the
+//! `HighContrast` theme dictionaries of WinUI do, and the style functions check
+//! [`crate::Theme::high_contrast`] to draw solid borders and highlight states.
+//!
+//! ```
+//! use fluent_iced::theme::{Contrast, Palette};
+//!
+//! for contrast in Contrast::ALL {
+//!     let palette = contrast.palette();
+//!
+//!     assert!(Palette::TOKENS
+//!         .iter()
+//!         .all(|token| palette.token(token).unwrap().a == 1.0));
+//! }
+//! ```
+
+use crate::theme::Palette;
+
+use iced::{color, Color};
+
+/// One of the contrast themes of Windows 11.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Contrast {
+    Aquatic,
+    Desert,
+    Dusk,
+    NightSky,
+}
+
+impl Contrast {
+    pub const ALL: [Contrast; 4] = [
+        Contrast::Aquatic,
+        Contrast::Desert,
+        Contrast::Dusk,
+        Contrast::NightSky,
+    ];
+
+    pub fn system_colours(&self) -> &'static SystemColours {
+        match self {
+            Contrast::Aquatic => &SystemColours::AQUATIC,
+            Contrast::Desert => &SystemColours::DESERT,
+            Contrast::Dusk => &SystemColours::DUSK,
+            Contrast::NightSky => &SystemColours::NIGHT_SKY,
+        }
+    }
+
+    pub fn palette(&self) -> &'static Palette {
+        match self {
+            Contrast::Aquatic => &AQUATIC,
+            Contrast::Desert => &DESERT,
+            Contrast::Dusk => &DUSK,
+            Contrast::NightSky => &NIGHT_SKY,
+        }
+    }
+}
+
+impl std::fmt::Display for Contrast {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Contrast::Aquatic => write!(f, "Aquatic"),
+            Contrast::Desert => write!(f, "Desert"),
+            Contrast::Dusk => write!(f, "Dusk"),
+            Contrast::NightSky => write!(f, "Night sky"),
+        }
+    }
+}
+
+/// Colours of a contrast theme, named after the `SystemColor*Color` resources.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SystemColours {
+    /// Background of windows and pages.
+    pub window: Color,
+    pub window_text: Color,
+    /// Hyperlinks.
+    pub hotlight: Color,
+    /// Disabled text and controls.
+    pub gray_text: Color,
+    /// Background of selected, hovered and pressed items.
+    pub highlight: Color,
+    pub highlight_text: Color,
+    pub button_face: Color,
+    pub button_text: Color,
+}
+
+const AQUATIC: Palette = SystemColours::AQUATIC.palette();
+const DESERT: Palette = SystemColours::DESERT.palette();
+const DUSK: Palette = SystemColours::DUSK.palette();
+const NIGHT_SKY: Palette = SystemColours::NIGHT_SKY.palette();
+
+impl SystemColours {
+    pub const AQUATIC: Self = Self {
+        window: color!(0x202020),
+        window_text: color!(0xFFFFFF),
+        hotlight: color!(0x75E9FC),
+        gray_text: color!(0xA6A6A6),
+        highlight: color!(0x8EE3F0),
+        highlight_text: color!(0x263B50),
+        button_face: color!(0x202020),
+        button_text: color!(0xFFFFFF),
+    };
+
+    pub const DESERT: Self = Self {
+        window: color!(0xFFFAEF),
+        window_text: color!(0x3D3D3D),
+        hotlight: color!(0x1C5E75),
+        gray_text: color!(0x676767),
+        highlight: color!(0x903909),
+        highlight_text: color!(0xFFF5E3),
+        button_face: color!(0xFFFAEF),
+        button_text: color!(0x202020),
+    };
+
+    pub const DUSK: Self = Self {
+        window: color!(0x2D3236),
+        window_text: color!(0xB6F6F0),
+        hotlight: color!(0x70EBDE),
+        gray_text: color!(0xA6A6A6),
+        highlight: color!(0xA1BFDE),
+        highlight_text: color!(0x212D3B),
+        button_face: color!(0x2D3236),
+        button_text: color!(0xB6F6F0),
+    };
+
+    pub const NIGHT_SKY: Self = Self {
+        window: color!(0x000000),
+        window_text: color!(0xFFFFFF),
+        hotlight: color!(0x8080FF),
+        gray_text: color!(0xD6D6D6),
+        highlight: color!(0xD6B4FD),
+        highlight_text: color!(0x2B2B2B),
+        button_face: color!(0x000000),
+        button_text: color!(0xFFEE32),
+    };
+
+    /// Palette with every token set to one of these colours, so there are no
+    /// translucent fills. Hover and pressed fills stay the window colour, the style
+    /// functions draw those states with [`SystemColours::highlight`] instead. Status
+    /// colours are all window text, as contrast themes have no green, yellow or red.
+    pub const fn palette(&self) -> Palette {
+        let window = self.window;
+        let text = self.window_text;
+
+        Palette {
+            accent_fill_color_default: self.highlight,
+            accent_fill_color_secondary: self.highlight,
+            accent_fill_color_tertiary: self.highlight,
+
+            acrylic_in_app_fill_color_default_fallback: window,
+
+            text_fill_color_primary: text,
+            text_fill_color_secondary: text,
+            text_fill_color_tertiary: text,
+            text_fill_color_disabled: self.gray_text,
+            text_fill_color_inverse: window,
+            accent_text_fill_color_primary: self.highlight,
+            accent_text_fill_color_secondary: self.highlight,
+            accent_text_fill_color_tertiary: self.highlight,
+            accent_text_fill_color_disabled: self.gray_text,
+            text_on_accent_fill_color_selected_text: self.highlight_text,
+            text_on_accent_fill_color_primary: self.highlight_text,
+            text_on_accent_fill_color_secondary: self.highlight_text,
+            text_on_accent_fill_color_disabled: window,
+            control_fill_color_default: self.button_face,
+            control_fill_color_secondary: self.button_face,
+            control_fill_color_tertiary: self.button_face,
+            control_fill_color_disabled: window,
+            control_fill_color_transparent: window,
+            control_fill_color_input_active: window,
+            control_strong_fill_color_default: self.button_text,
+            control_strong_fill_color_disabled: self.gray_text,
+            control_solid_fill_color_default: self.button_face,
+            subtle_fill_color_transparent: window,
+            subtle_fill_color_secondary: window,
+            subtle_fill_color_tertiary: window,
+            subtle_fill_color_disabled: window,
+            control_alt_fill_color_transparent: window,
+            control_alt_fill_color_secondary: window,
+            control_alt_fill_color_tertiary: window,
+            control_alt_fill_color_quarternary: window,
+            control_alt_fill_color_disabled: window,
+            control_on_image_fill_color_default: window,
+            control_on_image_fill_color_secondary: window,
+            control_on_image_fill_color_tertiary: window,
+            control_on_image_fill_color_disabled: window,
+            accent_fill_color_disabled: self.gray_text,
+            accent_fill_color_selected_text_background: self.highlight,
+            control_stroke_color_default: self.button_text,
+            control_stroke_color_secondary: self.button_text,
+            control_stroke_color_on_accent_default: self.highlight,
+            control_stroke_color_on_accent_secondary: self.highlight,
+            control_stroke_color_on_accent_tertiary: self.highlight,
+            control_stroke_color_on_accent_disabled: self.gray_text,
+            control_stroke_color_for_strong_fill_when_on_image: self.button_text,
+            card_stroke_color_default: text,
+            card_stroke_color_default_solid: text,
+            control_strong_stroke_color_default: self.button_text,
+            control_strong_stroke_color_disabled: self.gray_text,
+            surface_stroke_color_default: text,
+            surface_stroke_color_flyout: text,
+            surface_stroke_color_inverse: window,
+            divider_stroke_color_default: text,
+            focus_stroke_color_outer: text,
+            focus_stroke_color_inner: window,
+            card_background_fill_color_default: window,
+            card_background_fill_color_secondary: window,
+            smoke_fill_color_default: window,
+            layer_fill_color_default: window,
+            layer_fill_color_alt: window,
+            layer_on_acrylic_fill_color_default: window,
+            layer_on_accent_acrylic_fill_color_default: window,
+            layer_on_mica_base_alt_fill_color_default: window,
+            layer_on_mica_base_alt_fill_color_secondary: window,
+            layer_on_mica_base_alt_fill_color_tertiary: window,
+            layer_on_mica_base_alt_fill_color_transparent: window,
+            solid_background_fill_color_base: window,
+            solid_background_fill_color_secondary: window,
+            solid_background_fill_color_tertiary: window,
+            solid_background_fill_color_quarternary: window,
+            solid_background_fill_color_transparent: window,
+            solid_background_fill_color_base_alt: window,
+            system_fill_color_attention: self.highlight,
+            system_fill_color_success: text,
+            system_fill_color_caution: text,
+            system_fill_color_critical: text,
+            system_fill_color_neutral: text,
+            system_fill_color_solid_neutral: text,
+            system_fill_color_attention_background: window,
+            system_fill_color_success_background: window,
+            system_fill_color_caution_background: window,
+            system_fill_color_critical_background: window,
+            system_fill_color_neutral_background: window,
+            system_fill_color_solid_attention_background: window,
+            system_fill_color_solid_neutral_background: window,
+        }
+    }
+}
@@ -1,15 +1,27 @@
+//! Constructors for Iced widgets styled for Fluent, plus a few custom widgets.
+//!
+//! The type aliases fix the `Theme` and `Renderer` generics to [`crate::theme::Theme`]
+//! and the default Iced renderer.
+
 pub mod button;
 pub mod canvas;
 pub mod checkbox;
+pub mod code_block;
 pub mod combo_box;
 pub mod dialog;
+pub mod drag_handle;
+pub mod info_badge;
+#[cfg(feature = "menu")]
 pub mod menu;
+#[cfg(feature = "number_input")]
 pub mod number_input;
 pub mod pick_list;
 pub mod quad;
 pub mod radio;
+#[cfg(feature = "ribbon")]
 pub mod ribbon;
 pub mod scrollable;
+#[cfg(feature = "side_nav")]
 pub mod side_nav;
 pub mod slider;
 pub mod text;
@@ -26,10 +38,15 @@ pub type Checkbox<'a, Message> = iced::widget::Checkbox<'a, Message, Theme, Rend
 pub type Column<'a, Message> = iced::widget::Column<'a, Message, Theme, Renderer>;
 pub type ComboBox<'a, T, Message> = iced::widget::ComboBox<'a, T, Message, Theme, Renderer>;
 pub type Container<'a, Message> = iced::widget::Container<'a, Message, Theme, Renderer>;
+pub type DragHandle<'a, Message> = crate::widget::drag_handle::DragHandle<'a, Message, Theme>;
+#[cfg(feature = "drop_down")]
 pub type DropDown<'a, Message> = iced_aw::widget::DropDown<'a, Message, Theme, Renderer>;
 pub type Element<'a, Message> = iced::Element<'a, Message, Theme, Renderer>;
+#[cfg(feature = "menu")]
 pub type Menu<'a, Message> = iced_aw::menu::Menu<'a, Message, Theme, Renderer>;
+#[cfg(feature = "menu")]
 pub type MenuBar<'a, Message> = iced_aw::menu::MenuBar<'a, Message, Theme, Renderer>;
+#[cfg(feature = "menu")]
 pub type MenuItem<'a, Message> = iced_aw::menu::Item<'a, Message, Theme, Renderer>;
 pub type PickList<'a, T, L, V, Message> =
     iced::widget::PickList<'a, T, L, V, Message, Theme, Renderer>;
@@ -41,5 +58,6 @@ pub type Slider<'a, T, Message> = iced::widget::Slider<'a, T, Message, Theme>;
 pub type Text<'a> = iced::widget::Text<'a, Theme, Renderer>;
 pub type TextInput<'a, Message> = iced::widget::TextInput<'a, Message, Theme, Renderer>;
 pub type Toggler<'a, Message> = iced::widget::Toggler<'a, Message, Theme, Renderer>;
+#[cfg(feature = "wrap")]
 pub type Wrap<'a, Message, Direction> =
     iced_aw::widget::Wrap<'a, Message, Direction, Theme, Renderer>;
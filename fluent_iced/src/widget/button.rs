@@ -1,7 +1,9 @@
+//! Standard, drop down, split, menu and app bar buttons.
+
 use crate::{
     fluent_icon::FluentIcon,
     style,
-    widget::{text, Button, Column, DropDown, Element, Row},
+    widget::{text, Button, Column, Element, Row},
 };
 
 use iced::{
@@ -31,6 +33,7 @@ where
     .height(32.0)
 }
 
+#[cfg(feature = "drop_down")]
 pub fn split<'a, Message>(
     content: impl Into<Element<'a, Message>>,
     overlay: impl Into<Element<'a, Message>>,
@@ -51,7 +54,7 @@ where
     .style(style::button::split_indicator);
     let underlay = Row::new().push(content).push(indicator);
 
-    DropDown::new(underlay, overlay, expanded)
+    crate::widget::DropDown::new(underlay, overlay, expanded)
         .width(Length::Shrink)
         .on_dismiss(on_dismiss)
         .into()
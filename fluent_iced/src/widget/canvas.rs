@@ -1,3 +1,5 @@
+//! Canvas programs used to draw simple shapes.
+
 use iced::{
     advanced::{self, mouse},
     border::Radius,
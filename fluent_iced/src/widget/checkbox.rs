@@ -1,3 +1,5 @@
+//! Two and three state checkboxes.
+
 use crate::{fluent_icon::FluentIcon, font, widget::Checkbox};
 
 use iced::{
@@ -31,8 +33,8 @@ fn base<'a, Message>(
     code_point: char,
 ) -> Checkbox<'a, Message> {
     let check_mark = Icon {
-        font: font::SEGOE_FLUENT_ICONS,
-        code_point,
+        font: font::icons(),
+        code_point: font::glyph(code_point),
         size: Some(Pixels(14.0)),
         line_height: LineHeight::default(),
         shaping: Shaping::Advanced,
@@ -40,7 +42,7 @@ fn base<'a, Message>(
 
     Checkbox::new(label, is_checked)
         .size(20)
-        .font(font::SEGOE)
+        .font(font::regular())
         .text_size(14)
         .text_line_height(LineHeight::Absolute(Pixels(20.0)))
         .icon(check_mark)
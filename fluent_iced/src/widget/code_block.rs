@@ -0,0 +1,645 @@
+//! Read-only Rust source with syntax highlighting, collapsed under a header and with
+//! a button which copies the code to the clipboard.
+//!
+//! The block keeps whether it is expanded itself, so it can be shown without
+//! threading its state and messages through the app.
+
+use crate::{
+    fluent_icon::FluentIcon,
+    font,
+    style::{
+        self,
+        code_block::{Style, StyleFn},
+    },
+    theme::Theme,
+    widget::{button, scrollable, text, Element},
+};
+
+use iced::{
+    advanced::{
+        self,
+        clipboard::{self, Clipboard},
+        layout::{Limits, Node},
+        renderer::{self, Renderer as _},
+        text::{LineHeight, Paragraph, Renderer as _, Shaping, Wrapping},
+        widget::{tree, Operation, Tree},
+        Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    mouse::{self, Cursor},
+    widget::{container, row, scrollable::Direction, stack},
+    Event, Font, Length, Pixels, Point, Rectangle, Size,
+};
+
+use std::rc::Rc;
+
+type Renderer = iced::Renderer;
+
+const HEADER_HEIGHT: f32 = 40.0;
+const CHEVRON_SIZE: f32 = 12.0;
+const CODE_SIZE: f32 = 13.0;
+
+/// Messages of the header and the copy button, handled by the block itself.
+#[derive(Clone, Copy, Debug)]
+enum Internal {
+    Toggled,
+    Copied,
+}
+
+pub struct CodeBlock<'a> {
+    code: &'a str,
+    label: &'a str,
+    expanded: bool,
+    class: StyleFn<'a>,
+}
+
+impl<'a> CodeBlock<'a> {
+    pub fn new(code: &'a str) -> Self {
+        Self {
+            code,
+            label: "Source code",
+            expanded: false,
+            class: Box::new(style::code_block::default),
+        }
+    }
+
+    /// Text of the header, "Source code" by default.
+    pub fn label(mut self, label: &'a str) -> Self {
+        self.label = label;
+        self
+    }
+
+    /// Whether the block is expanded when it is first shown.
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self {
+        self.class = Box::new(style);
+        self
+    }
+}
+
+impl<'a, Message: 'a> From<CodeBlock<'a>> for Element<'a, Message> {
+    fn from(code_block: CodeBlock<'a>) -> Self {
+        Element::new(Block::new(code_block))
+    }
+}
+
+// The widget built from a CodeBlock, which shares its style with the code
+struct Block<'a> {
+    code: &'a str,
+    expanded: bool,
+    header: Element<'a, Internal>,
+    body: Element<'a, Internal>,
+    class: Rc<dyn Fn(&Theme) -> Style + 'a>,
+}
+
+impl<'a> Block<'a> {
+    fn new(code_block: CodeBlock<'a>) -> Self {
+        let CodeBlock {
+            code,
+            label,
+            expanded,
+            class,
+        } = code_block;
+        let class: Rc<dyn Fn(&Theme) -> Style + 'a> = Rc::from(class);
+
+        let header = button::standard(text::body1(label))
+            .width(Length::Fill)
+            .height(HEADER_HEIGHT)
+            .padding([10, 16])
+            .style(style::button::transparent)
+            .on_press(Internal::Toggled);
+
+        let copy_button = button::standard(
+            row![
+                text::icon(FluentIcon::Copy.codepoint()).size(12),
+                text::body1("Copy")
+            ]
+            .spacing(8)
+            .align_y(Vertical::Center),
+        )
+        .on_press(Internal::Copied);
+
+        let code_view = Code {
+            code,
+            class: class.clone(),
+        };
+
+        let code_view = scrollable::standard(container(code_view).padding(16))
+            .direction(Direction::Horizontal(scrollable::scrollbar::standard()))
+            .width(Length::Fill);
+
+        let body = stack![
+            code_view,
+            container(copy_button).align_right(Length::Fill).padding(8)
+        ];
+
+        Self {
+            code,
+            expanded,
+            header: header.into(),
+            body: body.into(),
+            class,
+        }
+    }
+}
+
+struct State {
+    expanded: bool,
+}
+
+impl<'a, Message> Widget<Message, Theme, Renderer> for Block<'a> {
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fill, Length::Shrink)
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            expanded: self.expanded,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.header), Tree::new(&self.body)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.header, &self.body]);
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let expanded = tree.state.downcast_ref::<State>().expanded;
+        let limits = limits.width(Length::Fill).height(Length::Shrink);
+
+        let header = self
+            .header
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, &limits);
+
+        // The body is laid out even when collapsed so it keeps its place in the tree
+        let body = if expanded {
+            self.body
+                .as_widget()
+                .layout(&mut tree.children[1], renderer, &limits)
+        } else {
+            Node::new(Size::ZERO)
+        }
+        .move_to(Point::new(0.0, header.size().height));
+
+        let size = Size::new(
+            limits.max().width,
+            header.size().height + body.size().height,
+        );
+
+        Node::with_children(size, vec![header, body])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let mut children = layout.children();
+        let (header, body) = (children.next().unwrap(), children.next().unwrap());
+
+        self.header
+            .as_widget()
+            .operate(&mut tree.children[0], header, renderer, operation);
+
+        if tree.state.downcast_ref::<State>().expanded {
+            self.body
+                .as_widget()
+                .operate(&mut tree.children[1], body, renderer, operation);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let expanded = tree.state.downcast_ref::<State>().expanded;
+        let mut children = layout.children();
+        let (header, body) = (children.next().unwrap(), children.next().unwrap());
+
+        let mut messages = Vec::new();
+        let mut local_shell = Shell::new(&mut messages);
+
+        let mut status = self.header.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            header,
+            cursor,
+            renderer,
+            clipboard,
+            &mut local_shell,
+            viewport,
+        );
+
+        if expanded {
+            status = status.merge(self.body.as_widget_mut().on_event(
+                &mut tree.children[1],
+                event,
+                body,
+                cursor,
+                renderer,
+                clipboard,
+                &mut local_shell,
+                viewport,
+            ));
+        }
+
+        if let Some(request) = local_shell.redraw_request() {
+            shell.request_redraw(request);
+        }
+
+        if local_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+
+        if local_shell.are_widgets_invalid() {
+            shell.invalidate_widgets();
+        }
+
+        for message in messages {
+            match message {
+                Internal::Toggled => {
+                    let state = tree.state.downcast_mut::<State>();
+                    state.expanded = !state.expanded;
+                    shell.invalidate_layout();
+                }
+                Internal::Copied => {
+                    clipboard.write(clipboard::Kind::Standard, self.code.to_owned());
+                }
+            }
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let expanded = tree.state.downcast_ref::<State>().expanded;
+        let bounds = layout.bounds();
+        let appearance = (self.class)(theme);
+        let mut children = layout.children();
+        let (header, body) = (children.next().unwrap(), children.next().unwrap());
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: appearance.border,
+                ..renderer::Quad::default()
+            },
+            appearance.background,
+        );
+
+        self.header.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            header,
+            cursor,
+            viewport,
+        );
+
+        let chevron = if expanded {
+            FluentIcon::ChevronUp
+        } else {
+            FluentIcon::ChevronDown
+        };
+        let header_bounds = header.bounds();
+
+        renderer.fill_text(
+            advanced::Text {
+                content: font::glyph(chevron.codepoint()).to_string(),
+                bounds: Size::new(CHEVRON_SIZE, header_bounds.height),
+                size: Pixels(CHEVRON_SIZE),
+                line_height: LineHeight::default(),
+                font: font::icons(),
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+                shaping: Shaping::Basic,
+                wrapping: Wrapping::None,
+            },
+            Point::new(
+                header_bounds.x + header_bounds.width - 16.0 - CHEVRON_SIZE / 2.0,
+                header_bounds.center_y(),
+            ),
+            appearance.text,
+            *viewport,
+        );
+
+        if expanded {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y: body.bounds().y,
+                        height: 1.0,
+                        ..bounds
+                    },
+                    ..renderer::Quad::default()
+                },
+                appearance.border.color,
+            );
+
+            self.body.as_widget().draw(
+                &tree.children[1],
+                renderer,
+                theme,
+                style,
+                body,
+                cursor,
+                viewport,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let (header, body) = (children.next().unwrap(), children.next().unwrap());
+
+        let header = self.header.as_widget().mouse_interaction(
+            &tree.children[0],
+            header,
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        if tree.state.downcast_ref::<State>().expanded {
+            header.max(self.body.as_widget().mouse_interaction(
+                &tree.children[1],
+                body,
+                cursor,
+                viewport,
+                renderer,
+            ))
+        } else {
+            header
+        }
+    }
+}
+
+// The highlighted code. Each kind of token is drawn from its own paragraph, holding
+// only the characters of that kind, which line up as the font is monospace.
+struct Code<'a> {
+    code: &'a str,
+    class: Rc<dyn Fn(&Theme) -> Style + 'a>,
+}
+
+#[derive(Default)]
+struct CodeState {
+    code: String,
+    paragraphs: Vec<(Token, <Renderer as advanced::text::Renderer>::Paragraph)>,
+}
+
+impl<'a, Message> Widget<Message, Theme, Renderer> for Code<'a> {
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<CodeState>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(CodeState::default())
+    }
+
+    fn layout(&self, tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let state = tree.state.downcast_mut::<CodeState>();
+
+        if state.paragraphs.is_empty() || state.code != self.code {
+            state.code = self.code.to_owned();
+            state.paragraphs = paragraphs(self.code);
+        }
+
+        let size = state
+            .paragraphs
+            .first()
+            .map_or(Size::ZERO, |(_, paragraph)| paragraph.min_bounds());
+
+        Node::new(limits.resolve(Length::Shrink, Length::Shrink, size))
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<CodeState>();
+        let style = (self.class)(theme);
+        let Some(clip_bounds) = layout.bounds().intersection(viewport) else {
+            return;
+        };
+
+        for (token, paragraph) in &state.paragraphs {
+            let colour = match token {
+                Token::Plain => style.text,
+                Token::Keyword => style.keyword,
+                Token::Type => style.type_name,
+                Token::Function => style.function,
+                Token::Macro => style.macro_name,
+                Token::String => style.string,
+                Token::Number => style.number,
+                Token::Comment => style.comment,
+            };
+
+            renderer.fill_paragraph(paragraph, layout.position(), colour, clip_bounds);
+        }
+    }
+}
+
+impl<'a, Message: 'a> From<Code<'a>> for Element<'a, Message> {
+    fn from(code: Code<'a>) -> Self {
+        Element::new(code)
+    }
+}
+
+fn paragraphs(code: &str) -> Vec<(Token, <Renderer as advanced::text::Renderer>::Paragraph)> {
+    let code = code.replace('\t', "    ");
+    let tokens = tokens(&code);
+
+    Token::ALL
+        .into_iter()
+        .filter(|token| *token == Token::Plain || tokens.contains(token))
+        .map(|token| {
+            // Everything but the token and line breaks is blanked out
+            let content: String = code
+                .chars()
+                .zip(&tokens)
+                .map(|(c, kind)| if *kind == token || c == '\n' { c } else { ' ' })
+                .collect();
+
+            let paragraph = Paragraph::with_text(advanced::Text {
+                content: content.as_str(),
+                bounds: Size::INFINITY,
+                size: Pixels(CODE_SIZE),
+                line_height: LineHeight::default(),
+                font: Font::MONOSPACE,
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Top,
+                shaping: Shaping::Advanced,
+                wrapping: Wrapping::None,
+            });
+
+            (token, paragraph)
+        })
+        .collect()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Token {
+    Plain,
+    Keyword,
+    Type,
+    Function,
+    Macro,
+    String,
+    Number,
+    Comment,
+}
+
+impl Token {
+    // Plain first, as it sizes the code
+    const ALL: [Token; 8] = [
+        Token::Plain,
+        Token::Keyword,
+        Token::Type,
+        Token::Function,
+        Token::Macro,
+        Token::String,
+        Token::Number,
+        Token::Comment,
+    ];
+}
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "false",
+    "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "type", "unsafe",
+    "use", "where", "while",
+];
+
+// The kind of each character of Rust source. This is a rough lexer, enough for
+// the short snippets of the gallery rather than every corner of the language.
+fn tokens(code: &str) -> Vec<Token> {
+    let chars: Vec<char> = code.chars().collect();
+    let mut tokens = vec![Token::Plain; chars.len()];
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+    let mut i = 0;
+
+    while i < chars.len() {
+        let start = i;
+        let c = chars[i];
+
+        let token = if c == '/' && chars.get(i + 1) == Some(&'/') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+            Token::Comment
+        } else if c == '"' {
+            i += 1;
+            while i < chars.len() && chars[i] != '"' {
+                i += if chars[i] == '\\' { 2 } else { 1 };
+            }
+            i += 1;
+            Token::String
+        } else if c == '\'' && (chars.get(i + 2) == Some(&'\'') || chars.get(i + 1) == Some(&'\\'))
+        {
+            // A character rather than a lifetime
+            i += 1;
+            while i < chars.len() && chars[i] != '\'' {
+                i += if chars[i] == '\\' { 2 } else { 1 };
+            }
+            i += 1;
+            Token::String
+        } else if c == '#' && matches!(chars.get(i + 1), Some('[' | '!')) {
+            let mut depth = 0;
+            while i < chars.len() {
+                match chars[i] {
+                    '[' => depth += 1,
+                    ']' => depth -= 1,
+                    _ => (),
+                }
+                i += 1;
+                if depth == 0 && chars[i - 1] == ']' {
+                    break;
+                }
+            }
+            Token::Macro
+        } else if c.is_ascii_digit() {
+            while i < chars.len()
+                && (is_ident(chars[i])
+                    || chars[i] == '.' && chars.get(i + 1).is_some_and(char::is_ascii_digit))
+            {
+                i += 1;
+            }
+            Token::Number
+        } else if is_ident(c) {
+            while i < chars.len() && is_ident(chars[i]) {
+                i += 1;
+            }
+
+            let word: String = chars[start..i].iter().collect();
+
+            match chars.get(i) {
+                _ if KEYWORDS.contains(&word.as_str()) => Token::Keyword,
+                Some('!') => {
+                    i += 1;
+                    Token::Macro
+                }
+                Some('(') => Token::Function,
+                Some(':') if chars.get(i + 2) == Some(&'<') => Token::Function,
+                _ if c.is_uppercase() => Token::Type,
+                _ => Token::Plain,
+            }
+        } else {
+            i += 1;
+            Token::Plain
+        };
+
+        let end = i.min(chars.len());
+        tokens[start..end].fill(token);
+    }
+
+    tokens
+}
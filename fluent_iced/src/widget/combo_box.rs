@@ -1,3 +1,5 @@
+//! Editable combo box.
+
 use crate::{fluent_icon::FluentIcon, font, widget::ComboBox};
 
 use iced::{
@@ -19,11 +21,11 @@ where
     T: std::fmt::Display + Clone,
 {
     ComboBox::new(state, placeholder, selection, on_selected)
-        .font(font::SEGOE)
+        .font(font::regular())
         .line_height(LineHeight::Absolute(Pixels(20.0)))
         .icon(Icon {
-            font: font::SEGOE_FLUENT_ICONS,
-            code_point: FluentIcon::ChevronDown.codepoint(),
+            font: font::icons(),
+            code_point: font::glyph(FluentIcon::ChevronDown.codepoint()),
             size: Some(10.into()),
             spacing: 4.0,
             side: Side::Right,
@@ -1,3 +1,5 @@
+//! Content dialog with title, primary, secondary and close buttons.
+
 use crate::{
     style,
     widget::{self, button, text, Column, Container, Element, Row},
@@ -0,0 +1,248 @@
+//! A handle which is dragged horizontally to resize the widget beside it, such
+//! as the pane of a [`SideNav`](crate::widget::side_nav::SideNav).
+
+use crate::{
+    style::drag_handle::{Catalog, Status, Style, StyleFn},
+    theme,
+};
+
+use iced::{
+    advanced::{
+        self,
+        layout::{Limits, Node},
+        mouse::{click, Click},
+        renderer,
+        widget::{tree, Tree},
+        Clipboard, Layout, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    Color, Element, Event, Length, Point, Rectangle, Size,
+};
+
+pub struct DragHandle<'a, Message, Theme = theme::Theme>
+where
+    Theme: Catalog,
+{
+    width: Length,
+    height: Length,
+    on_drag: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+    on_release: Option<Message>,
+    on_double_click: Option<Message>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme> DragHandle<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    pub fn new() -> Self {
+        Self {
+            width: Length::Fixed(8.0),
+            height: Length::Fill,
+            on_drag: None,
+            on_release: None,
+            on_double_click: None,
+            class: Theme::default(),
+        }
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sent while dragging with the horizontal distance from where the drag
+    /// started, which is negative to the left.
+    pub fn on_drag(mut self, on_drag: impl Fn(f32) -> Message + 'a) -> Self {
+        self.on_drag = Some(Box::new(on_drag));
+        self
+    }
+
+    pub fn on_release(mut self, message: Message) -> Self {
+        self.on_release = Some(message);
+        self
+    }
+
+    pub fn on_double_click(mut self, message: Message) -> Self {
+        self.on_double_click = Some(message);
+        self
+    }
+
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+}
+
+impl<'a, Message, Theme> Default for DragHandle<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Default)]
+struct State {
+    // Where the cursor was pressed, while dragging
+    drag_origin: Option<Point>,
+    last_click: Option<Click>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DragHandle<'a, Message, Theme>
+where
+    Message: Clone,
+    Renderer: advanced::Renderer,
+    Theme: Catalog,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(limits.resolve(self.width, self.height, Size::ZERO))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut advanced::Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(layout.bounds()) else {
+                    return event::Status::Ignored;
+                };
+
+                let click = Click::new(position, mouse::Button::Left, state.last_click);
+                state.last_click = Some(click);
+
+                match (click.kind(), &self.on_double_click) {
+                    (click::Kind::Double, Some(message)) => {
+                        state.drag_origin = None;
+                        shell.publish(message.clone());
+                    }
+                    _ => state.drag_origin = Some(position),
+                }
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                match (state.drag_origin, &self.on_drag) {
+                    (Some(origin), Some(on_drag)) => {
+                        shell.publish(on_drag(position.x - origin.x));
+                        event::Status::Captured
+                    }
+                    _ => event::Status::Ignored,
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if state.drag_origin.is_some() =>
+            {
+                state.drag_origin = None;
+
+                if let Some(message) = &self.on_release {
+                    shell.publish(message.clone());
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let status = if state.drag_origin.is_some() {
+            Status::Dragged
+        } else if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+        let Style { colour, width } = Catalog::style(theme, &self.class, status);
+
+        if colour == Color::TRANSPARENT {
+            return;
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.center_x() - width / 2.0,
+                    width,
+                    ..bounds
+                },
+                ..renderer::Quad::default()
+            },
+            colour,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.drag_origin.is_some() || cursor.is_over(layout.bounds()) {
+            mouse::Interaction::ResizingHorizontally
+        } else {
+            mouse::Interaction::None
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<DragHandle<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Theme: 'a + Catalog,
+    Renderer: 'a + advanced::Renderer,
+{
+    fn from(handle: DragHandle<'a, Message, Theme>) -> Self {
+        Element::new(handle)
+    }
+}
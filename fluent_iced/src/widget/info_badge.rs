@@ -0,0 +1,96 @@
+//! Small status indicator, similar to the WinUI `InfoBadge`.
+//!
+//! A badge is a dot, an icon or a number. [`attach`] layers it onto the top right
+//! corner of another widget, such as a [`crate::widget::ribbon::button::large`];
+//! the side navigation takes badges with `badge` on its groups and nodes.
+
+use crate::{
+    fluent_icon::FluentIcon,
+    style,
+    widget::{text, Container, Element},
+};
+
+use iced::{
+    alignment::{Horizontal, Vertical},
+    widget::{horizontal_space, stack},
+    Length, Padding,
+};
+
+/// Status a badge conveys, which picks its colour from the `system_fill_color_*`
+/// tokens of the palette.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Severity {
+    #[default]
+    Attention,
+    Informational,
+    Success,
+    Critical,
+}
+
+impl Severity {
+    pub const ALL: [Severity; 4] = [
+        Severity::Attention,
+        Severity::Informational,
+        Severity::Success,
+        Severity::Critical,
+    ];
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Attention => write!(f, "Attention"),
+            Severity::Informational => write!(f, "Informational"),
+            Severity::Success => write!(f, "Success"),
+            Severity::Critical => write!(f, "Critical"),
+        }
+    }
+}
+
+pub fn dot<'a, Message: 'a>(severity: Severity) -> Container<'a, Message> {
+    Container::new(horizontal_space())
+        .width(6)
+        .height(6)
+        .style(move |theme| style::container::info_badge(theme, severity))
+}
+
+pub fn icon<'a, Message: 'a>(icon: FluentIcon, severity: Severity) -> Container<'a, Message> {
+    Container::new(text::icon(icon.codepoint()).size(8))
+        .width(16)
+        .height(16)
+        .align_x(Horizontal::Center)
+        .align_y(Vertical::Center)
+        .style(move |theme| style::container::info_badge(theme, severity))
+}
+
+/// Numbers above 99 are shown as "99+".
+pub fn number<'a, Message: 'a>(value: u32, severity: Severity) -> Container<'a, Message> {
+    let label = if value > 99 {
+        String::from("99+")
+    } else {
+        value.to_string()
+    };
+
+    Container::new(text::caption1(label).size(11))
+        .height(16)
+        .padding([0, 5])
+        .align_y(Vertical::Center)
+        .style(move |theme| style::container::info_badge(theme, severity))
+}
+
+/// Layers `badge` onto the top right corner of `base`, which keeps its size.
+pub fn attach<'a, Message: 'a>(
+    base: impl Into<Element<'a, Message>>,
+    badge: impl Into<Element<'a, Message>>,
+) -> Element<'a, Message> {
+    stack![
+        base.into(),
+        Container::new(badge)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(Padding::new(2.0))
+            .align_x(Horizontal::Right)
+            .align_y(Vertical::Top)
+    ]
+    .into()
+}
@@ -1,3 +1,5 @@
+//! Menus, menu bars and their items.
+
 use crate::widget::{Menu, MenuItem};
 
 pub fn standard<Message>(items: Vec<MenuItem<Message>>) -> Menu<Message> {
@@ -1,3 +1,5 @@
+//! Numeric input with spin buttons, adapted from `iced_aw`.
+
 use crate::{
     fluent_icon::FluentIcon,
     font, style, theme,
@@ -685,10 +687,10 @@ where
 
         renderer.fill_text(
             iced::advanced::text::Text {
-                content: FluentIcon::ChevronDown.codepoint().to_string(),
+                content: font::glyph(FluentIcon::ChevronDown.codepoint()).to_string(),
                 bounds: Size::new(dec_bounds.width, dec_bounds.height),
                 size: icon_size,
-                font: font::SEGOE_FLUENT_ICONS,
+                font: font::icons(),
                 horizontal_alignment: Horizontal::Center,
                 vertical_alignment: Vertical::Center,
                 line_height: LineHeight::Relative(1.3),
@@ -720,10 +722,10 @@ where
 
         renderer.fill_text(
             iced::advanced::text::Text {
-                content: FluentIcon::ChevronUp.codepoint().to_string(),
+                content: font::glyph(FluentIcon::ChevronUp.codepoint()).to_string(),
                 bounds: Size::new(inc_bounds.width, inc_bounds.height),
                 size: icon_size,
-                font: font::SEGOE_FLUENT_ICONS,
+                font: font::icons(),
                 horizontal_alignment: Horizontal::Center,
                 vertical_alignment: Vertical::Center,
                 line_height: LineHeight::Relative(1.3),
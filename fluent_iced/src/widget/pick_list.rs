@@ -1,3 +1,5 @@
+//! Non-editable combo box.
+
 use crate::{fluent_icon::FluentIcon, font, widget::PickList};
 
 use iced::{
@@ -22,12 +24,12 @@ where
     Message: Clone,
 {
     PickList::new(options, selected, on_select)
-        .font(font::SEGOE)
+        .font(font::regular())
         .text_size(14)
         .text_line_height(LineHeight::Absolute(Pixels(20.0)))
         .handle(Handle::Static(Icon {
-            font: font::SEGOE_FLUENT_ICONS,
-            code_point: FluentIcon::ChevronDown.codepoint(),
+            font: font::icons(),
+            code_point: font::glyph(FluentIcon::ChevronDown.codepoint()),
             size: Some(10.into()),
             line_height: LineHeight::default(),
             shaping: iced::widget::text::Shaping::Advanced,
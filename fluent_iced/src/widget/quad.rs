@@ -1,5 +1,6 @@
-// A Quad widget exists in iced_aw but it can't be styled according to the theme
-// and this one can wrap around another widget
+//! A Quad widget exists in iced_aw but it can't be styled according to the theme
+//! and this one can wrap around another widget.
+
 use crate::{
     style::{
         self,
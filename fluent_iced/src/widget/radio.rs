@@ -1,3 +1,5 @@
+//! Radio buttons.
+
 use crate::{font, style, widget::Radio};
 
 use iced::{widget::text::LineHeight, Pixels};
@@ -15,7 +17,7 @@ where
 {
     iced::widget::Radio::new(label, value, selected, f)
         .size(20)
-        .font(font::SEGOE)
+        .font(font::regular())
         .text_size(14)
         .text_line_height(LineHeight::Absolute(Pixels(20.0)))
         .style(style::radio::default)
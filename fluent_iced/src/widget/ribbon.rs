@@ -1,3 +1,5 @@
+//! Buttons and separators for a ribbon toolbar.
+
 use crate::{
     style,
     widget::{Container, Element, Quad},
@@ -91,7 +93,7 @@ pub mod split_button {
 
     use crate::{
         fluent_icon::FluentIcon,
-        theme,
+        style, theme,
         widget::{text, Button, Column, DropDown, Element, Quad},
     };
 
@@ -119,42 +121,8 @@ pub mod split_button {
         type QuadStatus = crate::style::quad::Status;
         type QuadStyle = crate::style::quad::Style;
 
-        fn base_style(theme: &theme::Theme, status: ButtonStatus) -> ButtonStyle {
-            let palette = theme.palette();
-            let base = ButtonStyle {
-                background: Some(palette.subtle_fill_color_transparent.into()),
-                text_color: palette.text_fill_color_primary,
-                border: Border {
-                    color: palette.control_stroke_color_default,
-                    radius: Radius::new(4),
-                    width: 1.0,
-                },
-                shadow: Shadow::default(),
-            };
-
-            match status {
-                ButtonStatus::Active => ButtonStyle {
-                    border: Border::default(),
-                    ..base
-                },
-                ButtonStatus::Hovered => ButtonStyle {
-                    background: Some(palette.subtle_fill_color_secondary.into()),
-                    ..base
-                },
-                ButtonStatus::Pressed => ButtonStyle {
-                    background: Some(palette.subtle_fill_color_tertiary.into()),
-                    ..base
-                },
-                ButtonStatus::Disabled => ButtonStyle {
-                    background: Some(palette.subtle_fill_color_disabled.into()),
-                    text_color: palette.text_fill_color_disabled,
-                    ..base
-                },
-            }
-        }
-
         fn icon_button_style(theme: &theme::Theme, status: ButtonStatus) -> ButtonStyle {
-            let base = base_style(theme, status);
+            let base = style::button::ribbon_split(theme, status);
 
             ButtonStyle {
                 border: Border {
@@ -166,7 +134,7 @@ pub mod split_button {
         }
 
         fn indicator_button_style(theme: &theme::Theme, status: ButtonStatus) -> ButtonStyle {
-            let base = base_style(theme, status);
+            let base = style::button::ribbon_split(theme, status);
 
             ButtonStyle {
                 border: Border {
@@ -1,3 +1,5 @@
+//! Scrollable with thin Fluent scrollbars.
+
 use crate::{
     style,
     widget::{Element, Scrollable},
@@ -0,0 +1,1061 @@
+//! Navigation pane, similar to the WinUI `NavigationView`.
+
+use crate::{
+    fluent_icon::FluentIcon,
+    font, style,
+    widget::{
+        info_badge, scrollable, text, text_input, Button, Column, Container, DropDown, Element,
+        Row, Text, TextInput,
+    },
+};
+
+use std::fmt;
+
+use iced::{
+    alignment::{Horizontal, Vertical},
+    widget::{
+        horizontal_space, row, stack,
+        text::{Fragment, IntoFragment},
+        text_input::{Icon, Id, Side},
+        Space,
+    },
+    Length, Padding,
+};
+
+use iced_aw::drop_down::Alignment;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DisplayMode {
+    // Nav button and NavItem icons
+    Compact,
+    // Nav button and NavItems with icons and labels
+    Full,
+    // Groups in a row above the content, the ones which don't fit under "More"
+    Top,
+    // Only the nav button, which opens the pane in an overlay
+    Minimal,
+}
+
+impl DisplayMode {
+    pub const ALL: [DisplayMode; 4] = [
+        DisplayMode::Full,
+        DisplayMode::Compact,
+        DisplayMode::Minimal,
+        DisplayMode::Top,
+    ];
+}
+
+// Named after the WinUI `NavigationViewPaneDisplayMode`
+impl fmt::Display for DisplayMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DisplayMode::Compact => write!(f, "Left compact"),
+            DisplayMode::Full => write!(f, "Left"),
+            DisplayMode::Top => write!(f, "Top"),
+            DisplayMode::Minimal => write!(f, "Left minimal"),
+        }
+    }
+}
+
+/// Window widths at which a left pane changes mode, like `CompactModeThresholdWidth`
+/// and `ExpandedModeThresholdWidth` of the WinUI `NavigationView`.
+///
+/// ```
+/// use fluent_iced::widget::side_nav::{Breakpoints, DisplayMode};
+///
+/// let breakpoints = Breakpoints::default();
+///
+/// assert_eq!(breakpoints.display_mode(DisplayMode::Full, 1200.0), DisplayMode::Full);
+/// assert_eq!(breakpoints.display_mode(DisplayMode::Full, 800.0), DisplayMode::Compact);
+/// assert_eq!(breakpoints.display_mode(DisplayMode::Compact, 500.0), DisplayMode::Minimal);
+/// assert_eq!(breakpoints.display_mode(DisplayMode::Top, 500.0), DisplayMode::Top);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Breakpoints {
+    /// Narrower windows show the pane in [`DisplayMode::Minimal`].
+    pub compact: f32,
+    /// Narrower windows show the pane in [`DisplayMode::Compact`] at most.
+    pub full: f32,
+}
+
+impl Breakpoints {
+    /// The mode a pane set to `display_mode` is shown in at the window `width`. A
+    /// pane never gets wider than its mode, and the top mode doesn't change.
+    pub fn display_mode(&self, display_mode: DisplayMode, width: f32) -> DisplayMode {
+        match display_mode {
+            DisplayMode::Top | DisplayMode::Minimal => display_mode,
+            _ if width < self.compact => DisplayMode::Minimal,
+            DisplayMode::Full if width < self.full => DisplayMode::Compact,
+            _ => display_mode,
+        }
+    }
+}
+
+impl Default for Breakpoints {
+    // The defaults of WinUI
+    fn default() -> Self {
+        Self {
+            compact: 641.0,
+            full: 1008.0,
+        }
+    }
+}
+
+pub struct SideNav<'a, Message>
+where
+    Message: 'a + Clone,
+{
+    full_width: Length,
+    height: Length,
+    display_mode: DisplayMode,
+    breakpoints: Breakpoints,
+    window_width: Option<f32>,
+    pane_open: bool,
+    more_expanded: bool,
+    on_more_pressed: Option<Message>,
+    on_overlay_dismiss: Option<Message>,
+    search: Option<Search<'a, Message>>,
+    groups: Vec<Group<'a, Message>>,
+    footer_groups: Vec<Group<'a, Message>>,
+    on_nav_button_pressed: Message,
+    show_back_button: bool,
+    on_back_button_pressed: Option<Message>,
+}
+
+impl<'a, Message> SideNav<'a, Message>
+where
+    Message: 'a + Clone,
+{
+    pub fn new(display_mode: DisplayMode, on_nav_button_pressed: Message) -> Self {
+        Self {
+            full_width: Length::Fixed(300.0),
+            height: Length::Fill,
+            display_mode,
+            breakpoints: Breakpoints::default(),
+            window_width: None,
+            pane_open: false,
+            more_expanded: false,
+            on_more_pressed: None,
+            on_overlay_dismiss: None,
+            search: None,
+            groups: Vec::new(),
+            footer_groups: Vec::new(),
+            on_nav_button_pressed,
+            show_back_button: false,
+            on_back_button_pressed: None,
+        }
+    }
+
+    /// Width of the pane in the full mode, and of the pane overlay.
+    pub fn full_width(mut self, width: impl Into<Length>) -> Self {
+        self.full_width = width.into();
+        self
+    }
+
+    pub fn breakpoints(mut self, breakpoints: Breakpoints) -> Self {
+        self.breakpoints = breakpoints;
+        self
+    }
+
+    /// Adapts the mode to the window with the [`Breakpoints`]. The top mode also
+    /// uses the width to decide which groups go under "More".
+    pub fn window_width(mut self, width: f32) -> Self {
+        self.window_width = Some(width);
+        self
+    }
+
+    /// Shows the full pane in an overlay, when in compact or minimal mode.
+    pub fn pane_open(mut self, open: bool) -> Self {
+        self.pane_open = open;
+        self
+    }
+
+    /// Shows the groups which don't fit in the top mode, from the "More" button.
+    pub fn more_expanded(mut self, expanded: bool) -> Self {
+        self.more_expanded = expanded;
+        self
+    }
+
+    /// Sent by the "More" button. Without it the top mode shows every group.
+    pub fn on_more_pressed(mut self, message: Message) -> Self {
+        self.on_more_pressed = Some(message);
+        self
+    }
+
+    /// Sent when the pane overlay or the "More" overlay is dismissed.
+    pub fn on_overlay_dismiss(mut self, message: Message) -> Self {
+        self.on_overlay_dismiss = Some(message);
+        self
+    }
+
+    /// Shows a back button next to the nav button, disabled if `on_press` is `None`.
+    pub fn back_button(mut self, on_press: Option<Message>) -> Self {
+        self.show_back_button = true;
+        self.on_back_button_pressed = on_press;
+        self
+    }
+
+    pub fn with_search(mut self, search: Search<'a, Message>) -> Self {
+        self.search = Some(search);
+        self
+    }
+
+    pub fn with_groups(mut self, items: impl IntoIterator<Item = Group<'a, Message>>) -> Self {
+        self.groups.extend(items);
+        self
+    }
+
+    pub fn with_footer_groups(
+        mut self,
+        items: impl IntoIterator<Item = Group<'a, Message>>,
+    ) -> Self {
+        self.footer_groups.extend(items);
+        self
+    }
+
+    /// The mode the pane is shown in, after applying the [`Breakpoints`].
+    pub fn display_mode(&self) -> DisplayMode {
+        self.window_width.map_or(self.display_mode, |width| {
+            self.breakpoints.display_mode(self.display_mode, width)
+        })
+    }
+
+    fn nav_button(&self) -> Button<'a, Message> {
+        button_base(compact_view_contents(
+            FluentIcon::GlobalNavButton.codepoint(),
+        ))
+        .on_press(self.on_nav_button_pressed.clone())
+    }
+
+    fn back_button_view(&self) -> Option<Button<'a, Message>> {
+        self.show_back_button.then(|| {
+            button_base(compact_view_contents(FluentIcon::Back.codepoint()))
+                .width(Length::Shrink)
+                .on_press_maybe(self.on_back_button_pressed.clone())
+        })
+    }
+
+    // The back and nav buttons stacked, for the compact and minimal modes
+    fn compact_buttons(&self) -> Vec<Element<'a, Message>> {
+        let mut buttons: Vec<Element<'a, Message>> = self
+            .back_button_view()
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        buttons.push(self.nav_button().width(Length::Shrink).into());
+
+        buttons
+    }
+
+    fn compact_view(self) -> Element<'a, Message> {
+        let mut contents = self.compact_buttons();
+
+        if let Some(search) = self.search {
+            contents.push(search.compact_view());
+        }
+
+        let group_buttons = scrollable::standard(Column::with_children(
+            self.groups.into_iter().map(|group| group.compact_view()),
+        ))
+        .height(Length::Fill)
+        .into();
+
+        let footer_buttons = self
+            .footer_groups
+            .into_iter()
+            .map(|group| group.compact_view());
+
+        contents.push(group_buttons);
+        contents.extend(footer_buttons);
+
+        Column::with_children(contents).height(self.height).into()
+    }
+
+    fn full_view(self) -> Element<'a, Message> {
+        let mut contents = vec![row![]
+            .push_maybe(self.back_button_view())
+            .push(self.nav_button())
+            .into()];
+
+        if let Some(search) = self.search {
+            contents.push(search.full_view());
+        }
+
+        let group_items = scrollable::standard(Column::with_children(
+            self.groups.into_iter().map(|group| group.full_view()),
+        ))
+        .height(Length::Fill)
+        .into();
+
+        let footer_group_items = self
+            .footer_groups
+            .into_iter()
+            .map(|group| group.full_view());
+
+        contents.push(group_items);
+        contents.extend(footer_group_items);
+
+        Column::with_children(contents)
+            .width(self.full_width)
+            .height(self.height)
+            .into()
+    }
+
+    // The full pane in an overlay over the buttons, which is anchored to an empty
+    // widget as the overlays of drop downs are placed relative to their underlay
+    fn overlay_view(self) -> Element<'a, Message> {
+        let buttons = Column::with_children(self.compact_buttons()).height(self.height);
+        let on_overlay_dismiss = self.on_overlay_dismiss.clone();
+        let width = self.full_width;
+
+        let pane = Container::new(self.full_view())
+            .padding(4)
+            .style(style::container::overlay);
+
+        let anchor = DropDown::new(Space::new(0, 0), pane, true)
+            .width(width)
+            .height(Length::Fill)
+            .alignment(Alignment::BottomEnd)
+            .offset(0.0);
+
+        let anchor = match on_overlay_dismiss {
+            Some(message) => anchor.on_dismiss(message),
+            None => anchor,
+        };
+
+        row![anchor, buttons].into()
+    }
+
+    fn top_view(self) -> Element<'a, Message> {
+        const SEARCH_WIDTH: f32 = 240.0;
+        const MORE_WIDTH: f32 = 72.0;
+        const BACK_BUTTON_WIDTH: f32 = 40.0;
+
+        let mut contents: Vec<Element<'a, Message>> = self
+            .back_button_view()
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        let mut reserved: f32 = self.footer_groups.iter().map(Group::top_width).sum();
+        reserved += MORE_WIDTH;
+
+        if self.show_back_button {
+            reserved += BACK_BUTTON_WIDTH;
+        }
+
+        if self.search.is_some() {
+            reserved += SEARCH_WIDTH;
+        }
+
+        let mut available = match (self.window_width, &self.on_more_pressed) {
+            (Some(width), Some(_)) => width - reserved,
+            _ => f32::INFINITY,
+        };
+        let mut groups = self.groups.into_iter().peekable();
+
+        while let Some(group) = groups.next_if(|group| group.top_width() <= available) {
+            available -= group.top_width();
+            contents.push(group.top_view());
+        }
+
+        let overflow: Vec<_> = groups.collect();
+
+        if let (false, Some(on_press)) = (overflow.is_empty(), self.on_more_pressed) {
+            contents.push(more_view(
+                overflow,
+                self.more_expanded,
+                on_press,
+                self.on_overlay_dismiss,
+            ));
+        }
+
+        contents.push(horizontal_space().into());
+
+        if let Some(search) = self.search {
+            contents.push(
+                Container::new(search.full_view())
+                    .width(SEARCH_WIDTH)
+                    .into(),
+            );
+        }
+
+        contents.extend(self.footer_groups.into_iter().map(Group::top_view));
+
+        Row::with_children(contents)
+            .width(Length::Fill)
+            .align_y(Vertical::Center)
+            .into()
+    }
+}
+
+impl<'a, Message> From<SideNav<'a, Message>> for Element<'a, Message>
+where
+    Message: 'a + Clone,
+{
+    fn from(side_nav: SideNav<'a, Message>) -> Self {
+        match side_nav.display_mode() {
+            DisplayMode::Compact | DisplayMode::Minimal if side_nav.pane_open => {
+                side_nav.overlay_view()
+            }
+            DisplayMode::Compact => side_nav.compact_view(),
+            DisplayMode::Full => side_nav.full_view(),
+            DisplayMode::Top => side_nav.top_view(),
+            DisplayMode::Minimal => Column::with_children(side_nav.compact_buttons())
+                .height(side_nav.height)
+                .into(),
+        }
+    }
+}
+
+/// Search box at the top of the pane, similar to an `AutoSuggestBox`.
+///
+/// In full mode the box is shown with suggestions below it. In compact mode there
+/// is a search button instead, which opens the box and suggestions in an overlay.
+/// Finding the suggestions for the query is left to the application.
+pub struct Search<'a, Message>
+where
+    Message: 'a + Clone,
+{
+    query: &'a str,
+    placeholder: &'a str,
+    id: Option<Id>,
+    suggestions: Vec<NavNode<'a, Message>>,
+    expanded: bool,
+    on_input: Box<dyn Fn(String) -> Message + 'a>,
+    on_submit: Option<Message>,
+    on_press: Message,
+    on_overlay_dismiss: Option<Message>,
+}
+
+impl<'a, Message> Search<'a, Message>
+where
+    Message: 'a + Clone,
+{
+    /// `on_press` is sent by the search button in compact mode.
+    pub fn new(
+        query: &'a str,
+        on_input: impl Fn(String) -> Message + 'a,
+        on_press: Message,
+    ) -> Self {
+        Self {
+            query,
+            placeholder: "Search",
+            id: None,
+            suggestions: Vec::new(),
+            expanded: false,
+            on_input: Box::new(on_input),
+            on_submit: None,
+            on_press,
+            on_overlay_dismiss: None,
+        }
+    }
+
+    pub fn placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = placeholder;
+        self
+    }
+
+    /// Id of the text input, to focus it with [`iced::widget::text_input::focus`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn with_suggestions(
+        mut self,
+        items: impl IntoIterator<Item = NavNode<'a, Message>>,
+    ) -> Self {
+        self.suggestions.extend(items);
+        self
+    }
+
+    /// Shows the suggestions, or the overlay in compact mode.
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
+    /// Sent when Enter is pressed in the search box.
+    pub fn on_submit(mut self, message: Message) -> Self {
+        self.on_submit = Some(message);
+        self
+    }
+
+    pub fn on_overlay_dismiss(mut self, message: Message) -> Self {
+        self.on_overlay_dismiss = Some(message);
+        self
+    }
+
+    fn full_view(self) -> Element<'a, Message> {
+        let show_suggestions = self.expanded && !self.query.is_empty();
+        let on_overlay_dismiss = self.on_overlay_dismiss.clone();
+
+        let (input, suggestions) = self.contents();
+
+        let search = DropDown::new(
+            Container::new(input).padding([2, 4]),
+            Container::new(suggestions).style(style::container::overlay),
+            show_suggestions,
+        )
+        .width(Length::Fill)
+        .alignment(Alignment::Bottom);
+
+        if let Some(message) = on_overlay_dismiss {
+            search.on_dismiss(message).into()
+        } else {
+            search.into()
+        }
+    }
+
+    fn compact_view(self) -> Element<'a, Message> {
+        let underlay = button_base(compact_view_contents(FluentIcon::Search.codepoint()))
+            .width(Length::Shrink)
+            .on_press(self.on_press.clone());
+
+        let expanded = self.expanded;
+        let on_overlay_dismiss = self.on_overlay_dismiss.clone();
+        let show_suggestions = !self.query.is_empty();
+
+        let (input, suggestions) = self.contents();
+
+        let mut overlay = Column::new().push(Container::new(input).padding(4));
+
+        if show_suggestions {
+            overlay = overlay.push(suggestions);
+        }
+
+        let search = DropDown::new(
+            underlay,
+            Container::new(overlay)
+                .width(300)
+                .style(style::container::overlay),
+            expanded,
+        )
+        .width(Length::Shrink)
+        .alignment(Alignment::BottomEnd);
+
+        if let Some(message) = on_overlay_dismiss {
+            search.on_dismiss(message).into()
+        } else {
+            search.into()
+        }
+    }
+
+    // The search box and the list of suggestions
+    fn contents(self) -> (TextInput<'a, Message>, Element<'a, Message>) {
+        let mut input = text_input::standard(self.placeholder, self.query)
+            .on_input(self.on_input)
+            .on_submit_maybe(self.on_submit)
+            .icon(Icon {
+                font: font::icons(),
+                code_point: font::glyph(FluentIcon::Search.codepoint()),
+                size: Some(12.into()),
+                spacing: 8.0,
+                side: Side::Right,
+            });
+
+        if let Some(id) = self.id {
+            input = input.id(id);
+        }
+
+        let suggestions: Element<'a, Message> = if self.suggestions.is_empty() {
+            Container::new(text::body1("No results found"))
+                .padding([8, 12])
+                .into()
+        } else {
+            Column::with_children(self.suggestions.into_iter().map(|item| {
+                button_base(full_view_contents(item.icon, item.label))
+                    .on_press(item.on_press)
+                    .into()
+            }))
+            .padding(4)
+            .into()
+        };
+
+        (input, suggestions)
+    }
+}
+
+pub struct Group<'a, Message>
+where
+    Message: 'a + Clone,
+{
+    icon: char,
+    label: Fragment<'a>,
+    overlay_width: Length,
+    items: Vec<NavNode<'a, Message>>,
+    badge: Option<Element<'a, Message>>,
+    expanded: bool,
+    selected: bool,
+    on_press: Message,
+    on_overlay_dismiss: Option<Message>,
+}
+
+impl<'a, Message> Group<'a, Message>
+where
+    Message: 'a + Clone,
+{
+    pub fn new(icon: char, label: impl IntoFragment<'a>, on_press: Message) -> Self {
+        Self {
+            icon,
+            label: label.into_fragment(),
+            overlay_width: 300.into(),
+            items: Vec::new(),
+            badge: None,
+            expanded: false,
+            selected: false,
+            on_press,
+            on_overlay_dismiss: None,
+        }
+    }
+
+    pub fn overlay_width(mut self, width: impl Into<Length>) -> Self {
+        self.overlay_width = width.into();
+        self
+    }
+
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
+    /// Marks the group as the current page. A group with a selected item is
+    /// highlighted too, unless its items are shown below it.
+    pub fn selected(mut self, selected: bool) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// An [`info_badge`] shown beside the label, or on the icon in compact mode.
+    pub fn badge(mut self, badge: impl Into<Element<'a, Message>>) -> Self {
+        self.badge = Some(badge.into());
+        self
+    }
+
+    pub fn with_items(mut self, items: impl IntoIterator<Item = NavNode<'a, Message>>) -> Self {
+        self.items.extend(items);
+        self
+    }
+
+    pub fn on_overlay_dismiss(mut self, message: Message) -> Self {
+        self.on_overlay_dismiss = Some(message);
+        self
+    }
+
+    fn full_view(self) -> Element<'a, Message> {
+        let indicator = if self.items.is_empty() {
+            None
+        } else if self.expanded {
+            Some(FluentIcon::ChevronUp)
+        } else {
+            Some(FluentIcon::ChevronDown)
+        };
+
+        let selected = self.selected || (!self.expanded && self.has_selected_item());
+
+        let header = selectable(
+            button_base(
+                full_view_contents(Some(self.icon), self.label)
+                    .push_maybe(self.badge)
+                    .push_maybe(indicator.map(|chevron| text::icon(chevron.codepoint()).size(8))),
+            )
+            .on_press(self.on_press),
+            selected,
+        );
+
+        if self.expanded {
+            let mut contents = vec![header];
+            contents.extend(self.items.into_iter().map(|item| item.full_view(1)));
+
+            Column::with_children(contents).into()
+        } else {
+            header
+        }
+    }
+
+    fn compact_view(self) -> Element<'a, Message> {
+        let selected = self.selected || self.has_selected_item();
+
+        let underlay = selectable(
+            button_base(compact_view_contents(self.icon))
+                .width(Length::Shrink)
+                .on_press(self.on_press),
+            selected,
+        );
+
+        let underlay = match self.badge {
+            Some(badge) => info_badge::attach(underlay, badge),
+            None => underlay,
+        };
+
+        let overlay = items_overlay(self.items, self.overlay_width);
+
+        let button = DropDown::new(underlay, overlay, self.expanded)
+            .width(Length::Shrink)
+            .alignment(Alignment::BottomEnd);
+
+        if let Some(message) = self.on_overlay_dismiss {
+            button.on_dismiss(message).into()
+        } else {
+            button.into()
+        }
+    }
+
+    fn top_view(self) -> Element<'a, Message> {
+        let selected = self.selected || self.has_selected_item();
+
+        let underlay = selectable_top(
+            button_base(
+                top_view_contents(self.icon, self.label)
+                    .push_maybe(self.badge)
+                    .push_maybe(
+                        (!self.items.is_empty())
+                            .then(|| text::icon(FluentIcon::ChevronDown.codepoint()).size(8)),
+                    ),
+            )
+            .width(Length::Shrink)
+            .on_press(self.on_press),
+            selected,
+        );
+
+        if self.items.is_empty() {
+            return underlay;
+        }
+
+        let overlay = items_overlay(self.items, self.overlay_width);
+
+        let button = DropDown::new(underlay, overlay, self.expanded)
+            .width(Length::Shrink)
+            .alignment(Alignment::Bottom);
+
+        if let Some(message) = self.on_overlay_dismiss {
+            button.on_dismiss(message).into()
+        } else {
+            button.into()
+        }
+    }
+
+    // Text isn't measured before layout, so the width in the top mode is estimated
+    // from the length of the label
+    fn top_width(&self) -> f32 {
+        let chevron = if self.items.is_empty() { 0.0 } else { 16.0 };
+
+        48.0 + self.label.chars().count() as f32 * 7.0 + chevron
+    }
+
+    fn has_selected_item(&self) -> bool {
+        self.items.iter().any(NavNode::has_selected)
+    }
+}
+
+/// An item of a [`Group`], which can have items of its own to any depth.
+///
+/// In full mode the children of an expanded node are listed below it, indented. In
+/// the flyouts of the compact and top modes they open in a flyout beside the node.
+pub struct NavNode<'a, Message>
+where
+    Message: 'a + Clone,
+{
+    icon: Option<char>,
+    label: Fragment<'a>,
+    children: Vec<NavNode<'a, Message>>,
+    badge: Option<Element<'a, Message>>,
+    expanded: bool,
+    selected: bool,
+    on_press: Message,
+    on_overlay_dismiss: Option<Message>,
+}
+
+/// The name of [`NavNode`] from before items could be nested, which it is a drop-in
+/// replacement for.
+#[deprecated(note = "renamed to `NavNode`")]
+pub type Item<'a, Message> = NavNode<'a, Message>;
+
+impl<'a, Message> NavNode<'a, Message>
+where
+    Message: 'a + Clone,
+{
+    pub fn new(icon: Option<char>, label: impl IntoFragment<'a>, on_press: Message) -> Self {
+        Self {
+            icon,
+            label: label.into_fragment(),
+            children: Vec::new(),
+            badge: None,
+            expanded: false,
+            selected: false,
+            on_press,
+            on_overlay_dismiss: None,
+        }
+    }
+
+    /// An [`info_badge`] shown beside the label.
+    pub fn badge(mut self, badge: impl Into<Element<'a, Message>>) -> Self {
+        self.badge = Some(badge.into());
+        self
+    }
+
+    pub fn with_children(
+        mut self,
+        children: impl IntoIterator<Item = NavNode<'a, Message>>,
+    ) -> Self {
+        self.children.extend(children);
+        self
+    }
+
+    /// Shows the children, below the node or in a flyout.
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
+    /// Marks the node as the current page. A node with a selected descendant is
+    /// highlighted too, unless its children are shown.
+    pub fn selected(mut self, selected: bool) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    pub fn on_overlay_dismiss(mut self, message: Message) -> Self {
+        self.on_overlay_dismiss = Some(message);
+        self
+    }
+
+    fn has_selected(&self) -> bool {
+        self.selected || self.children.iter().any(NavNode::has_selected)
+    }
+
+    fn full_view(self, depth: u16) -> Element<'a, Message> {
+        let indicator = if self.children.is_empty() {
+            None
+        } else if self.expanded {
+            Some(FluentIcon::ChevronUp)
+        } else {
+            Some(FluentIcon::ChevronDown)
+        };
+
+        let selected =
+            self.selected || (!self.expanded && self.children.iter().any(NavNode::has_selected));
+
+        let button = selectable(
+            button_base(
+                full_view_contents(self.icon, self.label)
+                    .push_maybe(self.badge)
+                    .push_maybe(indicator.map(|chevron| text::icon(chevron.codepoint()).size(8))),
+            )
+            .padding(Padding::from([0, 12]).left(12 + INDENT * depth))
+            .on_press(self.on_press),
+            selected,
+        );
+
+        if self.expanded {
+            let mut contents = vec![button];
+            contents.extend(
+                self.children
+                    .into_iter()
+                    .map(|child| child.full_view(depth + 1)),
+            );
+
+            Column::with_children(contents).into()
+        } else {
+            button
+        }
+    }
+
+    fn flyout_view(self) -> Element<'a, Message> {
+        let selected = self.has_selected();
+
+        let button = selectable(
+            button_base(
+                row![text::body1(self.label), horizontal_space()]
+                    .push_maybe(self.badge)
+                    .push_maybe(
+                        (!self.children.is_empty())
+                            .then(|| text::icon(FluentIcon::ChevronRight.codepoint()).size(8)),
+                    )
+                    .height(Length::Fill)
+                    .spacing(8)
+                    .align_y(Vertical::Center),
+            )
+            .on_press(self.on_press),
+            selected,
+        );
+
+        if self.children.is_empty() {
+            return button;
+        }
+
+        let flyout = DropDown::new(
+            button,
+            items_overlay(self.children, 200.into()),
+            self.expanded,
+        )
+        .alignment(Alignment::BottomEnd);
+
+        if let Some(message) = self.on_overlay_dismiss {
+            flyout.on_dismiss(message).into()
+        } else {
+            flyout.into()
+        }
+    }
+}
+
+// Indentation of each level of nodes in full mode
+const INDENT: u16 = 16;
+
+fn button_base<'a, Message>(content: impl Into<Element<'a, Message>>) -> Button<'a, Message> {
+    Button::new(content)
+        .width(Length::Fill)
+        .height(36.0)
+        .padding([0.0, 12.0])
+        .style(style::button::nav_item)
+}
+
+// Draws the selected background and the accent pill on the left of `button`
+fn selectable<'a, Message: 'a + Clone>(
+    button: Button<'a, Message>,
+    selected: bool,
+) -> Element<'a, Message> {
+    if !selected {
+        return button.into();
+    }
+
+    let indicator = Container::new(
+        Container::new(horizontal_space())
+            .width(3)
+            .height(16)
+            .style(style::container::nav_indicator),
+    )
+    .height(Length::Fill)
+    .align_y(Vertical::Center);
+
+    stack![button.style(style::button::nav_item_selected), indicator].into()
+}
+
+// Same as `selectable`, with the pill below the label as in the top mode
+fn selectable_top<'a, Message: 'a + Clone>(
+    button: Button<'a, Message>,
+    selected: bool,
+) -> Element<'a, Message> {
+    if !selected {
+        return button.into();
+    }
+
+    let indicator = Container::new(
+        Container::new(horizontal_space())
+            .width(16)
+            .height(3)
+            .style(style::container::nav_indicator),
+    )
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .align_x(Horizontal::Center)
+    .align_y(Vertical::Bottom);
+
+    stack![button.style(style::button::nav_item_selected), indicator].into()
+}
+
+fn items_overlay<'a, Message: 'a + Clone>(
+    items: Vec<NavNode<'a, Message>>,
+    width: Length,
+) -> Element<'a, Message> {
+    Container::new(Column::with_children(
+        items.into_iter().map(NavNode::flyout_view),
+    ))
+    .width(width)
+    .style(style::container::overlay)
+    .into()
+}
+
+// The groups which don't fit in the top mode, with their items listed below them
+fn more_view<'a, Message: 'a + Clone>(
+    groups: Vec<Group<'a, Message>>,
+    expanded: bool,
+    on_press: Message,
+    on_overlay_dismiss: Option<Message>,
+) -> Element<'a, Message> {
+    let selected = groups
+        .iter()
+        .any(|group| group.selected || group.has_selected_item());
+
+    let underlay = selectable_top(
+        button_base(
+            row![
+                text::body1("More"),
+                text::icon(FluentIcon::ChevronDown.codepoint()).size(8)
+            ]
+            .height(Length::Fill)
+            .spacing(8)
+            .align_y(Vertical::Center),
+        )
+        .width(Length::Shrink)
+        .on_press(on_press),
+        selected,
+    );
+
+    let contents = groups.into_iter().flat_map(|group| {
+        let header = if group.items.is_empty() {
+            selectable(
+                button_base(
+                    full_view_contents(Some(group.icon), group.label).push_maybe(group.badge),
+                )
+                .on_press(group.on_press),
+                group.selected,
+            )
+        } else {
+            Container::new(text::caption1(group.label))
+                .padding([8, 12])
+                .into()
+        };
+
+        std::iter::once(header).chain(group.items.into_iter().map(|item| item.full_view(1)))
+    });
+
+    let overlay = Container::new(
+        scrollable::standard(Column::with_children(contents).padding(4)).height(Length::Shrink),
+    )
+    .width(240)
+    .max_height(480)
+    .style(style::container::overlay);
+
+    let button = DropDown::new(underlay, overlay, expanded)
+        .width(Length::Shrink)
+        .alignment(Alignment::Bottom);
+
+    if let Some(message) = on_overlay_dismiss {
+        button.on_dismiss(message).into()
+    } else {
+        button.into()
+    }
+}
+
+fn top_view_contents<'a, Message: 'a>(
+    icon: char,
+    label: impl IntoFragment<'a>,
+) -> Row<'a, Message> {
+    row![text::icon(icon), text::body1(label)]
+        .height(Length::Fill)
+        .spacing(8)
+        .align_y(Vertical::Center)
+}
+
+fn full_view_contents<'a, Message: 'a>(
+    icon: Option<char>,
+    label: impl IntoFragment<'a>,
+) -> Row<'a, Message> {
+    let icon: Element<'a, Message> = icon
+        .map_or(horizontal_space().width(16).into(), |codepoint| {
+            text::icon(codepoint).into()
+        });
+
+    row![icon, text::body1(label), horizontal_space(),]
+        .height(Length::Fill)
+        .spacing(18)
+        .align_y(Vertical::Center)
+}
+
+fn compact_view_contents<'a>(icon: char) -> Text<'a> {
+    text::icon(icon)
+        .height(Length::Fill)
+        .align_y(Vertical::Center)
+}
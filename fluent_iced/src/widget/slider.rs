@@ -1,3 +1,5 @@
+//! Horizontal slider.
+
 use crate::{style, widget::Slider};
 
 use std::ops::RangeInclusive;
@@ -1,3 +1,5 @@
+//! Text using the Fluent type ramp, and icons.
+
 use crate::{font, theme::Theme, widget::Text};
 
 use iced::{advanced::text::LineHeight, widget::text, Pixels};
@@ -22,38 +24,38 @@ impl iced::widget::text::Catalog for Theme {
 
 pub fn caption1<'a>(fragment: impl text::IntoFragment<'a>) -> Text<'a> {
     Text::new(fragment)
-        .font(font::SEGOE)
+        .font(font::regular())
         .size(12)
         .line_height(LineHeight::Absolute(Pixels(16.0)))
 }
 
 pub fn body1<'a>(fragment: impl text::IntoFragment<'a>) -> Text<'a> {
     Text::new(fragment)
-        .font(font::SEGOE)
+        .font(font::regular())
         .size(14)
         .line_height(LineHeight::Absolute(Pixels(20.0)))
 }
 
 pub fn bold<'a>(fragment: impl text::IntoFragment<'a>) -> Text<'a> {
-    body1(fragment).font(font::SEGOE_BOLD)
+    body1(fragment).font(font::bold())
 }
 
 pub fn subtitle1<'a>(fragment: impl text::IntoFragment<'a>) -> Text<'a> {
     Text::new(fragment)
-        .font(font::SEGOE_SEMIBOLD)
+        .font(font::semibold())
         .size(20)
         .line_height(LineHeight::Absolute(Pixels(26.0)))
 }
 
 pub fn title2<'a>(fragment: impl text::IntoFragment<'a>) -> Text<'a> {
     Text::new(fragment)
-        .font(font::SEGOE_SEMIBOLD)
+        .font(font::semibold())
         .size(28)
         .line_height(LineHeight::Absolute(Pixels(36.0)))
 }
 
 pub fn icon<'a>(codepoint: char) -> Text<'a> {
-    text(codepoint)
-        .font(font::SEGOE_FLUENT_ICONS)
+    text(font::glyph(codepoint))
+        .font(font::icons())
         .shaping(iced::widget::text::Shaping::Advanced)
 }
@@ -1,3 +1,5 @@
+//! Single line text input.
+
 use crate::{
     font,
     widget::{
@@ -13,7 +15,7 @@ where
     Message: 'a + Clone,
 {
     TextInput::new(placeholder, value)
-        .font(font::SEGOE)
+        .font(font::regular())
         .line_height(LineHeight::Absolute(Pixels(20.0)))
         .size(14)
         .padding(Padding {
@@ -1,3 +1,5 @@
+//! Wrapper which draws an accent underline below focused inputs.
+
 use crate::style;
 
 use iced::{
@@ -9,12 +11,10 @@ use iced::{
     Border, Element, Length, Rectangle, Shadow, Size,
 };
 
-use iced_aw::style::number_input;
-
 pub struct Underline<'a, Message, Theme, Renderer>
 where
     Message: Clone,
-    Theme: style::underline::Catalog + number_input::ExtendedCatalog,
+    Theme: style::underline::Catalog,
     Renderer: advanced::text::Renderer,
 {
     element: ElementType<'a, Message, Theme, Renderer>,
@@ -24,7 +24,7 @@ where
 impl<'a, Message, Theme, Renderer> Underline<'a, Message, Theme, Renderer>
 where
     Message: Clone,
-    Theme: style::underline::Catalog + number_input::ExtendedCatalog,
+    Theme: style::underline::Catalog,
     Renderer: advanced::text::Renderer,
 {
     pub fn new(content: ElementType<'a, Message, Theme, Renderer>) -> Self {
@@ -36,6 +36,7 @@ where
 }
 
 pub enum ElementType<'a, Message, Theme, Renderer> {
+    #[cfg(feature = "number_input")]
     NumberInput(Element<'a, Message, Theme, Renderer>),
     TextInput(Element<'a, Message, Theme, Renderer>),
 }
@@ -43,6 +44,7 @@ pub enum ElementType<'a, Message, Theme, Renderer> {
 impl<'a, Message, Theme, Renderer> ElementType<'a, Message, Theme, Renderer> {
     fn as_widget(&self) -> &dyn Widget<Message, Theme, Renderer> {
         match self {
+            #[cfg(feature = "number_input")]
             ElementType::NumberInput(element) => element.as_widget(),
             ElementType::TextInput(element) => element.as_widget(),
         }
@@ -50,6 +52,7 @@ impl<'a, Message, Theme, Renderer> ElementType<'a, Message, Theme, Renderer> {
 
     fn as_widget_mut(&mut self) -> &mut dyn Widget<Message, Theme, Renderer> {
         match self {
+            #[cfg(feature = "number_input")]
             ElementType::NumberInput(element) => element.as_widget_mut(),
             ElementType::TextInput(element) => element.as_widget_mut(),
         }
@@ -60,7 +63,7 @@ impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Underline<'a, Message, Theme, Renderer>
 where
     Message: Clone,
-    Theme: style::underline::Catalog + iced_aw::style::number_input::ExtendedCatalog,
+    Theme: style::underline::Catalog,
     Renderer: advanced::Renderer + advanced::text::Renderer,
 {
     fn size(&self) -> Size<Length> {
@@ -139,6 +142,7 @@ where
             .draw(tree, renderer, theme, style, layout, cursor, viewport);
 
         let content_bounds = match self.element {
+            #[cfg(feature = "number_input")]
             ElementType::NumberInput(_) => {
                 let mut children = layout.children();
                 children
@@ -159,6 +163,7 @@ where
         let is_mouse_over = cursor.is_over(content_bounds);
 
         let input_tree = match self.element {
+            #[cfg(feature = "number_input")]
             ElementType::NumberInput(_) => &tree.children[0],
             ElementType::TextInput(_) => tree,
         };
@@ -217,7 +222,7 @@ impl<'a, Message, Theme, Renderer> From<Underline<'a, Message, Theme, Renderer>>
     for Element<'a, Message, Theme, Renderer>
 where
     Message: 'a + Clone,
-    Theme: 'a + style::underline::Catalog + number_input::ExtendedCatalog,
+    Theme: 'a + style::underline::Catalog,
     Renderer: 'a + advanced::text::Renderer,
 {
     fn from(underline: Underline<'a, Message, Theme, Renderer>) -> Self {
@@ -0,0 +1,651 @@
+//! Audits the contrast of the colours every style function draws text and the
+//! state of controls with, against the fill they are drawn on, in each built-in
+//! theme and with a range of custom accent colours.
+//!
+//! Text needs a ratio of [`contrast::TEXT`], and the marks and icons of controls,
+//! such as check marks and the knobs of toggle switches, [`contrast::NON_TEXT`].
+//! Disabled controls are left out as WCAG makes no requirement for them, and so
+//! are the [`EXCEPTIONS`] to WinUI and the [`ACCENT_EXCEPTIONS`] with custom
+//! accents. All failures are reported at once.
+//!
+//! The pairs are listed per module of `style` in [`MODULES`], and every module
+//! has to be in it or in [`UNAUDITED`], so a new module can't be missed.
+
+use fluent_iced::{
+    style,
+    theme::{contrast, Contrast, Theme},
+    widget::info_badge::Severity,
+};
+
+use std::fmt::Write;
+
+use iced::{color, overlay, widget, Background, Color};
+
+/// Colours of the accent setting of Windows, which custom themes derive from.
+const ACCENTS: [Color; 6] = [
+    color!(0xFFB900),
+    color!(0xF7630C),
+    color!(0xE81123),
+    color!(0x881798),
+    color!(0x00B7C3),
+    color!(0x107C10),
+];
+
+type Pairs = fn(&Theme) -> Vec<Pair>;
+
+type ButtonStyle = fn(&Theme, widget::button::Status) -> widget::button::Style;
+
+/// Pairs which follow WinUI on purpose, with the reason.
+const EXCEPTIONS: [(&str, &str); 1] = [(
+    "button::primary Pressed",
+    "WinUI dims the text of accent buttons while they are held",
+)];
+
+/// Marks drawn in the accent fill on the window, which lack contrast with light
+/// [`ACCENTS`] as the fill follows the accent, like in WinUI. They are audited in
+/// the themes without a custom accent. Matched by the start of the pair.
+const ACCENT_EXCEPTIONS: [&str; 4] = [
+    "container::nav_indicator",
+    "drag_handle::default Dragged",
+    "slider::default",
+    "underline::default Focused",
+];
+
+/// A colour drawn over a fill, which is drawn on a surface of the window.
+struct Pair {
+    style: String,
+    foreground: Color,
+    background: Color,
+    surface: Color,
+    minimum: f32,
+}
+
+impl Pair {
+    fn text(style: impl Into<String>, foreground: Color, background: Color) -> Self {
+        Self {
+            style: style.into(),
+            foreground,
+            background,
+            surface: Color::TRANSPARENT,
+            minimum: contrast::TEXT,
+        }
+    }
+
+    fn non_text(style: impl Into<String>, foreground: Color, background: Color) -> Self {
+        Self {
+            minimum: contrast::NON_TEXT,
+            ..Self::text(style, foreground, background)
+        }
+    }
+
+    /// Draws the fill on `surface` rather than on the window background.
+    fn on(self, surface: Color) -> Self {
+        Self { surface, ..self }
+    }
+
+    fn ratio(&self, theme: &Theme) -> f32 {
+        let palette = theme.palette();
+        let surface = contrast::over(self.surface, palette.solid_background_fill_color_base);
+        let background = contrast::over(self.background, surface);
+
+        contrast::ratio(contrast::over(self.foreground, background), background)
+    }
+}
+
+/// The themes by name, and whether they have a custom accent.
+fn themes() -> Vec<(String, Theme, bool)> {
+    let mut themes = vec![
+        (Theme::Light.to_string(), Theme::Light, false),
+        (Theme::Dark.to_string(), Theme::Dark, false),
+    ];
+
+    themes.extend(
+        Contrast::ALL.map(|contrast| (contrast.to_string(), Theme::HighContrast(contrast), false)),
+    );
+
+    for accent in ACCENTS {
+        let [r, g, b, _] = accent.into_rgba8();
+
+        for theme in [Theme::Light, Theme::Dark] {
+            let name = format!("{theme} with accent #{r:02X}{g:02X}{b:02X}");
+
+            themes.push((name, theme.with_accent(accent), true));
+        }
+    }
+
+    themes
+}
+
+fn fill(background: impl Into<Option<Background>>) -> Color {
+    match background.into() {
+        None => Color::TRANSPARENT,
+        Some(Background::Color(color)) => color,
+        Some(Background::Gradient(_)) => panic!("gradients are not audited"),
+    }
+}
+
+/// The pairs each module of `style` draws, by module.
+const MODULES: [(&str, Pairs); 16] = [
+    ("button", button),
+    ("checkbox", checkbox),
+    ("code_block", code_block),
+    ("combo_box", combo_box),
+    ("container", container),
+    ("drag_handle", drag_handle),
+    ("menu", menu),
+    ("menu_bar", menu_bar),
+    ("number_input", number_input),
+    ("pick_list", pick_list),
+    ("radio", radio),
+    ("scrollable", scrollable),
+    ("slider", slider),
+    ("text_input", text_input),
+    ("toggler", toggler),
+    ("underline", underline),
+];
+
+/// Modules of `style` which draw nothing that needs contrast, with the reason.
+const UNAUDITED: [(&str, &str); 2] = [
+    ("quad", "separators and outlines are decorative"),
+    ("svg", "images are drawn in their own colours"),
+];
+
+const BUTTON_STATUSES: [widget::button::Status; 3] = [
+    widget::button::Status::Active,
+    widget::button::Status::Hovered,
+    widget::button::Status::Pressed,
+];
+
+fn page_text(theme: &Theme) -> Color {
+    theme.palette().text_fill_color_primary
+}
+
+fn button(theme: &Theme) -> Vec<Pair> {
+    let menu_bar = style::menu_bar::default(theme, iced_aw::style::Status::Active);
+
+    let buttons: [(&str, ButtonStyle); 10] = [
+        ("primary", style::button::primary),
+        ("secondary", style::button::secondary),
+        ("split_content", style::button::split_content),
+        ("split_indicator", style::button::split_indicator),
+        ("transparent", style::button::transparent),
+        ("ribbon_split", style::button::ribbon_split),
+        ("flyout", style::button::flyout),
+        ("menu_item", style::button::menu_item),
+        ("nav_item", style::button::nav_item),
+        ("nav_item_selected", style::button::nav_item_selected),
+    ];
+
+    let mut pairs = Vec::new();
+
+    for (name, style) in buttons {
+        for status in BUTTON_STATUSES {
+            let button = style(theme, status);
+            let pair = Pair::text(
+                format!("button::{name} {status:?}"),
+                button.text_color,
+                fill(button.background),
+            );
+
+            // Menu items are drawn in the menus of menu bars
+            pairs.push(match name {
+                "menu_item" => pair.on(fill(menu_bar.menu_background)),
+                _ => pair,
+            });
+        }
+    }
+
+    pairs
+}
+
+fn checkbox(theme: &Theme) -> Vec<Pair> {
+    let mut pairs = Vec::new();
+
+    for is_checked in [false, true] {
+        for status in [
+            widget::checkbox::Status::Active { is_checked },
+            widget::checkbox::Status::Hovered { is_checked },
+        ] {
+            let checkbox = style::checkbox::primary(theme, status);
+
+            pairs.push(Pair::text(
+                format!("checkbox::primary {status:?} label"),
+                checkbox.text_color.unwrap_or(page_text(theme)),
+                Color::TRANSPARENT,
+            ));
+
+            if is_checked {
+                pairs.push(Pair::non_text(
+                    format!("checkbox::primary {status:?} icon"),
+                    checkbox.icon_color,
+                    fill(checkbox.background),
+                ));
+            }
+        }
+    }
+
+    pairs
+}
+
+fn code_block(theme: &Theme) -> Vec<Pair> {
+    let code = style::code_block::default(theme);
+
+    [
+        ("text", code.text),
+        ("keyword", code.keyword),
+        ("type_name", code.type_name),
+        ("function", code.function),
+        ("macro_name", code.macro_name),
+        ("string", code.string),
+        ("number", code.number),
+        ("comment", code.comment),
+    ]
+    .into_iter()
+    .map(|(token, colour)| {
+        Pair::text(
+            format!("code_block::default {token}"),
+            colour,
+            fill(code.background),
+        )
+    })
+    .collect()
+}
+
+fn combo_box(theme: &Theme) -> Vec<Pair> {
+    let input = <Theme as widget::combo_box::Catalog>::default_input();
+    let menu = <Theme as widget::combo_box::Catalog>::default_menu();
+    let menu = <Theme as overlay::menu::Catalog>::style(theme, &menu);
+
+    let mut pairs = Vec::new();
+
+    for status in [
+        widget::text_input::Status::Active,
+        widget::text_input::Status::Hovered,
+        widget::text_input::Status::Focused,
+    ] {
+        let input = <Theme as widget::text_input::Catalog>::style(theme, &input, status);
+
+        pairs.push(Pair::text(
+            format!("combo_box input {status:?} value"),
+            input.value,
+            fill(input.background),
+        ));
+        pairs.push(Pair::text(
+            format!("combo_box input {status:?} placeholder"),
+            input.placeholder,
+            fill(input.background),
+        ));
+        pairs.push(Pair::non_text(
+            format!("combo_box input {status:?} icon"),
+            input.icon,
+            fill(input.background),
+        ));
+    }
+
+    pairs.push(Pair::text(
+        "combo_box menu text",
+        menu.text_color,
+        fill(menu.background),
+    ));
+    pairs.push(
+        Pair::text(
+            "combo_box menu selected text",
+            menu.selected_text_color,
+            fill(menu.selected_background),
+        )
+        .on(fill(menu.background)),
+    );
+
+    pairs
+}
+
+fn container(theme: &Theme) -> Vec<Pair> {
+    let mut pairs = Vec::new();
+
+    for container in ["overlay", "dialog"] {
+        let style = match container {
+            "overlay" => style::container::overlay(theme),
+            _ => style::container::dialog(theme),
+        };
+
+        pairs.push(Pair::text(
+            format!("container::{container} text"),
+            style.text_color.unwrap_or(page_text(theme)),
+            fill(style.background),
+        ));
+    }
+
+    for severity in Severity::ALL {
+        let badge = style::container::info_badge(theme, severity);
+
+        pairs.push(Pair::text(
+            format!("container::info_badge {severity} text"),
+            badge.text_color.unwrap_or(page_text(theme)),
+            fill(badge.background),
+        ));
+    }
+
+    // The pill beside the selected item of a side nav
+    let indicator = style::container::nav_indicator(theme);
+
+    for status in BUTTON_STATUSES {
+        let item = style::button::nav_item_selected(theme, status);
+
+        pairs.push(Pair::non_text(
+            format!("container::nav_indicator on nav_item_selected {status:?}"),
+            fill(indicator.background),
+            fill(item.background),
+        ));
+    }
+
+    let palette = theme.palette();
+
+    for (token, colour) in [
+        ("primary", palette.accent_text_fill_color_primary),
+        ("secondary", palette.accent_text_fill_color_secondary),
+    ] {
+        pairs.push(Pair::text(
+            format!("accent_text_fill_color_{token}"),
+            colour,
+            Color::TRANSPARENT,
+        ));
+    }
+
+    pairs
+}
+
+fn drag_handle(theme: &Theme) -> Vec<Pair> {
+    // The handle shows no line until it's hovered
+    [
+        ("Hovered", style::drag_handle::Status::Hovered),
+        ("Dragged", style::drag_handle::Status::Dragged),
+    ]
+    .into_iter()
+    .map(|(name, status)| {
+        Pair::non_text(
+            format!("drag_handle::default {name} line"),
+            style::drag_handle::default(theme, status).colour,
+            Color::TRANSPARENT,
+        )
+    })
+    .collect()
+}
+
+fn menu(theme: &Theme) -> Vec<Pair> {
+    let menu = style::menu::default(theme);
+
+    vec![
+        Pair::text("menu::default text", menu.text_color, fill(menu.background)),
+        Pair::text(
+            "menu::default selected text",
+            menu.selected_text_color,
+            fill(menu.selected_background),
+        )
+        .on(fill(menu.background)),
+    ]
+}
+
+fn menu_bar(theme: &Theme) -> Vec<Pair> {
+    let mut pairs = Vec::new();
+
+    // The roots of the bar are menu items drawn on the bar
+    for status in [
+        iced_aw::style::Status::Active,
+        iced_aw::style::Status::Hovered,
+        iced_aw::style::Status::Pressed,
+        iced_aw::style::Status::Focused,
+    ] {
+        let bar = style::menu_bar::default(theme, status);
+
+        for item_status in BUTTON_STATUSES {
+            let item = style::button::menu_item(theme, item_status);
+
+            pairs.push(
+                Pair::text(
+                    format!("menu_bar::default {status:?} root {item_status:?}"),
+                    item.text_color,
+                    fill(item.background),
+                )
+                .on(fill(bar.bar_background)),
+            );
+        }
+    }
+
+    pairs
+}
+
+fn number_input(theme: &Theme) -> Vec<Pair> {
+    [
+        iced_aw::style::Status::Active,
+        iced_aw::style::Status::Hovered,
+        iced_aw::style::Status::Pressed,
+    ]
+    .into_iter()
+    .map(|status| {
+        let number_input = style::number_input::default(theme, status);
+
+        Pair::non_text(
+            format!("number_input::default {status:?} icon"),
+            number_input.icon_color,
+            fill(number_input.button_background),
+        )
+    })
+    .collect()
+}
+
+fn pick_list(theme: &Theme) -> Vec<Pair> {
+    let mut pairs = Vec::new();
+
+    for status in [
+        widget::pick_list::Status::Active,
+        widget::pick_list::Status::Hovered,
+        widget::pick_list::Status::Opened,
+    ] {
+        let pick_list = style::pick_list::default(theme, status);
+
+        pairs.push(Pair::text(
+            format!("pick_list::default {status:?} text"),
+            pick_list.text_color,
+            fill(pick_list.background),
+        ));
+        pairs.push(Pair::text(
+            format!("pick_list::default {status:?} placeholder"),
+            pick_list.placeholder_color,
+            fill(pick_list.background),
+        ));
+        pairs.push(Pair::non_text(
+            format!("pick_list::default {status:?} handle"),
+            pick_list.handle_color,
+            fill(pick_list.background),
+        ));
+    }
+
+    pairs
+}
+
+fn radio(theme: &Theme) -> Vec<Pair> {
+    let mut pairs = Vec::new();
+
+    for is_selected in [false, true] {
+        for status in [
+            widget::radio::Status::Active { is_selected },
+            widget::radio::Status::Hovered { is_selected },
+        ] {
+            let radio = style::radio::default(theme, status);
+
+            pairs.push(Pair::text(
+                format!("radio::default {status:?} label"),
+                radio.text_color.unwrap_or(page_text(theme)),
+                Color::TRANSPARENT,
+            ));
+
+            if is_selected {
+                pairs.push(Pair::non_text(
+                    format!("radio::default {status:?} dot"),
+                    radio.dot_color,
+                    fill(radio.background),
+                ));
+            }
+        }
+    }
+
+    pairs
+}
+
+fn scrollable(theme: &Theme) -> Vec<Pair> {
+    let style = style::scrollable::default(theme, widget::scrollable::Status::Active);
+    let rail = style.vertical_rail;
+
+    vec![Pair::non_text(
+        "scrollable::default scroller",
+        rail.scroller.color,
+        fill(rail.background),
+    )]
+}
+
+fn slider(theme: &Theme) -> Vec<Pair> {
+    let mut pairs = Vec::new();
+
+    for status in [
+        widget::slider::Status::Active,
+        widget::slider::Status::Hovered,
+        widget::slider::Status::Dragged,
+    ] {
+        let slider = style::slider::default(theme, status);
+        let (value, rest) = slider.rail.backgrounds;
+
+        pairs.push(Pair::non_text(
+            format!("slider::default {status:?} value rail"),
+            fill(value),
+            Color::TRANSPARENT,
+        ));
+        pairs.push(Pair::non_text(
+            format!("slider::default {status:?} rail"),
+            fill(rest),
+            Color::TRANSPARENT,
+        ));
+        pairs.push(Pair::non_text(
+            format!("slider::default {status:?} handle"),
+            fill(slider.handle.background),
+            slider.handle.border_color,
+        ));
+    }
+
+    pairs
+}
+
+fn text_input(theme: &Theme) -> Vec<Pair> {
+    let class = <Theme as widget::text_input::Catalog>::default();
+    let mut pairs = Vec::new();
+
+    for status in [
+        widget::text_input::Status::Active,
+        widget::text_input::Status::Hovered,
+        widget::text_input::Status::Focused,
+    ] {
+        let input = <Theme as widget::text_input::Catalog>::style(theme, &class, status);
+
+        pairs.push(Pair::text(
+            format!("text_input {status:?} value"),
+            input.value,
+            fill(input.background),
+        ));
+        pairs.push(Pair::text(
+            format!("text_input {status:?} placeholder"),
+            input.placeholder,
+            fill(input.background),
+        ));
+    }
+
+    pairs
+}
+
+fn toggler(theme: &Theme) -> Vec<Pair> {
+    let mut pairs = Vec::new();
+
+    for is_toggled in [false, true] {
+        for status in [
+            widget::toggler::Status::Active { is_toggled },
+            widget::toggler::Status::Hovered { is_toggled },
+        ] {
+            let toggler = style::toggler::default(theme, status);
+
+            pairs.push(Pair::non_text(
+                format!("toggler::default {status:?} foreground"),
+                toggler.foreground,
+                toggler.background,
+            ));
+        }
+    }
+
+    pairs
+}
+
+fn underline(theme: &Theme) -> Vec<Pair> {
+    let class = <Theme as widget::text_input::Catalog>::default();
+
+    [
+        widget::text_input::Status::Active,
+        widget::text_input::Status::Hovered,
+        widget::text_input::Status::Focused,
+    ]
+    .into_iter()
+    .map(|status| {
+        let input = <Theme as widget::text_input::Catalog>::style(theme, &class, status);
+
+        Pair::non_text(
+            format!("underline::default {status:?}"),
+            style::underline::default(theme, status).colour,
+            fill(input.background),
+        )
+    })
+    .collect()
+}
+
+#[test]
+fn style_colours_meet_wcag_contrast() {
+    let mut report = String::new();
+    let mut failures = 0;
+
+    for (name, theme, custom_accent) in themes() {
+        for pair in MODULES.iter().flat_map(|(_, pairs)| pairs(&theme)) {
+            let ratio = pair.ratio(&theme);
+            let excepted = EXCEPTIONS.iter().any(|(style, _)| pair.style == *style)
+                || custom_accent
+                    && ACCENT_EXCEPTIONS
+                        .iter()
+                        .any(|style| pair.style.starts_with(style));
+
+            if ratio < pair.minimum && !excepted {
+                failures += 1;
+
+                let _ = writeln!(
+                    report,
+                    "{name}: {} has a ratio of {ratio:.2}, needs {}",
+                    pair.style, pair.minimum,
+                );
+            }
+        }
+    }
+
+    assert!(failures == 0, "{failures} colours lack contrast:\n{report}");
+}
+
+#[test]
+fn every_style_module_is_audited() {
+    let modules = include_str!("../src/style.rs")
+        .lines()
+        .filter_map(|line| line.strip_prefix("pub mod ")?.strip_suffix(';'));
+
+    let missing: Vec<_> = modules
+        .filter(|module| {
+            !MODULES.iter().any(|(name, _)| name == module)
+                && !UNAUDITED.iter().any(|(name, _)| name == module)
+        })
+        .collect();
+
+    assert!(
+        missing.is_empty(),
+        "add the pairs of these style modules to `MODULES`: {missing:?}"
+    );
+}
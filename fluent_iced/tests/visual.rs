@@ -0,0 +1,691 @@
+//! Renders widgets in each of their states with the tiny-skia software renderer and
+//! compares them with the images in `tests/goldens`, so changes to the styles show
+//! up as failing tests.
+//!
+//! The text is rendered in the bundled Inter font and the icons in the bundled
+//! Fluent UI System Icons, so the images are close on every platform. Small
+//! differences in anti-aliasing are tolerated. When a change to the images is
+//! intended, write them again with:
+//!
+//! ```sh
+//! BLESS=1 cargo test -p fluent_iced --test visual
+//! ```
+//!
+//! The images rendered by a failing test are written next to the test binary, see
+//! the failure message.
+//!
+//! The states of a widget, and the variants which have to be told apart such as a
+//! checked and an indeterminate checkbox, must not render the same image. States
+//! which a widget can't be in are left out:
+//!
+//! - Pressed, for checkboxes, radio buttons, togglers and text inputs, which have
+//!   no pressed status in iced and draw as hovered.
+//! - Disabled, for radio buttons, sliders, pick lists and combo boxes, which iced
+//!   builds with their message, so they can't be disabled.
+//! - Focused, for every widget but text and number inputs. The others don't take
+//!   focus in iced, and combo boxes don't pass the focus on to their text input.
+//! - Everything but Active for info badges, which don't react to the cursor.
+
+use fluent_iced::{
+    fluent_icon::FluentIcon,
+    font::{self, Fonts, IconFont, TextFont},
+    style,
+    theme::Theme,
+    widget::{
+        button,
+        checkbox::{self, ThreeState},
+        combo_box, info_badge,
+        number_input::{self, NumberInput},
+        pick_list, radio, ribbon,
+        side_nav::{DisplayMode, Group, NavNode, SideNav},
+        slider, text, text_input, toggler, Container, DragHandle, Element, Row,
+    },
+};
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    sync::Once,
+};
+
+use iced::{
+    advanced::{
+        clipboard,
+        graphics::Viewport,
+        renderer,
+        widget::operation::{self, focusable, Operation},
+    },
+    application::DefaultStyle,
+    mouse, Event, Font, Length, Pixels, Point, Rectangle, Renderer, Size,
+};
+use iced_runtime::user_interface::{self, UserInterface};
+use tiny_skia::{Mask, Pixmap};
+
+/// Difference in a colour channel which is still counted as the same.
+const CHANNEL_TOLERANCE: u8 = 1;
+
+/// Fraction of the pixels which can differ before an image is counted as changed.
+const PIXEL_TOLERANCE: f32 = 0.005;
+
+const THEMES: [(&str, Theme); 2] = [("light", Theme::Light), ("dark", Theme::Dark)];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum State {
+    Active,
+    /// The cursor is over the target.
+    Hovered,
+    /// The left mouse button was pressed over the target and not released.
+    Pressed,
+    /// Built without its message, such as a button without `on_press`.
+    Disabled,
+    /// The first focusable widget is focused.
+    Focused,
+}
+
+/// An image rendered by [`check`].
+struct Image {
+    file_name: String,
+    state: State,
+    theme: &'static str,
+    pixmap: Pixmap,
+}
+
+impl State {
+    fn name(self) -> &'static str {
+        match self {
+            State::Active => "active",
+            State::Hovered => "hovered",
+            State::Pressed => "pressed",
+            State::Disabled => "disabled",
+            State::Focused => "focused",
+        }
+    }
+}
+
+/// Checks the widget returned by `view` in each of the `states` and themes against
+/// `<name>-<state>-<theme>.png`. `view` is passed whether the widget is enabled,
+/// and the widget is centred in an image of `size`, with the cursor at the centre.
+/// The states must render different images.
+fn check(
+    name: &str,
+    size: impl Into<Size>,
+    states: &[State],
+    view: impl Fn(bool) -> Element<'static, ()>,
+) -> Vec<Image> {
+    let size = size.into();
+
+    check_at(
+        name,
+        size,
+        Point::new(size.width / 2.0, size.height / 2.0),
+        states,
+        view,
+    )
+}
+
+/// Like [`check`] with the cursor at `target`.
+fn check_at(
+    name: &str,
+    size: impl Into<Size>,
+    target: Point,
+    states: &[State],
+    view: impl Fn(bool) -> Element<'static, ()>,
+) -> Vec<Image> {
+    static FONTS: Once = Once::new();
+
+    FONTS.call_once(|| {
+        font::load_now(Fonts {
+            text: TextFont::Inter,
+            icons: IconFont::Fallback(font::FLUENT_SYSTEM_ICONS),
+        })
+    });
+
+    let size = size.into();
+    let bless = env::var_os("BLESS").is_some_and(|bless| bless != "0");
+    let mut failures = Vec::new();
+    let mut images = Vec::new();
+
+    for &state in states {
+        for &(theme_name, ref theme) in &THEMES {
+            let file_name = format!("{name}-{}-{theme_name}.png", state.name());
+            let actual = render(view(state != State::Disabled), theme, size, target, state);
+            let golden = goldens().join(&file_name);
+
+            if bless {
+                fs::write(&golden, encode(&actual))
+                    .unwrap_or_else(|error| panic!("{}: {error}", golden.display()));
+            } else {
+                let difference = match Pixmap::load_png(&golden) {
+                    Ok(expected) => compare(&actual, &expected),
+                    Err(error) => Err(format!("{error}")),
+                };
+
+                if let Err(difference) = difference {
+                    let output = Path::new(env!("CARGO_TARGET_TMPDIR")).join("visual");
+                    let path = output.join(&file_name);
+
+                    fs::create_dir_all(&output).expect("create output directory");
+                    fs::write(&path, encode(&actual)).expect("write rendered image");
+
+                    failures.push(format!(
+                        "{file_name}: {difference}, rendered {}",
+                        path.display()
+                    ));
+                }
+            }
+
+            images.push(Image {
+                file_name,
+                state,
+                theme: theme_name,
+                pixmap: actual,
+            });
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} of the images changed, run with BLESS=1 if it's intended:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+
+    for (theme, _) in THEMES {
+        assert_distinct(images.iter().filter(|image| image.theme == theme));
+    }
+
+    images
+}
+
+/// Panics if two variants of a widget are the same in one of the `states`.
+fn assert_variants_distinct(images: &[Image], states: &[State]) {
+    for &state in states {
+        for (theme, _) in THEMES {
+            assert_distinct(
+                images
+                    .iter()
+                    .filter(|image| image.state == state && image.theme == theme),
+            );
+        }
+    }
+}
+
+/// Panics if two of the `images` are the same. They are rendered alike, so any
+/// difference between them is drawn by the widget.
+fn assert_distinct<'a>(images: impl IntoIterator<Item = &'a Image>) {
+    let images: Vec<_> = images.into_iter().collect();
+
+    for (i, a) in images.iter().enumerate() {
+        for b in &images[i + 1..] {
+            assert!(
+                a.pixmap.data() != b.pixmap.data(),
+                "{} and {} are the same",
+                a.file_name,
+                b.file_name
+            );
+        }
+    }
+}
+
+fn render(
+    element: Element<'static, ()>,
+    theme: &Theme,
+    size: Size,
+    target: Point,
+    state: State,
+) -> Pixmap {
+    let mut renderer =
+        Renderer::Secondary(iced_tiny_skia::Renderer::new(Font::DEFAULT, Pixels(16.0)));
+
+    let mut interface = UserInterface::build(
+        Container::new(element).center(Length::Fill),
+        size,
+        user_interface::Cache::default(),
+        &mut renderer,
+    );
+
+    let cursor = match state {
+        State::Hovered | State::Pressed => mouse::Cursor::Available(target),
+        State::Active | State::Disabled | State::Focused => mouse::Cursor::Unavailable,
+    };
+
+    let mut events = Vec::new();
+
+    if let mouse::Cursor::Available(position) = cursor {
+        events.push(Event::Mouse(mouse::Event::CursorMoved { position }));
+    }
+
+    if state == State::Pressed {
+        events.push(Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        )));
+    }
+
+    let _ = interface.update(
+        &events,
+        cursor,
+        &mut renderer,
+        &mut clipboard::Null,
+        &mut Vec::new(),
+    );
+
+    if state == State::Focused {
+        let mut operation: Box<dyn Operation> = Box::new(focusable::focus_next());
+
+        // Focusing the next widget first counts them, in an operation of its own
+        loop {
+            interface.operate(&renderer, operation.as_mut());
+
+            match operation.finish() {
+                operation::Outcome::Chain(next) => operation = next,
+                _ => break,
+            }
+        }
+    }
+
+    let appearance = theme.default_style();
+
+    let _ = interface.draw(
+        &mut renderer,
+        theme,
+        &renderer::Style {
+            text_color: appearance.text_color,
+        },
+        cursor,
+    );
+
+    let mut pixmap = Pixmap::new(size.width as u32, size.height as u32).expect("create pixmap");
+    let mut clip_mask = Mask::new(pixmap.width(), pixmap.height()).expect("create clip mask");
+    let viewport = Viewport::with_physical_size(Size::new(pixmap.width(), pixmap.height()), 1.0);
+
+    let Renderer::Secondary(renderer) = &mut renderer else {
+        unreachable!("the renderer was created as tiny-skia");
+    };
+
+    renderer.draw(
+        &mut pixmap.as_mut(),
+        &mut clip_mask,
+        &viewport,
+        &[Rectangle::with_size(size)],
+        appearance.background_color,
+        &[] as &[&str],
+    );
+
+    // The renderer swaps red and blue for the BGRA window surfaces
+    for pixel in pixmap.data_mut().chunks_exact_mut(4) {
+        pixel.swap(0, 2);
+    }
+
+    pixmap
+}
+
+fn compare(actual: &Pixmap, expected: &Pixmap) -> Result<(), String> {
+    if (actual.width(), actual.height()) != (expected.width(), expected.height()) {
+        return Err(format!(
+            "size changed from {}x{} to {}x{}",
+            expected.width(),
+            expected.height(),
+            actual.width(),
+            actual.height()
+        ));
+    }
+
+    let changed = actual
+        .data()
+        .chunks_exact(4)
+        .zip(expected.data().chunks_exact(4))
+        .filter(|(actual, expected)| {
+            actual
+                .iter()
+                .zip(expected.iter())
+                .any(|(actual, expected)| actual.abs_diff(*expected) > CHANNEL_TOLERANCE)
+        })
+        .count();
+
+    let total = (actual.width() * actual.height()) as usize;
+
+    if changed as f32 > total as f32 * PIXEL_TOLERANCE {
+        Err(format!("{changed} of {total} pixels changed"))
+    } else {
+        Ok(())
+    }
+}
+
+fn encode(pixmap: &Pixmap) -> Vec<u8> {
+    pixmap.encode_png().expect("encode png")
+}
+
+fn goldens() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/goldens")
+}
+
+#[test]
+fn button_standard() {
+    check(
+        "button_standard",
+        (120.0, 48.0),
+        &[
+            State::Active,
+            State::Hovered,
+            State::Pressed,
+            State::Disabled,
+        ],
+        |enabled| {
+            button::standard(text::body1("Button"))
+                .on_press_maybe(enabled.then_some(()))
+                .into()
+        },
+    );
+}
+
+#[test]
+fn button_primary() {
+    check(
+        "button_primary",
+        (120.0, 48.0),
+        &[
+            State::Active,
+            State::Hovered,
+            State::Pressed,
+            State::Disabled,
+        ],
+        |enabled| {
+            button::standard(text::body1("Button"))
+                .on_press_maybe(enabled.then_some(()))
+                .style(style::button::primary)
+                .into()
+        },
+    );
+}
+
+#[test]
+fn button_dropdown() {
+    check(
+        "button_dropdown",
+        (120.0, 48.0),
+        &[
+            State::Active,
+            State::Hovered,
+            State::Pressed,
+            State::Disabled,
+        ],
+        |enabled| {
+            button::dropdown(text::body1("Email"))
+                .on_press_maybe(enabled.then_some(()))
+                .into()
+        },
+    );
+}
+
+#[test]
+fn button_menu() {
+    check(
+        "button_menu",
+        (160.0, 40.0),
+        &[
+            State::Active,
+            State::Hovered,
+            State::Pressed,
+            State::Disabled,
+        ],
+        |enabled| {
+            Container::new(
+                button::menu_icon("Reply All", FluentIcon::Mail)
+                    .on_press_maybe(enabled.then_some(())),
+            )
+            .width(144)
+            .into()
+        },
+    );
+}
+
+#[test]
+fn button_app_bar() {
+    check(
+        "button_app_bar",
+        (100.0, 80.0),
+        &[
+            State::Active,
+            State::Hovered,
+            State::Pressed,
+            State::Disabled,
+        ],
+        |enabled| {
+            button::app_bar("Save", FluentIcon::Save)
+                .on_press_maybe(enabled.then_some(()))
+                .into()
+        },
+    );
+}
+
+#[test]
+fn checkbox_three_state() {
+    let states = [State::Active, State::Hovered, State::Disabled];
+    let mut images = Vec::new();
+
+    for (name, check_state) in [
+        ("checkbox_checked", ThreeState::Checked),
+        ("checkbox_unchecked", ThreeState::Unchecked),
+        ("checkbox_indeterminate", ThreeState::Indeterminate),
+    ] {
+        images.extend(check(name, (160.0, 40.0), &states, |enabled| {
+            checkbox::three_state("Checkbox", check_state)
+                .on_toggle_maybe(enabled.then_some(|_| ()))
+                .into()
+        }));
+    }
+
+    assert_variants_distinct(&images, &states);
+}
+
+#[test]
+fn radio() {
+    let states = [State::Active, State::Hovered];
+    let mut images = Vec::new();
+
+    for (name, selected) in [("radio_selected", Some(1)), ("radio_unselected", None)] {
+        images.extend(check(name, (160.0, 40.0), &states, |_| {
+            radio::standard("Option", 1, selected, |_| ()).into()
+        }));
+    }
+
+    assert_variants_distinct(&images, &states);
+}
+
+#[test]
+fn toggler() {
+    let states = [State::Active, State::Hovered, State::Disabled];
+    let mut images = Vec::new();
+
+    for (name, is_toggled) in [("toggler_on", true), ("toggler_off", false)] {
+        images.extend(check(name, (80.0, 40.0), &states, |enabled| {
+            toggler::standard(is_toggled)
+                .on_toggle_maybe(enabled.then_some(|_| ()))
+                .into()
+        }));
+    }
+
+    assert_variants_distinct(&images, &states);
+}
+
+#[test]
+fn slider() {
+    check(
+        "slider",
+        (200.0, 40.0),
+        &[State::Active, State::Hovered, State::Pressed],
+        |_| {
+            slider::standard(0.0..=100.0, 40.0, |_| ())
+                .width(160)
+                .into()
+        },
+    );
+}
+
+#[test]
+fn text_input() {
+    check(
+        "text_input",
+        (240.0, 56.0),
+        &[
+            State::Active,
+            State::Hovered,
+            State::Focused,
+            State::Disabled,
+        ],
+        |enabled| {
+            text_input::underline(
+                text_input::standard("Placeholder", "")
+                    .width(200)
+                    .on_input_maybe(enabled.then_some(|_| ())),
+            )
+        },
+    );
+}
+
+#[test]
+fn number_input() {
+    check(
+        "number_input",
+        (200.0, 56.0),
+        &[
+            State::Active,
+            State::Hovered,
+            State::Focused,
+            State::Disabled,
+        ],
+        // The buttons are disabled when the value can't change
+        |enabled| {
+            let bounds = if enabled { 0..=10 } else { 5..=5 };
+
+            number_input::underline(NumberInput::new(5, bounds, |_| ()))
+        },
+    );
+}
+
+#[test]
+fn pick_list() {
+    const FRUITS: &[&str] = &["Apple", "Banana", "Cherry"];
+
+    check(
+        "pick_list",
+        (200.0, 200.0),
+        &[State::Active, State::Hovered, State::Pressed],
+        |_| pick_list::standard(FRUITS, Some("Apple"), |_| ()).into(),
+    );
+}
+
+#[test]
+fn combo_box() {
+    // The combo box doesn't pass operations on to its text input, pressing it focuses
+    // the input and opens the menu instead
+    check(
+        "combo_box",
+        (240.0, 200.0),
+        &[State::Active, State::Hovered, State::Pressed],
+        |_| {
+            // The element has to borrow the state for 'static
+            let state = Box::leak(Box::new(iced::widget::combo_box::State::new(vec![
+                "Apple", "Banana", "Cherry",
+            ])));
+
+            combo_box::standard(state, "Pick a fruit", None, |_| ())
+                .width(200)
+                .into()
+        },
+    );
+}
+
+#[test]
+fn side_nav() {
+    check_at(
+        "side_nav",
+        (320.0, 240.0),
+        Point::new(100.0, 92.0),
+        &[State::Active, State::Hovered, State::Pressed],
+        |_| {
+            SideNav::new(DisplayMode::Full, ())
+                .full_width(320)
+                .with_groups([
+                    Group::new(FluentIcon::Home.codepoint(), "Home", ()).selected(true),
+                    Group::new(FluentIcon::Document.codepoint(), "Documents", ())
+                        .badge(info_badge::number(3, info_badge::Severity::Attention)),
+                    Group::new(FluentIcon::Folder.codepoint(), "Folders", ())
+                        .expanded(true)
+                        .with_items([NavNode::new(None, "Inbox", ())]),
+                ])
+                .into()
+        },
+    );
+}
+
+#[test]
+fn ribbon_button() {
+    check(
+        "ribbon_button_large",
+        (100.0, 100.0),
+        &[
+            State::Active,
+            State::Hovered,
+            State::Pressed,
+            State::Disabled,
+        ],
+        |enabled| {
+            ribbon::button::large("Paste", FluentIcon::Paste)
+                .on_press_maybe(enabled.then_some(()))
+                .into()
+        },
+    );
+
+    check(
+        "ribbon_button_medium",
+        (100.0, 48.0),
+        &[
+            State::Active,
+            State::Hovered,
+            State::Pressed,
+            State::Disabled,
+        ],
+        |enabled| {
+            ribbon::button::medium("Copy", FluentIcon::Copy)
+                .on_press_maybe(enabled.then_some(()))
+                .into()
+        },
+    );
+}
+
+#[test]
+fn drag_handle() {
+    check(
+        "drag_handle",
+        (40.0, 120.0),
+        &[State::Active, State::Hovered, State::Pressed],
+        |_| DragHandle::new().on_drag(|_| ()).into(),
+    );
+}
+
+#[test]
+fn info_badge() {
+    use info_badge::Severity;
+
+    let images = Severity::ALL.map(|severity| {
+        check(
+            &format!("info_badge_{severity}").to_lowercase(),
+            (120.0, 40.0),
+            &[State::Active],
+            |_| {
+                Row::new()
+                    .push(info_badge::dot(severity))
+                    .push(info_badge::icon(FluentIcon::Mail, severity))
+                    .push(info_badge::number(42, severity))
+                    .spacing(8)
+                    .align_y(iced::Alignment::Center)
+                    .into()
+            },
+        )
+    });
+
+    let images: Vec<_> = images.into_iter().flatten().collect();
+
+    assert_variants_distinct(&images, &[State::Active]);
+}
@@ -0,0 +1,262 @@
+//! Command line arguments, which take precedence over the saved [`Settings`].
+
+use crate::gallery::Page;
+use crate::settings::{self, Settings};
+
+use fluent_iced::theme::{file::Format, Theme};
+
+use std::{fmt, path::PathBuf};
+
+use iced::Size;
+
+pub const USAGE: &str = "\
+usage: fluent_iced_gallery [options]
+
+options:
+    --page <id>          open the page with the id, see --list-pages
+    --theme <theme>      light, dark, aquatic, desert, dusk, night_sky, or the
+                         path of a .toml or .json theme file
+    --explain            outline the layout of widgets
+    --window <WxH>       size of the window, such as 1280x800
+    --list-pages         print the id and title of every page and exit
+    --screenshot <dir>   write a <page id>-<theme>.png image of the page, or of
+                         every page, in the theme, or in light and dark, into
+                         the directory and exit
+    --help               print this help and exit";
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Args {
+    pub page: Option<Page>,
+    pub theme: Option<ThemeArg>,
+    pub explain: bool,
+    pub window_size: Option<Size>,
+    pub list_pages: bool,
+    pub screenshot: Option<PathBuf>,
+    pub help: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ThemeArg {
+    BuiltIn(Theme),
+    File(PathBuf),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    UnknownArgument(String),
+    MissingValue(&'static str),
+    UnknownPage(String),
+    UnknownTheme(String),
+    InvalidWindowSize(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UnknownArgument(arg) => write!(f, "unknown argument \"{arg}\""),
+            Error::MissingValue(option) => write!(f, "{option} expects a value"),
+            Error::UnknownPage(id) => {
+                write!(f, "unknown page \"{id}\", see --list-pages for the ids")
+            }
+            Error::UnknownTheme(theme) => write!(f, "unknown theme \"{theme}\""),
+            Error::InvalidWindowSize(size) => {
+                write!(
+                    f,
+                    "invalid window size \"{size}\", expected WxH such as 1280x800"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl Args {
+    /// Parses the arguments, without the program name. Values follow their option
+    /// either as the next argument or after `=`.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, Error> {
+        let mut parsed = Args::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let (option, inline_value) = match arg.split_once('=') {
+                Some((option, value)) => (option.to_owned(), Some(value.to_owned())),
+                None => (arg.clone(), None),
+            };
+
+            let mut value = |option: &'static str| {
+                inline_value
+                    .clone()
+                    .or_else(|| args.next())
+                    .ok_or(Error::MissingValue(option))
+            };
+
+            match option.as_str() {
+                "--page" => {
+                    let id = value("--page")?;
+                    parsed.page = Some(Page::from_slug(&id).ok_or(Error::UnknownPage(id))?);
+                }
+                "--theme" => parsed.theme = Some(parse_theme(value("--theme")?)?),
+                "--window" => parsed.window_size = Some(parse_size(value("--window")?)?),
+                "--screenshot" => parsed.screenshot = Some(value("--screenshot")?.into()),
+                "--explain" if inline_value.is_none() => parsed.explain = true,
+                "--list-pages" if inline_value.is_none() => parsed.list_pages = true,
+                "--help" | "-h" if inline_value.is_none() => parsed.help = true,
+                _ => return Err(Error::UnknownArgument(arg)),
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    /// The settings with the arguments applied.
+    pub fn apply(&self, mut settings: Settings) -> Settings {
+        if let Some(page) = self.page {
+            settings.page = page;
+        }
+
+        match &self.theme {
+            Some(ThemeArg::BuiltIn(theme)) => {
+                settings.theme = theme.clone();
+                settings.theme_file = None;
+            }
+            Some(ThemeArg::File(path)) => settings.theme_file = Some(path.clone()),
+            None => (),
+        }
+
+        if self.explain {
+            settings.explain = true;
+        }
+
+        if let Some(size) = self.window_size {
+            settings.window_size = size;
+        }
+
+        settings
+    }
+}
+
+/// The id and title of every page, one per line.
+pub fn page_list() -> String {
+    Page::all()
+        .iter()
+        .map(|page| format!("{:<24}{}", page.slug(), page.title()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn parse_theme(value: String) -> Result<ThemeArg, Error> {
+    if let Some(theme) = settings::themes().find(|theme| settings::theme_key(theme) == value) {
+        return Ok(ThemeArg::BuiltIn(theme));
+    }
+
+    let path = PathBuf::from(&value);
+
+    match Format::from_path(&path) {
+        Some(_) => Ok(ThemeArg::File(path)),
+        None => Err(Error::UnknownTheme(value)),
+    }
+}
+
+fn parse_size(value: String) -> Result<Size, Error> {
+    let size = value.split_once('x').and_then(|(width, height)| {
+        let width: u32 = width.parse().ok()?;
+        let height: u32 = height.parse().ok()?;
+
+        (width > 0 && height > 0).then(|| Size::new(width as f32, height as f32))
+    });
+
+    size.ok_or(Error::InvalidWindowSize(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::page::{drop_down_button::DropDownButton, GalleryPage};
+
+    fn parse(args: &[&str]) -> Result<Args, Error> {
+        Args::parse(args.iter().map(|arg| (*arg).to_owned()))
+    }
+
+    #[test]
+    fn values_follow_options_or_an_equals_sign() {
+        let expected = Args {
+            page: Some(Page::Sample(DropDownButton::TITLE)),
+            theme: Some(ThemeArg::BuiltIn(Theme::Light)),
+            window_size: Some(Size::new(1280.0, 800.0)),
+            explain: true,
+            ..Args::default()
+        };
+
+        assert_eq!(
+            parse(&[
+                "--page",
+                "drop-down-button",
+                "--theme",
+                "light",
+                "--window",
+                "1280x800",
+                "--explain"
+            ]),
+            Ok(expected.clone())
+        );
+        assert_eq!(
+            parse(&[
+                "--page=drop-down-button",
+                "--theme=light",
+                "--window=1280x800",
+                "--explain"
+            ]),
+            Ok(expected)
+        );
+    }
+
+    #[test]
+    fn theme_files_are_recognised_by_extension() {
+        assert_eq!(
+            parse(&["--theme", "orchid.toml"]).unwrap().theme,
+            Some(ThemeArg::File("orchid.toml".into()))
+        );
+        assert_eq!(
+            parse(&["--theme", "orchid.txt"]),
+            Err(Error::UnknownTheme("orchid.txt".into()))
+        );
+    }
+
+    #[test]
+    fn missing_value_is_an_error() {
+        assert_eq!(parse(&["--page"]), Err(Error::MissingValue("--page")));
+        assert_eq!(
+            parse(&["--explain", "--window"]),
+            Err(Error::MissingValue("--window"))
+        );
+    }
+
+    #[test]
+    fn window_size_must_be_w_x_h() {
+        for size in ["1280", "1280x", "x800", "0x800", "1280x-800", "1280X800"] {
+            assert_eq!(
+                parse(&["--window", size]),
+                Err(Error::InvalidWindowSize(size.into())),
+                "{size}"
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_page_and_arguments_are_errors() {
+        assert_eq!(
+            parse(&["--page", "Drop Down Button"]),
+            Err(Error::UnknownPage("Drop Down Button".into()))
+        );
+        assert_eq!(
+            parse(&["--explain=yes"]),
+            Err(Error::UnknownArgument("--explain=yes".into()))
+        );
+        assert_eq!(
+            parse(&["--verbose"]),
+            Err(Error::UnknownArgument("--verbose".into()))
+        );
+    }
+}
@@ -1,228 +1,489 @@
-use crate::{
+use crate::page::{self, page, AnyPage, Entry, Group, PageMessage};
+use crate::settings::Settings;
+
+use fluent_iced::{
     fluent_icon::FluentIcon,
-    page::{self, page},
-    style,
-    theme::Theme,
-    widget::{pick_list, side_nav, text, toggler, Container, Element},
+    font, style,
+    theme::{self, Contrast, Palette, Theme},
+    widget::{
+        button, pick_list, scrollable, side_nav, text, text_input, toggler, Button, Column,
+        Container, DragHandle, Element,
+    },
 };
 
-use std::mem::discriminant;
+use std::{
+    collections::HashMap,
+    ops::RangeInclusive,
+    path::PathBuf,
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
 
 use iced::{
     alignment::Vertical,
-    border,
+    border, color, event,
+    keyboard::{self, key::Named, Key},
+    mouse,
     widget::{
-        center, column, container, horizontal_space, iced, image, mouse_area, opaque, row, stack,
+        center, column, container, horizontal_space, iced, image, mouse_area, opaque, row,
+        scrollable::{AbsoluteOffset, Viewport},
+        stack, vertical_space,
     },
-    window, ContentFit, Length, Size, Subscription,
+    window, Color, ContentFit, Event, Length, Padding, Point, Size, Subscription, Task,
 };
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum Page {
     #[default]
     Home,
+    /// A page of [`page::REGISTRY`], by title.
+    Sample(&'static str),
+    Settings,
+}
 
-    // Basic input
-    Button(page::button::Button),
-    DropDownButton(page::drop_down_button::DropDownButton),
-    Checkbox(page::checkbox::Checkbox),
-    ToggleButton(page::toggle_button::ToggleButton),
-    SplitButton(page::split_button::SplitButton),
-    ComboBox(page::combo_box::ComboBox),
-    Radio(page::radio::Radio),
-    Slider(page::slider::Slider),
-    Toggler(page::toggler::Toggler),
+impl Page {
+    /// Every page, in the order of the side navigation.
+    pub fn all() -> Vec<Page> {
+        fn push_pages(items: &[PageItem], pages: &mut Vec<Page>) {
+            for item in items {
+                pages.extend(item.page);
+                push_pages(&item.children, pages);
+            }
+        }
 
-    // Dialogs and flyouts
-    Dialog(page::dialog::Dialog),
+        let mut all = Vec::new();
 
-    // Layout
-    RowColumn(page::row_column::RowColumn),
+        for page_group in pages().iter().chain(&footer_pages()) {
+            all.extend(page_group.page);
+            push_pages(&page_group.page_items, &mut all);
+        }
 
-    // Media
-    Image(page::image::Image),
-    Svg(page::svg::Svg),
+        all
+    }
 
-    // Menu & toolbars
-    AppBarButton(page::app_bar_button::AppBarButton),
-    MenuBar(page::menu_bar::MenuBar),
-    Ribbon(page::ribbon::Ribbon),
+    pub fn from_slug(slug: &str) -> Option<Page> {
+        Page::all().into_iter().find(|page| page.slug() == slug)
+    }
 
-    // Text
-    TextInput(page::text_input::TextInput),
+    pub fn title(&self) -> &'static str {
+        match self {
+            Page::Home => "Home",
+            Page::Sample(title) => title,
+            Page::Settings => "Settings",
+        }
+    }
 
-    Settings,
+    /// Stable id of the page, its title in lowercase with words joined by dashes,
+    /// such as `drop-down-button`.
+    pub fn slug(&self) -> String {
+        self.title()
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(str::to_lowercase)
+            .collect::<Vec<_>>()
+            .join("-")
+    }
 }
 
 pub struct Gallery {
     current_page: Page,
+    // Pages left by navigating, most recent last, and pages left by going back
+    back_stack: Vec<Visit>,
+    forward_stack: Vec<Visit>,
+    scroll_offset: AbsoluteOffset,
+    // State of the pages visited this session, created from the registry on the
+    // first visit
+    page_states: HashMap<&'static str, Box<dyn AnyPage>>,
     side_nav_display_mode: side_nav::DisplayMode,
+    side_nav_breakpoints: side_nav::Breakpoints,
+    side_nav_pane_open: bool,
+    side_nav_more_open: bool,
+    side_nav_width: f32,
+    // Width of the pane when the drag handle was pressed, while resizing
+    side_nav_resize_start: Option<f32>,
     pages: Vec<PageGroup>,
     footer_pages: Vec<PageGroup>,
     page_group_overlay_open: Option<&'static str>,
+    search_query: String,
+    search_open: bool,
     window_size: Size,
+    window_position: Option<Point>,
+    base_theme: Theme,
     theme: Theme,
+    accent: Option<Color>,
+    theme_file_path: String,
+    theme_file: Option<ThemeFile>,
+    theme_file_messages: Vec<String>,
     explain: bool,
+    fonts: font::Fonts,
+    // The settings file as it was loaded, and the settings the gallery was launched
+    // with, which the command line arguments may override
+    saved_settings: Settings,
+    launch_settings: Settings,
 }
 
 #[derive(Debug, Clone)]
 pub enum Message {
     PageSelected(Page),
+    NavigatedBack,
+    NavigatedForward,
+    PageScrolled(Viewport),
+    PageReset,
     PageGroupToggled(&'static str),
     PageGroupOverlayDismissed,
+    // Path of labels to a nested group, starting with the label of its page group
+    PageItemToggled(Vec<&'static str>),
     SideNavDisplayModeToggled,
+    SideNavDisplayModeSelected(side_nav::DisplayMode),
+    SideNavMorePressed,
+    SideNavOverlayDismissed,
+    SideNavResized(f32),
+    SideNavResizeEnded,
+    SideNavCollapsed,
+    SearchChanged(String),
+    SearchSubmitted,
+    SearchOpened,
+    SearchDismissed,
     WindowResized((window::Id, Size)),
-    ThemeToggled,
+    WindowMoved(Point),
+    // The settings are saved when the window is closed
+    WindowCloseRequested(window::Id),
+    ThemeSelected(Theme),
+    ThemeFilePathChanged(String),
+    ThemeFileLoaded,
+    ThemeFilePolled,
+    AccentSelected(Option<Color>),
     ExplainToggled,
+    FontLoaded(Result<(), iced::font::Error>),
+    Page(PageMessage),
+}
+
+const SEARCH_SUGGESTIONS: usize = 8;
+
+// Widths the pane can be resized to with the drag handle
+const SIDE_NAV_WIDTHS: RangeInclusive<f32> = 200.0..=480.0;
 
-    // Basic input
-    ButtonPage(page::button::Message),
-    DropDownButtonPage(page::drop_down_button::Message),
-    ToggleButtonPage(page::toggle_button::Message),
-    SplitButtonPage(page::split_button::Message),
-    CheckboxPage(page::checkbox::Message),
-    ComboBoxPage(page::combo_box::Message),
-    SliderPage(page::slider::Message),
-    TogglerPage(page::toggler::Message),
-
-    // Dialogs and flyouts
-    DialogPage(page::dialog::Message),
-
-    // Layout
-    RowColumnPage(page::row_column::Message),
-
-    // Media
-    ImagePage(page::image::Message),
-    SvgPage(page::svg::Message),
-
-    // Menu & toolbars
-    AppBarButton(page::app_bar_button::Message),
-    MenuBar(page::menu_bar::Message),
-    Ribbon(page::ribbon::Message),
-
-    // Text
-    TextInputPage(page::text_input::Message),
-    RadioPage(page::radio::Message),
+const THEME_FILE_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+// A page as it was left, to restore it when navigating back or forward
+#[derive(Clone, Copy, Debug)]
+struct Visit {
+    page: Page,
+    scroll_offset: AbsoluteOffset,
 }
 
-const SIDE_NAV_COMPACT_WIDTH: f32 = 1000.0;
+// A theme file which is reloaded whenever it is modified
+struct ThemeFile {
+    path: PathBuf,
+    modified: Option<SystemTime>,
+    /// Whether the file sets the accent, which then wins over the accent setting.
+    sets_accent: bool,
+}
+
+// A selection of the Windows accent colours
+const ACCENT_COLOURS: [Color; 8] = [
+    color!(0xFFB900),
+    color!(0xE74856),
+    color!(0xE3008C),
+    color!(0x881798),
+    color!(0x0063B1),
+    color!(0x00B7C3),
+    color!(0x107C10),
+    color!(0x7A7574),
+];
 
 impl Gallery {
-    pub fn update(&mut self, message: Message) {
-        match (message, &mut self.current_page) {
-            (Message::PageSelected(page), _) => {
-                self.select_page(page);
+    /// The gallery opened with `settings`, which are `saved` with the overrides of
+    /// the command line arguments. Only the changes made to them in the session are
+    /// saved when the window is closed.
+    pub fn new(settings: Settings, saved: Settings) -> (Self, Task<Message>) {
+        let fonts = font::detect(Some(font::FLUENT_SYSTEM_ICONS));
+
+        let mut gallery = Self {
+            saved_settings: saved,
+            launch_settings: settings.clone(),
+            fonts,
+            explain: settings.explain,
+            side_nav_display_mode: settings.side_nav_display_mode,
+            side_nav_width: settings
+                .side_nav_width
+                .clamp(*SIDE_NAV_WIDTHS.start(), *SIDE_NAV_WIDTHS.end()),
+            window_size: settings.window_size,
+            window_position: settings.window_position,
+            ..Self::default()
+        };
+
+        gallery.set_theme(settings.theme, settings.accent);
+
+        if let Some(path) = settings.theme_file {
+            gallery.theme_file_path = path.to_string_lossy().into_owned();
+            gallery.theme_file = Some(ThemeFile {
+                path,
+                modified: None,
+                sets_accent: false,
+            });
+            gallery.reload_theme_file();
+        }
+
+        for path in &settings.expanded_groups {
+            let path: Vec<&str> = path.iter().map(String::as_str).collect();
+
+            if let Some(expanded) = gallery.expanded_mut(&path) {
+                *expanded = true;
+            }
+        }
+
+        gallery.select_page(settings.page);
+
+        (gallery, font::load(fonts).map(Message::FontLoaded))
+    }
+
+    pub fn settings(&self) -> Settings {
+        let mut expanded_groups = Vec::new();
+
+        for page_group in &self.pages {
+            if page_group.expanded {
+                expanded_groups.push(vec![page_group.label.to_owned()]);
+            }
+
+            PageItem::expanded_paths(
+                &page_group.page_items,
+                &mut vec![page_group.label],
+                &mut expanded_groups,
+            );
+        }
+
+        Settings {
+            theme: self.base_theme.clone(),
+            theme_file: self.theme_file.as_ref().map(|file| file.path.clone()),
+            accent: self.accent,
+            explain: self.explain,
+            page: self.current_page,
+            side_nav_display_mode: self.side_nav_display_mode,
+            side_nav_width: self.side_nav_width,
+            expanded_groups,
+            window_size: self.window_size,
+            window_position: self.window_position,
+        }
+    }
+
+    pub fn update(&mut self, message: Message) -> Task<Message> {
+        match (message, self.current_page) {
+            (Message::PageSelected(page), _) => return self.navigate(page),
+            (Message::NavigatedBack, _) => {
+                if let Some(visit) = self.back_stack.pop() {
+                    self.forward_stack.push(self.visit());
+                    return self.restore(visit);
+                }
+            }
+            (Message::NavigatedForward, _) => {
+                if let Some(visit) = self.forward_stack.pop() {
+                    self.back_stack.push(self.visit());
+                    return self.restore(visit);
+                }
             }
+            (Message::PageScrolled(viewport), _) => {
+                self.scroll_offset = viewport.absolute_offset();
+            }
+            (Message::PageReset, Page::Sample(title)) => {
+                if let Some(entry) = Entry::find(title) {
+                    self.page_states.insert(title, entry.create());
+                    return self.scroll_to(AbsoluteOffset::default());
+                }
+            }
+            (Message::PageReset, _) => (),
             (Message::PageGroupToggled(label), _) => {
+                let expands_groups = self.side_nav_expands_groups();
+
                 if let Some(page_group) = self
                     .pages
                     .iter_mut()
                     .find(|page_group| page_group.label == label)
                 {
-                    if self.side_nav_display_mode == side_nav::DisplayMode::Compact
-                        || self.window_size.width < SIDE_NAV_COMPACT_WIDTH
-                    {
-                        self.page_group_overlay_open = Some(page_group.label);
-                    } else if self.side_nav_display_mode == side_nav::DisplayMode::Full {
+                    if expands_groups {
                         page_group.expanded = !page_group.expanded;
+                    } else {
+                        self.page_group_overlay_open = Some(page_group.label);
                     }
                 }
             }
-            (Message::PageGroupOverlayDismissed, _) => self.page_group_overlay_open = None,
-            (Message::SideNavDisplayModeToggled, _) => {
-                self.side_nav_display_mode = match self.side_nav_display_mode {
-                    side_nav::DisplayMode::Compact => side_nav::DisplayMode::Full,
-                    side_nav::DisplayMode::Full => side_nav::DisplayMode::Compact,
+            (Message::PageGroupOverlayDismissed, _) => {
+                self.page_group_overlay_open = None;
+                self.collapse_flyouts();
+            }
+            (Message::PageItemToggled(path), _) => {
+                if let Some(expanded) = self.expanded_mut(&path) {
+                    *expanded = !*expanded;
                 }
             }
-            (Message::WindowResized((_, size)), _) => {
-                self.window_size = size;
-
-                if size.width < SIDE_NAV_COMPACT_WIDTH {
-                    self.page_group_overlay_open = None;
+            // Collapses or expands the pane, or opens it over the page if the window
+            // is too narrow to expand it
+            (Message::SideNavDisplayModeToggled, _) => {
+                let full_fits = self
+                    .side_nav_breakpoints
+                    .display_mode(side_nav::DisplayMode::Full, self.window_size.width)
+                    == side_nav::DisplayMode::Full;
+
+                match self.side_nav_display_mode() {
+                    side_nav::DisplayMode::Full => {
+                        self.side_nav_display_mode = side_nav::DisplayMode::Compact;
+                    }
+                    side_nav::DisplayMode::Compact if full_fits => {
+                        self.side_nav_display_mode = side_nav::DisplayMode::Full;
+                    }
+                    side_nav::DisplayMode::Compact | side_nav::DisplayMode::Minimal => {
+                        self.side_nav_pane_open = !self.side_nav_pane_open;
+                    }
+                    side_nav::DisplayMode::Top => (),
                 }
+
+                self.page_group_overlay_open = None;
+            }
+            (Message::SideNavDisplayModeSelected(display_mode), _) => {
+                self.side_nav_display_mode = display_mode;
+                self.close_side_nav_overlays();
             }
+            (Message::SideNavMorePressed, _) => self.side_nav_more_open = !self.side_nav_more_open,
+            (Message::SideNavOverlayDismissed, _) => {
+                self.side_nav_pane_open = false;
+                self.side_nav_more_open = false;
+            }
+            (Message::SideNavResized(offset), _) => {
+                let start = *self
+                    .side_nav_resize_start
+                    .get_or_insert(self.side_nav_width);
 
-            (Message::ThemeToggled, _) => {
-                self.theme = match self.theme {
-                    Theme::Light => Theme::Dark,
-                    Theme::Dark => Theme::Light,
+                self.side_nav_width =
+                    (start + offset).clamp(*SIDE_NAV_WIDTHS.start(), *SIDE_NAV_WIDTHS.end());
+            }
+            (Message::SideNavResizeEnded, _) => self.side_nav_resize_start = None,
+            // The width is kept for when the pane is expanded again
+            (Message::SideNavCollapsed, _) => {
+                self.side_nav_display_mode = side_nav::DisplayMode::Compact;
+                self.side_nav_resize_start = None;
+                self.close_side_nav_overlays();
+            }
+            (Message::SearchChanged(query), _) => {
+                self.search_query = query;
+                self.search_open = true;
+            }
+            (Message::SearchSubmitted, _) => {
+                if let Some(entry) = Entry::search(&self.search_query).first() {
+                    return self.navigate(Page::Sample(entry.title));
                 }
             }
-            (Message::ExplainToggled, _) => self.explain = !self.explain,
+            (Message::SearchOpened, _) => {
+                self.search_open = true;
 
-            // Page messages
-            // Basic input
-            (Message::ButtonPage(message), Page::Button(page)) => page.update(message),
-            (Message::DropDownButtonPage(message), Page::DropDownButton(page)) => {
-                page.update(message)
+                return iced::widget::text_input::focus(search_input_id());
             }
-            (Message::ToggleButtonPage(message), Page::ToggleButton(page)) => page.update(message),
-            (Message::SplitButtonPage(message), Page::SplitButton(page)) => page.update(message),
-            (Message::CheckboxPage(message), Page::Checkbox(page)) => page.update(message),
-            (Message::ComboBoxPage(message), Page::ComboBox(page)) => page.update(message),
-            (Message::RadioPage(message), Page::Radio(page)) => page.update(message),
-            (Message::SliderPage(message), Page::Slider(page)) => page.update(message),
-            (Message::TogglerPage(message), Page::Toggler(page)) => page.update(message),
+            (Message::SearchDismissed, _) => self.search_open = false,
+            (Message::WindowResized((_, size)), _) => {
+                let display_mode = self.side_nav_display_mode();
 
-            // Dialogs and flyouts
-            (Message::DialogPage(message), Page::Dialog(page)) => page.update(message),
+                self.window_size = size;
 
-            // Layout
-            (Message::RowColumnPage(message), Page::RowColumn(page)) => page.update(message),
+                if self.side_nav_display_mode() != display_mode {
+                    self.close_side_nav_overlays();
+                }
+            }
+            (Message::WindowMoved(position), _) => self.window_position = Some(position),
+            (Message::WindowCloseRequested(id), _) => {
+                let settings = self
+                    .settings()
+                    .merge_changes(&self.launch_settings, self.saved_settings.clone());
+
+                if let Err(error) = settings.save() {
+                    eprintln!("Failed to save settings: {error}");
+                }
 
-            // Media
-            (Message::ImagePage(message), Page::Image(page)) => page.update(message),
-            (Message::SvgPage(message), Page::Svg(page)) => page.update(message),
+                return window::close(id);
+            }
 
-            // Menu & toolbars
-            (Message::AppBarButton(message), Page::AppBarButton(page)) => page.update(message),
-            (Message::MenuBar(message), Page::MenuBar(page)) => page.update(message),
-            (Message::Ribbon(message), Page::Ribbon(page)) => page.update(message),
+            (Message::ThemeSelected(theme), _) => {
+                if !matches!(theme, Theme::Custom(_)) {
+                    self.theme_file = None;
+                    self.theme_file_messages.clear();
+                }
 
-            // Text
-            (Message::TextInputPage(message), Page::TextInput(page)) => page.update(message),
+                self.set_theme(theme, self.accent);
+            }
+            (Message::ThemeFilePathChanged(path), _) => self.theme_file_path = path,
+            (Message::ThemeFileLoaded, _) => {
+                self.theme_file = Some(ThemeFile {
+                    path: PathBuf::from(self.theme_file_path.trim()),
+                    modified: None,
+                    sets_accent: false,
+                });
+                self.reload_theme_file();
+            }
+            (Message::ThemeFilePolled, _) => self.reload_theme_file(),
+            (Message::AccentSelected(accent), _) => self.set_theme(self.base_theme.clone(), accent),
+            (Message::ExplainToggled, _) => self.explain = !self.explain,
+            (Message::FontLoaded(result), _) => {
+                if let Err(error) = result {
+                    eprintln!("Failed to load bundled font: {error:?}");
+                }
+            }
 
-            _ => panic!("Message, Page pair not valid."),
+            (Message::Page(message), Page::Sample(title)) => {
+                if let Some(page) = self.page_states.get_mut(title) {
+                    page.update(message);
+                }
+            }
+            // Sent by a page which is no longer shown
+            (Message::Page(_), _) => (),
         }
+
+        Task::none()
     }
 
     pub fn subscription(&self) -> Subscription<Message> {
         let window_resize_sub = iced::window::resize_events().map(Message::WindowResized);
 
-        let menu_bar_sub = match &self.current_page {
-            Page::MenuBar(page) => page.subscription().map(Message::MenuBar),
-            _ => Subscription::none(),
+        let page_sub = self
+            .current_sample()
+            .map_or_else(Subscription::none, |page| {
+                page.subscription().map(Message::Page)
+            });
+
+        let theme_file_sub = if self.theme_file.is_some() {
+            iced::time::every(THEME_FILE_POLL_INTERVAL).map(|_| Message::ThemeFilePolled)
+        } else {
+            Subscription::none()
         };
 
-        iced::Subscription::batch([window_resize_sub, menu_bar_sub])
+        let window_sub = iced::event::listen_with(window_event);
+
+        let navigation_sub = iced::event::listen_with(navigation_event);
+
+        iced::Subscription::batch([
+            window_resize_sub,
+            window_sub,
+            page_sub,
+            theme_file_sub,
+            navigation_sub,
+        ])
     }
 
-    pub fn view(&self) -> Element<Message> {
+    pub fn view(&self) -> Element<'_, Message> {
         let nav_items = |pages: &[PageGroup]| -> Vec<side_nav::Group<Message>> {
             pages
                 .iter()
                 .map(|group| {
-                    let items = group.page_items.iter().map(|item| {
-                        side_nav::Item::new(
-                            None,
-                            item.label,
-                            Message::PageSelected(item.page.clone()),
-                        )
-                    });
-
-                    let expanded = if self.side_nav_display_mode == side_nav::DisplayMode::Compact
-                        || self.window_size.width < SIDE_NAV_COMPACT_WIDTH
-                    {
-                        self.page_group_overlay_open == Some(group.label)
-                    } else if self.side_nav_display_mode == side_nav::DisplayMode::Full {
+                    let items = group
+                        .page_items
+                        .iter()
+                        .map(|item| self.nav_node(item, vec![group.label]));
+
+                    let expanded = if self.side_nav_expands_groups() {
                         group.expanded
                     } else {
-                        false
+                        self.page_group_overlay_open == Some(group.label)
                     };
 
                     let group_message = if group.page_items.is_empty() {
-                        Message::PageSelected(group.page.clone().expect("Group should have page"))
+                        Message::PageSelected(group.page.expect("Group should have page"))
                     } else {
                         Message::PageGroupToggled(group.label)
                     };
@@ -230,6 +491,7 @@ impl Gallery {
                     side_nav::Group::new(group.icon, group.label, group_message)
                         .with_items(items)
                         .expanded(expanded)
+                        .selected(group.page == Some(self.current_page))
                         .overlay_width(group.overlay_width)
                         .on_overlay_dismiss(Message::PageGroupOverlayDismissed)
                 })
@@ -239,76 +501,132 @@ impl Gallery {
         let page_items = nav_items(&self.pages);
         let footer_page_items = nav_items(&self.footer_pages);
 
-        let display_mode = if self.side_nav_display_mode == side_nav::DisplayMode::Full
-            && self.window_size.width > SIDE_NAV_COMPACT_WIDTH
-        {
-            side_nav::DisplayMode::Full
-        } else {
-            side_nav::DisplayMode::Compact
-        };
-
-        let side_nav = side_nav::SideNav::new(display_mode, Message::SideNavDisplayModeToggled)
-            .with_groups(page_items)
-            .with_footer_groups(footer_page_items);
-
-        let padding = match self.current_page {
-            Page::Home => 0,
-            _ => 24,
+        let suggestions = Entry::search(&self.search_query)
+            .into_iter()
+            .take(SEARCH_SUGGESTIONS)
+            .map(|entry| {
+                side_nav::NavNode::new(
+                    Some(entry.icon.codepoint()),
+                    entry.title,
+                    Message::PageSelected(Page::Sample(entry.title)),
+                )
+            });
+
+        let search = side_nav::Search::new(
+            &self.search_query,
+            Message::SearchChanged,
+            Message::SearchOpened,
+        )
+        .placeholder("Search controls and samples")
+        .id(search_input_id())
+        .with_suggestions(suggestions)
+        .expanded(self.search_open)
+        .on_submit(Message::SearchSubmitted)
+        .on_overlay_dismiss(Message::SearchDismissed);
+
+        let side_nav = side_nav::SideNav::new(
+            self.side_nav_display_mode,
+            Message::SideNavDisplayModeToggled,
+        )
+        .full_width(self.side_nav_width)
+        .breakpoints(self.side_nav_breakpoints)
+        .window_width(self.window_size.width)
+        .pane_open(self.side_nav_pane_open)
+        .more_expanded(self.side_nav_more_open)
+        .on_more_pressed(Message::SideNavMorePressed)
+        .on_overlay_dismiss(Message::SideNavOverlayDismissed)
+        .back_button((!self.back_stack.is_empty()).then_some(Message::NavigatedBack))
+        .with_search(search)
+        .with_groups(page_items)
+        .with_footer_groups(footer_page_items);
+
+        let (padding, title, resettable, scrollable) = match self.current_page {
+            Page::Home => (0, None, false, false),
+            Page::Settings => (24, Some(Page::Settings.title()), false, true),
+            Page::Sample(title) => {
+                let entry = Entry::find(title);
+
+                (
+                    24,
+                    Some(title),
+                    entry.is_some_and(|entry| entry.resettable),
+                    entry.is_some_and(|entry| entry.scrollable),
+                )
+            }
         };
 
-        let page = Container::new(match &self.current_page {
+        let page_content = match self.current_page {
             Page::Home => self.home_page_view(),
+            Page::Sample(_) => self
+                .current_sample()
+                .map_or_else(|| column![].into(), |page| page.view().map(Message::Page)),
+            Page::Settings => self.settings_page_view(),
+        };
 
-            // Basic input
-            Page::Button(page) => page.view().map(Message::ButtonPage),
-            Page::DropDownButton(page) => page.view().map(Message::DropDownButtonPage),
-            Page::ToggleButton(page) => page.view().map(Message::ToggleButtonPage),
-            Page::SplitButton(page) => page.view().map(Message::SplitButtonPage),
-            Page::Checkbox(page) => page.view().map(Message::CheckboxPage),
-            Page::ComboBox(page) => page.view().map(Message::ComboBoxPage),
-            Page::Radio(page) => page.view().map(Message::RadioPage),
-            Page::Slider(page) => page.view().map(Message::SliderPage),
-            Page::Toggler(page) => page.view().map(Message::TogglerPage),
-
-            // Dialogs and flyouts
-            Page::Dialog(page) => page.view().map(Message::DialogPage),
-
-            // Layout
-            Page::RowColumn(page) => page.view().map(Message::RowColumnPage),
+        // Pages keep their state for the session, the reset button brings back the defaults
+        let page_content = match title {
+            Some(title) => {
+                let reset_button = resettable.then(|| {
+                    button::standard(
+                        row![
+                            text::icon(FluentIcon::Refresh.codepoint()),
+                            text::body1("Reset page")
+                        ]
+                        .spacing(8)
+                        .align_y(Vertical::Center),
+                    )
+                    .on_press(Message::PageReset)
+                });
+
+                let header = row![text::title2(title), horizontal_space()]
+                    .push_maybe(reset_button)
+                    .align_y(Vertical::Center);
+
+                column![header, page_content].spacing(32).into()
+            }
+            None => page_content,
+        };
 
-            // Media
-            Page::Image(page) => page.view().map(Message::ImagePage),
-            Page::Svg(page) => page.view().map(Message::SvgPage),
+        let page_content = if scrollable {
+            scrollable::standard(page_content)
+                .id(page_scrollable_id())
+                .on_scroll(Message::PageScrolled)
+                .into()
+        } else {
+            page_content
+        };
 
-            // Menu & toolbars
-            Page::AppBarButton(page) => page.view().map(Message::AppBarButton),
-            Page::MenuBar(page) => page.view().map(Message::MenuBar),
-            Page::Ribbon(page) => page.view().map(Message::Ribbon),
+        let page = Container::new(page_content)
+            .style(|theme| {
+                container::Style::default()
+                    .background(theme.palette().solid_background_fill_color_tertiary)
+                    .border(border::rounded(8))
+            })
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(padding);
+
+        let content: Element<Message> = match self.side_nav_display_mode() {
+            side_nav::DisplayMode::Top => column![side_nav, page].spacing(4).into(),
+            // Double clicking the handle collapses the pane, like the nav button
+            side_nav::DisplayMode::Full => {
+                let drag_handle = DragHandle::new()
+                    .on_drag(Message::SideNavResized)
+                    .on_release(Message::SideNavResizeEnded)
+                    .on_double_click(Message::SideNavCollapsed);
+
+                row![side_nav, drag_handle, page].into()
+            }
+            side_nav::DisplayMode::Compact | side_nav::DisplayMode::Minimal => {
+                row![side_nav, page].spacing(4).into()
+            }
+        };
 
-            // Text
-            Page::TextInput(page) => page.view().map(Message::TextInputPage),
+        let content = Element::new(container(content).padding(4));
 
-            Page::Settings => self.settings_page_view(),
-        })
-        .style(|theme| {
-            container::Style::default()
-                .background(theme.palette().solid_background_fill_color_tertiary)
-                .border(border::rounded(8))
-        })
-        .width(Length::Fill)
-        .height(Length::Fill)
-        .padding(padding);
-
-        let content = Element::new(container(row![side_nav, page,].spacing(4)).padding(4));
-
-        let view = if let Page::Dialog(page) = &self.current_page {
-            if page.is_dialog_open() {
-                modal(content, page.dialog().map(Message::DialogPage))
-            } else {
-                content
-            }
-        } else {
-            content
+        let view = match self.current_sample().and_then(|page| page.modal()) {
+            Some(dialog) => modal(content, dialog.map(Message::Page)),
+            None => content,
         };
 
         if self.explain {
@@ -322,18 +640,228 @@ impl Gallery {
         self.theme.clone()
     }
 
-    fn select_page(&mut self, page: Page) {
+    // The accent is kept as the setting even when a theme file's own accent is used
+    // instead, so it isn't lost when switching back to a built-in theme
+    fn set_theme(&mut self, base: Theme, accent: Option<Color>) {
+        let file_accent = matches!(base, Theme::Custom(_))
+            && self
+                .theme_file
+                .as_ref()
+                .is_some_and(|file| file.sets_accent);
+
+        self.accent = accent;
+        self.theme = match accent {
+            Some(accent) if !file_accent => base.with_accent(accent),
+            _ => base.clone(),
+        };
+        self.base_theme = base;
+    }
+
+    // Loads the theme file if it was modified since it was last loaded. On errors the
+    // current theme is kept so a half-written file doesn't reset the gallery.
+    fn reload_theme_file(&mut self) {
+        let Some(file) = &mut self.theme_file else {
+            return;
+        };
+
+        let modified = std::fs::metadata(&file.path)
+            .and_then(|metadata| metadata.modified())
+            .ok();
+
+        if modified.is_some() && modified == file.modified {
+            return;
+        }
+
+        file.modified = modified;
+
+        match theme::file::load(&file.path) {
+            Ok(theme_file) => {
+                file.sets_accent = !theme_file.missing.contains(&"accent_fill_color_default");
+
+                self.theme_file_messages = if theme_file.missing.is_empty() {
+                    Vec::new()
+                } else {
+                    vec![format!(
+                        "{} tokens not in the file were taken from the base theme",
+                        theme_file.missing.len()
+                    )]
+                };
+
+                self.set_theme(Theme::Custom(Arc::new(theme_file.palette)), self.accent);
+            }
+            Err(errors) => {
+                self.theme_file_messages = errors.iter().map(ToString::to_string).collect();
+            }
+        }
+    }
+
+    // Goes to `page`, remembering the current page for the back button
+    fn navigate(&mut self, page: Page) -> Task<Message> {
+        let from = self.visit();
+
+        if !self.select_page(page) || page == from.page {
+            return Task::none();
+        }
+
+        self.back_stack.push(from);
+        self.forward_stack.clear();
+
+        self.scroll_to(AbsoluteOffset::default())
+    }
+
+    fn restore(&mut self, visit: Visit) -> Task<Message> {
+        self.select_page(visit.page);
+
+        self.scroll_to(visit.scroll_offset)
+    }
+
+    fn visit(&self) -> Visit {
+        let scroll_offset = match self.current_sample().and_then(|page| page.scrollable()) {
+            Some((_, offset)) => offset,
+            None => self.scroll_offset,
+        };
+
+        Visit {
+            page: self.current_page,
+            scroll_offset,
+        }
+    }
+
+    // Pages share the scrollable, so it is scrolled whenever the page changes. Pages
+    // which scroll themselves are scrolled through their own scrollable instead.
+    fn scroll_to(&mut self, offset: AbsoluteOffset) -> Task<Message> {
+        if let Some(page) = self.current_sample_mut() {
+            if let Some((id, _)) = page.scrollable() {
+                page.scrolled_to(offset);
+
+                return iced::widget::scrollable::scroll_to(id, offset);
+            }
+        }
+
+        self.scroll_offset = offset;
+
+        iced::widget::scrollable::scroll_to(page_scrollable_id(), offset)
+    }
+
+    // The mode of the side navigation at the current window size
+    fn side_nav_display_mode(&self) -> side_nav::DisplayMode {
+        self.side_nav_breakpoints
+            .display_mode(self.side_nav_display_mode, self.window_size.width)
+    }
+
+    // Whether groups expand in the pane, rather than showing their pages in an overlay
+    fn side_nav_expands_groups(&self) -> bool {
+        match self.side_nav_display_mode() {
+            side_nav::DisplayMode::Full => true,
+            side_nav::DisplayMode::Compact | side_nav::DisplayMode::Minimal => {
+                self.side_nav_pane_open
+            }
+            side_nav::DisplayMode::Top => false,
+        }
+    }
+
+    // Nested groups in flyouts are closed with the flyout of their page group,
+    // while in the pane they stay expanded
+    fn collapse_flyouts(&mut self) {
+        if !self.side_nav_expands_groups() {
+            for page_group in &mut self.pages {
+                PageItem::collapse(&mut page_group.page_items);
+            }
+        }
+    }
+
+    // Whether the page group or nested group at the end of a path of labels is
+    // expanded
+    fn expanded_mut(&mut self, path: &[&str]) -> Option<&mut bool> {
+        let (label, path) = path.split_first()?;
+        let page_group = self
+            .pages
+            .iter_mut()
+            .find(|page_group| page_group.label == *label)?;
+
+        if path.is_empty() {
+            Some(&mut page_group.expanded)
+        } else {
+            PageItem::find_mut(&mut page_group.page_items, path).map(|item| &mut item.expanded)
+        }
+    }
+
+    fn close_side_nav_overlays(&mut self) {
+        self.collapse_flyouts();
         self.page_group_overlay_open = None;
+        self.side_nav_pane_open = false;
+        self.side_nav_more_open = false;
+        self.search_open = false;
+    }
+
+    // Returns false if the page isn't in the registry
+    fn select_page(&mut self, page: Page) -> bool {
+        self.close_side_nav_overlays();
+        self.search_query.clear();
+
+        if let Page::Sample(title) = page {
+            let Some(entry) = Entry::find(title) else {
+                return false;
+            };
+
+            self.page_states
+                .entry(title)
+                .or_insert_with(|| entry.create());
+        }
+
+        self.current_page = page;
+
+        true
+    }
+
+    fn nav_node(
+        &self,
+        item: &PageItem,
+        mut path: Vec<&'static str>,
+    ) -> side_nav::NavNode<'static, Message> {
+        path.push(item.label);
+
+        let on_press = match item.page {
+            Some(page) => Message::PageSelected(page),
+            None => Message::PageItemToggled(path.clone()),
+        };
+
+        side_nav::NavNode::new(item.icon, item.label, on_press)
+            .with_children(
+                item.children
+                    .iter()
+                    .map(|child| self.nav_node(child, path.clone())),
+            )
+            .expanded(item.expanded)
+            .selected(item.page == Some(self.current_page))
+            .on_overlay_dismiss(Message::PageItemToggled(path))
+    }
 
-        if discriminant(&self.current_page) != discriminant(&page) {
-            self.current_page = page
+    /// State of the page `P`, if it was visited.
+    #[cfg(test)]
+    pub fn page_state<P: page::GalleryPage>(&self) -> Option<&P> {
+        self.page_states.get(P::TITLE)?.as_any().downcast_ref()
+    }
+
+    fn current_sample(&self) -> Option<&dyn AnyPage> {
+        match self.current_page {
+            Page::Sample(title) => self.page_states.get(title).map(AsRef::as_ref),
+            _ => None,
+        }
+    }
+
+    fn current_sample_mut(&mut self) -> Option<&mut Box<dyn AnyPage>> {
+        match self.current_page {
+            Page::Sample(title) => self.page_states.get_mut(title),
+            _ => None,
         }
     }
 
-    fn home_page_view(&self) -> Element<Message> {
-        let image_path = match self.theme {
-            Theme::Light => "/assets/images/bloom_light.jpg",
-            Theme::Dark => "/assets/images/bloom_dark.jpg",
+    fn home_page_view(&self) -> Element<'_, Message> {
+        let image_path = if self.theme.is_dark() {
+            "/assets/images/bloom_dark.jpg"
+        } else {
+            "/assets/images/bloom_light.jpg"
         };
 
         stack![
@@ -348,7 +876,7 @@ impl Gallery {
         .into()
     }
 
-    fn settings_page_view(&self) -> Element<Message> {
+    fn settings_page_view(&self) -> Element<'_, Message> {
         fn setting_card<'a>(
             icon: char,
             label: &'static str,
@@ -372,10 +900,76 @@ impl Gallery {
             .into()
         }
 
+        let mut themes = vec![Theme::Light, Theme::Dark];
+        themes.extend(Contrast::ALL.map(Theme::HighContrast));
+
+        if let Theme::Custom(_) = self.base_theme {
+            themes.push(self.base_theme.clone());
+        }
+
         let theme_widget = pick_list::standard(
-            [Theme::Light, Theme::Dark],
-            Some(self.theme.clone()),
-            |_| Message::ThemeToggled,
+            themes,
+            Some(self.base_theme.clone()),
+            Message::ThemeSelected,
+        )
+        .into();
+
+        let theme_file_widget = row![
+            text_input::standard("Path to a .toml or .json theme", &self.theme_file_path)
+                .width(320)
+                .on_input(Message::ThemeFilePathChanged)
+                .on_submit(Message::ThemeFileLoaded),
+            button::standard(text::body1("Load")).on_press_maybe(
+                (!self.theme_file_path.trim().is_empty()).then_some(Message::ThemeFileLoaded)
+            ),
+        ]
+        .spacing(8)
+        .align_y(Vertical::Center)
+        .into();
+
+        let accent_button = |accent: Option<Color>| -> Element<Message> {
+            let selected = self.accent == accent;
+
+            Button::new(vertical_space())
+                .width(24)
+                .height(24)
+                .on_press(Message::AccentSelected(accent))
+                .style(move |theme: &Theme, status| {
+                    let palette = theme.palette();
+                    let base = style::button::secondary(theme, status);
+                    let default = if theme.is_dark() {
+                        Palette::DARK.accent_fill_color_default
+                    } else {
+                        Palette::LIGHT.accent_fill_color_default
+                    };
+
+                    iced::widget::button::Style {
+                        background: Some(accent.unwrap_or(default).into()),
+                        border: if selected {
+                            base.border.color(palette.text_fill_color_primary).width(2)
+                        } else {
+                            base.border
+                        },
+                        ..base
+                    }
+                })
+                .into()
+        };
+
+        let accent_widget = row![
+            accent_button(None),
+            text::caption1("Default"),
+            horizontal_space().width(8),
+        ]
+        .extend(ACCENT_COLOURS.map(|colour| accent_button(Some(colour))))
+        .spacing(4)
+        .align_y(Vertical::Center)
+        .into();
+
+        let navigation_widget = pick_list::standard(
+            side_nav::DisplayMode::ALL,
+            Some(self.side_nav_display_mode),
+            Message::SideNavDisplayModeSelected,
         )
         .into();
 
@@ -384,19 +978,48 @@ impl Gallery {
             .on_toggle(|_| Message::ExplainToggled)
             .into();
 
-        page(
-            "Settings",
-            [column![
-                setting_card(FluentIcon::Color.codepoint(), "Theme", theme_widget),
-                setting_card(
-                    FluentIcon::PageMarginLandscapeNormal.codepoint(),
-                    "Explain",
-                    explain_widget
-                ),
-            ]
-            .spacing(4)
-            .into()],
+        let theme_file_messages = Column::with_children(
+            self.theme_file_messages
+                .iter()
+                .map(|message| text::caption1(message.clone()).into()),
         )
+        .padding(Padding::ZERO.left(56).bottom(4))
+        .spacing(2);
+
+        let fonts_widget = text::body1(format!(
+            "{}, {}",
+            self.fonts.text.name(),
+            self.fonts.icons.name()
+        ))
+        .into();
+
+        page([column![
+            setting_card(FluentIcon::Color.codepoint(), "Theme", theme_widget),
+            setting_card(
+                FluentIcon::OpenFile.codepoint(),
+                "Theme file",
+                theme_file_widget
+            ),
+            theme_file_messages,
+            setting_card(
+                FluentIcon::Personalize.codepoint(),
+                "Accent colour",
+                accent_widget
+            ),
+            setting_card(
+                FluentIcon::GlobalNavButton.codepoint(),
+                "Navigation style",
+                navigation_widget
+            ),
+            setting_card(
+                FluentIcon::PageMarginLandscapeNormal.codepoint(),
+                "Explain",
+                explain_widget
+            ),
+            setting_card(FluentIcon::Font.codepoint(), "Fonts", fonts_widget),
+        ]
+        .spacing(4)
+        .into()])
     }
 }
 
@@ -404,13 +1027,33 @@ impl Default for Gallery {
     fn default() -> Self {
         Gallery {
             current_page: Page::default(),
+            back_stack: Vec::new(),
+            forward_stack: Vec::new(),
+            scroll_offset: AbsoluteOffset::default(),
+            page_states: HashMap::new(),
             pages: pages(),
             footer_pages: footer_pages(),
             page_group_overlay_open: None,
+            search_query: String::new(),
+            search_open: false,
             side_nav_display_mode: side_nav::DisplayMode::Full,
-            window_size: Size::default(),
+            side_nav_breakpoints: side_nav::Breakpoints::default(),
+            side_nav_pane_open: false,
+            side_nav_more_open: false,
+            side_nav_width: 300.0,
+            side_nav_resize_start: None,
+            window_size: window::Settings::default().size,
+            window_position: None,
+            base_theme: <Theme as Default>::default(),
             theme: <Theme as Default>::default(),
+            accent: None,
+            theme_file_path: String::new(),
+            theme_file: None,
+            theme_file_messages: Vec::new(),
             explain: false,
+            fonts: font::Fonts::SEGOE,
+            saved_settings: Settings::default(),
+            launch_settings: Settings::default(),
         }
     }
 }
@@ -424,138 +1067,111 @@ struct PageGroup {
     page_items: Vec<PageItem>,
 }
 
+// A page, or a nested group of pages if `page` is `None`
 struct PageItem {
+    icon: Option<char>,
     label: &'static str,
-    page: Page,
+    page: Option<Page>,
+    expanded: bool,
+    children: Vec<PageItem>,
+}
+
+impl PageItem {
+    // Adds the page of `entry` under the nested groups named by `subgroups`,
+    // creating the groups which don't exist yet
+    fn insert(items: &mut Vec<PageItem>, subgroups: &[&'static str], entry: &Entry) {
+        let Some((label, subgroups)) = subgroups.split_first() else {
+            items.push(PageItem {
+                icon: Some(entry.icon.codepoint()),
+                label: entry.title,
+                page: Some(Page::Sample(entry.title)),
+                expanded: false,
+                children: Vec::new(),
+            });
+            return;
+        };
+
+        let index = items
+            .iter()
+            .position(|item| item.page.is_none() && item.label == *label)
+            .unwrap_or_else(|| {
+                items.push(PageItem {
+                    icon: None,
+                    label,
+                    page: None,
+                    expanded: false,
+                    children: Vec::new(),
+                });
+                items.len() - 1
+            });
+
+        Self::insert(&mut items[index].children, subgroups, entry);
+    }
+
+    fn find_mut<'a>(items: &'a mut [PageItem], path: &[&str]) -> Option<&'a mut PageItem> {
+        let (label, path) = path.split_first()?;
+        let item = items.iter_mut().find(|item| item.label == *label)?;
+
+        if path.is_empty() {
+            Some(item)
+        } else {
+            Self::find_mut(&mut item.children, path)
+        }
+    }
+
+    // Adds the paths of the expanded groups under `path` to `paths`
+    fn expanded_paths(
+        items: &[PageItem],
+        path: &mut Vec<&'static str>,
+        paths: &mut Vec<Vec<String>>,
+    ) {
+        for item in items {
+            path.push(item.label);
+
+            if item.expanded {
+                paths.push(path.iter().map(|label| (*label).to_owned()).collect());
+            }
+
+            Self::expanded_paths(&item.children, path, paths);
+            path.pop();
+        }
+    }
+
+    fn collapse(items: &mut [PageItem]) {
+        for item in items {
+            item.expanded = false;
+            Self::collapse(&mut item.children);
+        }
+    }
 }
 
 fn pages() -> Vec<PageGroup> {
-    vec![
-        PageGroup {
-            icon: FluentIcon::Home.codepoint(),
-            label: "Home",
-            expanded: false,
-            overlay_width: 100.into(),
-            page: Some(Page::Home),
-            page_items: Vec::new(),
-        },
+    vec![PageGroup {
+        icon: FluentIcon::Home.codepoint(),
+        label: "Home",
+        expanded: false,
+        overlay_width: 100.into(),
+        page: Some(Page::Home),
+        page_items: Vec::new(),
+    }]
+    .into_iter()
+    .chain(Group::ALL.into_iter().map(|group| {
         PageGroup {
-            icon: FluentIcon::CheckboxComposite.codepoint(),
-            label: "Basic input",
+            icon: group.icon().codepoint(),
+            label: group.label(),
             expanded: false,
             overlay_width: 160.into(),
             page: None,
-            page_items: vec![
-                PageItem {
-                    label: "Button",
-                    page: Page::Button(page::button::Button::default()),
-                },
-                PageItem {
-                    label: "Drop Down Button",
-                    page: Page::DropDownButton(page::drop_down_button::DropDownButton::default()),
-                },
-                PageItem {
-                    label: "Toggle Button",
-                    page: Page::ToggleButton(page::toggle_button::ToggleButton::default()),
-                },
-                PageItem {
-                    label: "Split Button",
-                    page: Page::SplitButton(page::split_button::SplitButton::default()),
-                },
-                PageItem {
-                    label: "Checkbox",
-                    page: Page::Checkbox(page::checkbox::Checkbox::default()),
-                },
-                PageItem {
-                    label: "ComboBox",
-                    page: Page::ComboBox(page::combo_box::ComboBox::default()),
-                },
-                PageItem {
-                    label: "Radio",
-                    page: Page::Radio(page::radio::Radio::default()),
-                },
-                PageItem {
-                    label: "Slider",
-                    page: Page::Slider(page::slider::Slider::default()),
-                },
-                PageItem {
-                    label: "Toggler",
-                    page: Page::Toggler(page::toggler::Toggler::default()),
-                },
-            ],
-        },
-        PageGroup {
-            icon: FluentIcon::Message.codepoint(),
-            label: "Dialogs and flyouts",
-            expanded: false,
-            overlay_width: 120.into(),
-            page: None,
-            page_items: vec![PageItem {
-                label: "Dialog",
-                page: Page::Dialog(page::dialog::Dialog::default()),
-            }],
-        },
-        PageGroup {
-            icon: FluentIcon::PreviewLink.codepoint(),
-            label: "Layout",
-            expanded: false,
-            overlay_width: 120.into(),
-            page: None,
-            page_items: vec![PageItem {
-                label: "Row & Column",
-                page: Page::RowColumn(page::row_column::RowColumn::default()),
-            }],
-        },
-        PageGroup {
-            icon: FluentIcon::Slideshow.codepoint(),
-            label: "Media",
-            expanded: false,
-            overlay_width: 120.into(),
-            page: None,
-            page_items: vec![
-                PageItem {
-                    label: "Image",
-                    page: Page::Image(page::image::Image::default()),
-                },
-                PageItem {
-                    label: "Svg",
-                    page: Page::Svg(page::svg::Svg::default()),
-                },
-            ],
-        },
-        PageGroup {
-            icon: FluentIcon::Save.codepoint(),
-            label: "Menu & toolbars",
-            expanded: false,
-            overlay_width: 120.into(),
-            page: None,
-            page_items: vec![
-                PageItem {
-                    label: "AppBar Button",
-                    page: Page::AppBarButton(page::app_bar_button::AppBarButton::default()),
-                },
-                PageItem {
-                    label: "MenuBar",
-                    page: Page::MenuBar(page::menu_bar::MenuBar::default()),
-                },
-                PageItem {
-                    label: "Ribbon",
-                    page: Page::Ribbon(page::ribbon::Ribbon::default()),
-                },
-            ],
-        },
-        PageGroup {
-            icon: FluentIcon::Font.codepoint(),
-            label: "Text",
-            expanded: false,
-            overlay_width: 120.into(),
-            page: None,
-            page_items: vec![PageItem {
-                label: "Text Input",
-                page: Page::TextInput(page::text_input::TextInput::default()),
-            }],
-        },
-    ]
+            page_items: page::REGISTRY
+                .iter()
+                .filter(|entry| entry.group == group)
+                .fold(Vec::new(), |mut items, entry| {
+                    PageItem::insert(&mut items, entry.subgroups, entry);
+                    items
+                }),
+        }
+    }))
+    .collect()
 }
 
 fn footer_pages() -> Vec<PageGroup> {
@@ -569,6 +1185,44 @@ fn footer_pages() -> Vec<PageGroup> {
     }]
 }
 
+fn page_scrollable_id() -> iced::widget::scrollable::Id {
+    iced::widget::scrollable::Id::new("page")
+}
+
+fn window_event(event: Event, _status: event::Status, window: window::Id) -> Option<Message> {
+    match event {
+        Event::Window(window::Event::Moved(position)) => Some(Message::WindowMoved(position)),
+        Event::Window(window::Event::CloseRequested) => Some(Message::WindowCloseRequested(window)),
+        _ => None,
+    }
+}
+
+// Alt+Left and Alt+Right, or the back and forward mouse buttons
+fn navigation_event(event: Event, status: event::Status, _window: window::Id) -> Option<Message> {
+    match event {
+        Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. })
+            if modifiers.alt() && status == event::Status::Ignored =>
+        {
+            match key {
+                Key::Named(Named::ArrowLeft) => Some(Message::NavigatedBack),
+                Key::Named(Named::ArrowRight) => Some(Message::NavigatedForward),
+                _ => None,
+            }
+        }
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Back)) => {
+            Some(Message::NavigatedBack)
+        }
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Forward)) => {
+            Some(Message::NavigatedForward)
+        }
+        _ => None,
+    }
+}
+
+fn search_input_id() -> iced::widget::text_input::Id {
+    iced::widget::text_input::Id::new("side_nav_search")
+}
+
 fn modal<'a, Message>(
     base: impl Into<Element<'a, Message>>,
     content: impl Into<Element<'a, Message>>,
@@ -585,3 +1239,72 @@ where
 
     stack![base.into(), opaque(area),].into()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::page::{button::Button, drop_down_button::DropDownButton, GalleryPage};
+    use crate::simulator::Simulator;
+
+    use std::{env, fs};
+
+    use iced::color;
+
+    fn gallery(theme_file: PathBuf, accent: Color) -> Gallery {
+        let settings = Settings {
+            theme_file: Some(theme_file),
+            accent: Some(accent),
+            ..Settings::default()
+        };
+
+        Gallery::new(settings.clone(), settings).0
+    }
+
+    #[test]
+    fn accent_of_a_theme_file_wins_over_the_accent_setting() {
+        let orchid = PathBuf::from(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/assets/themes/orchid.toml"
+        ));
+        let gallery = gallery(orchid, color!(0x107C10));
+
+        assert_eq!(
+            gallery.theme().palette().accent_fill_color_default,
+            color!(0xC239B3)
+        );
+        assert_eq!(gallery.accent, Some(color!(0x107C10)));
+    }
+
+    #[test]
+    fn accent_setting_applies_to_a_theme_file_without_an_accent() {
+        let path = env::temp_dir().join("fluent_iced_gallery_test_no_accent.toml");
+        fs::write(&path, "base = \"light\"\n").unwrap();
+
+        let gallery = gallery(path.clone(), color!(0x107C10));
+        fs::remove_file(path).unwrap();
+
+        assert_eq!(
+            gallery.theme().palette().accent_fill_color_default,
+            Theme::Light
+                .with_accent(color!(0x107C10))
+                .palette()
+                .accent_fill_color_default
+        );
+    }
+
+    #[test]
+    fn reset_button_is_hidden_on_pages_which_opt_out() {
+        let reset_buttons = |title| {
+            Simulator::new(Settings {
+                page: Page::Sample(title),
+                ..Settings::default()
+            })
+            .find("Reset page")
+            .len()
+        };
+
+        assert_eq!(reset_buttons(Button::TITLE), 1);
+        assert_eq!(reset_buttons(DropDownButton::TITLE), 0);
+    }
+}
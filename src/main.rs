@@ -1,25 +1,79 @@
-mod fluent_icon;
-mod font;
+mod cli;
 mod gallery;
 mod page;
-mod style;
-mod theme;
-mod widget;
+mod screenshot;
+mod settings;
+#[cfg(test)]
+mod simulator;
 
+use cli::Args;
 use gallery::Gallery;
+use settings::Settings;
 
-use iced::window::{icon, Settings};
+use std::{env, process::ExitCode};
+
+use iced::window::{self, icon, Position};
+
+fn main() -> ExitCode {
+    let args = match Args::parse(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(error) => {
+            eprintln!("{error}\n\n{}", cli::USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    if args.help {
+        println!("{}", cli::USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    if args.list_pages {
+        println!("{}", cli::page_list());
+        return ExitCode::SUCCESS;
+    }
+
+    if let Some(dir) = &args.screenshot {
+        return match screenshot::export(&args, dir) {
+            Ok(paths) => {
+                for path in paths {
+                    println!("{}", path.display());
+                }
+
+                ExitCode::SUCCESS
+            }
+            Err(error) => {
+                eprintln!("{error}");
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    let saved = Settings::load_or_default();
+    let settings = args.apply(saved.clone());
 
-fn main() -> iced::Result {
     let icon = icon::from_file_data(include_bytes!("../assets/images/logo.png"), None);
 
-    iced::application("Fluent Iced Gallery", Gallery::update, Gallery::view)
+    let result = iced::application("Fluent Iced Gallery", Gallery::update, Gallery::view)
         .subscription(Gallery::subscription)
         .theme(Gallery::theme)
-        .window(Settings {
+        .window(window::Settings {
+            size: settings.window_size,
+            position: settings
+                .window_position
+                .map_or(Position::Default, Position::Specific),
             min_size: Some((500.0, 500.0).into()),
             icon: icon.ok(),
-            ..Settings::default()
+            exit_on_close_request: false,
+            ..window::Settings::default()
         })
-        .run()
+        .run_with(move || Gallery::new(settings, saved));
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
 }
@@ -4,7 +4,9 @@ pub mod checkbox;
 pub mod combo_box;
 pub mod dialog;
 pub mod drop_down_button;
+pub mod iconography;
 pub mod image;
+pub mod info_badge;
 pub mod menu_bar;
 pub mod radio;
 pub mod ribbon;
@@ -16,33 +18,353 @@ pub mod text_input;
 pub mod toggle_button;
 pub mod toggler;
 
-use crate::widget::{
-    text, {Container, Element, Row},
+/// Code of the examples, extracted from the regions marked in the pages by `build.rs`.
+pub mod source {
+    include!(concat!(env!("OUT_DIR"), "/source.rs"));
+}
+
+use fluent_iced::{
+    fluent_icon::FluentIcon,
+    widget::{
+        code_block::CodeBlock,
+        text, {Container, Element, Row},
+    },
 };
 
+use std::{any::Any, cmp::Reverse, fmt, sync::Arc};
+
 use iced::{
     alignment::Vertical,
     border::Radius,
-    widget::{column, container::Style, horizontal_space},
-    Border, Length, Padding,
+    widget::{
+        column,
+        container::Style,
+        horizontal_space,
+        scrollable::{self, AbsoluteOffset},
+    },
+    Border, Length, Padding, Subscription,
 };
 
+/// A page of the gallery, listed in [`REGISTRY`].
+pub trait GalleryPage: Default + 'static {
+    type Message: Clone + fmt::Debug + Send + Sync + 'static;
+
+    const TITLE: &'static str;
+    const ICON: FluentIcon;
+    const GROUP: Group;
+    /// Labels of the nested groups the page is listed under within its group,
+    /// outermost first.
+    const SUBGROUPS: &'static [&'static str] = &[];
+    /// Extra words the search matches, besides the title and group.
+    const KEYWORDS: &'static [&'static str] = &[];
+    /// Whether the gallery shows a button to bring back the default state of the
+    /// page, which is otherwise kept for the session.
+    const RESETTABLE: bool = true;
+    /// Whether the gallery scrolls the page. Pages filling the height, such as ones
+    /// with their own scrollable, set this to false.
+    const SCROLLABLE: bool = true;
+
+    fn update(&mut self, message: Self::Message);
+
+    fn view(&self) -> Element<'_, Self::Message>;
+
+    fn subscription(&self) -> Subscription<Self::Message> {
+        Subscription::none()
+    }
+
+    /// Content shown in a modal above the whole gallery, such as a dialog.
+    fn modal(&self) -> Option<Element<'_, Self::Message>> {
+        None
+    }
+
+    /// Id and offset of the scrollable of a page which sets `SCROLLABLE` to false
+    /// and scrolls its content itself, so the gallery can restore the offset on
+    /// back and forward like it does for the pages it scrolls.
+    fn scrollable(&self) -> Option<(scrollable::Id, AbsoluteOffset)> {
+        None
+    }
+
+    /// Called when the gallery scrolls the scrollable of [`Self::scrollable`].
+    fn scrolled_to(&mut self, _offset: AbsoluteOffset) {}
+}
+
+/// Group of the side navigation a page is listed under.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Group {
+    DesignGuidance,
+    BasicInput,
+    DialogsAndFlyouts,
+    Layout,
+    Media,
+    MenuAndToolbars,
+    StatusAndInfo,
+    Text,
+}
+
+impl Group {
+    pub const ALL: [Group; 8] = [
+        Group::DesignGuidance,
+        Group::BasicInput,
+        Group::DialogsAndFlyouts,
+        Group::Layout,
+        Group::Media,
+        Group::MenuAndToolbars,
+        Group::StatusAndInfo,
+        Group::Text,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Group::DesignGuidance => "Design guidance",
+            Group::BasicInput => "Basic input",
+            Group::DialogsAndFlyouts => "Dialogs and flyouts",
+            Group::Layout => "Layout",
+            Group::Media => "Media",
+            Group::MenuAndToolbars => "Menu & toolbars",
+            Group::StatusAndInfo => "Status & info",
+            Group::Text => "Text",
+        }
+    }
+
+    pub fn icon(&self) -> FluentIcon {
+        match self {
+            Group::DesignGuidance => FluentIcon::Design,
+            Group::BasicInput => FluentIcon::CheckboxComposite,
+            Group::DialogsAndFlyouts => FluentIcon::Message,
+            Group::Layout => FluentIcon::PreviewLink,
+            Group::Media => FluentIcon::Slideshow,
+            Group::MenuAndToolbars => FluentIcon::Save,
+            Group::StatusAndInfo => FluentIcon::Info,
+            Group::Text => FluentIcon::Font,
+        }
+    }
+}
+
+/// Every page of the gallery, in the order they are listed.
+pub const REGISTRY: &[Entry] = &[
+    // Design guidance
+    Entry::new::<iconography::Iconography>(),
+    // Basic input
+    Entry::new::<button::Button>(),
+    Entry::new::<drop_down_button::DropDownButton>(),
+    Entry::new::<toggle_button::ToggleButton>(),
+    Entry::new::<split_button::SplitButton>(),
+    Entry::new::<checkbox::Checkbox>(),
+    Entry::new::<combo_box::ComboBox>(),
+    Entry::new::<radio::Radio>(),
+    Entry::new::<slider::Slider>(),
+    Entry::new::<toggler::Toggler>(),
+    // Dialogs and flyouts
+    Entry::new::<dialog::Dialog>(),
+    // Layout
+    Entry::new::<row_column::RowColumn>(),
+    // Media
+    Entry::new::<image::Image>(),
+    Entry::new::<svg::Svg>(),
+    // Menu & toolbars
+    Entry::new::<app_bar_button::AppBarButton>(),
+    Entry::new::<menu_bar::MenuBar>(),
+    Entry::new::<ribbon::Ribbon>(),
+    // Status & info
+    Entry::new::<info_badge::InfoBadge>(),
+    // Text
+    Entry::new::<text_input::TextInput>(),
+];
+
+/// A registered page. Its title is unique and identifies the page.
+#[derive(Clone, Copy, Debug)]
+pub struct Entry {
+    pub title: &'static str,
+    pub icon: FluentIcon,
+    pub group: Group,
+    pub subgroups: &'static [&'static str],
+    pub keywords: &'static [&'static str],
+    pub resettable: bool,
+    pub scrollable: bool,
+    create: fn() -> Box<dyn AnyPage>,
+}
+
+impl Entry {
+    pub const fn new<P: GalleryPage>() -> Self {
+        Self {
+            title: P::TITLE,
+            icon: P::ICON,
+            group: P::GROUP,
+            subgroups: P::SUBGROUPS,
+            keywords: P::KEYWORDS,
+            resettable: P::RESETTABLE,
+            scrollable: P::SCROLLABLE,
+            create: || Box::new(P::default()),
+        }
+    }
+
+    pub fn find(title: &str) -> Option<&'static Entry> {
+        REGISTRY.iter().find(|entry| entry.title == title)
+    }
+
+    /// The page with its default state.
+    pub fn create(&self) -> Box<dyn AnyPage> {
+        (self.create)()
+    }
+
+    /// Entries fuzzy matching `query`, best match first. A match in the title
+    /// ranks above one in the keywords, which ranks above one in the group labels.
+    pub fn search(query: &str) -> Vec<&'static Entry> {
+        let query = query.trim().to_lowercase();
+
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        let mut matches: Vec<(u32, &Entry)> = REGISTRY
+            .iter()
+            .filter_map(|entry| {
+                let title = fuzzy_score(&query, entry.title).map(|score| score * 3);
+                let keywords = entry
+                    .keywords
+                    .iter()
+                    .filter_map(|keyword| fuzzy_score(&query, keyword))
+                    .max()
+                    .map(|score| score * 2);
+                let group = std::iter::once(entry.group.label())
+                    .chain(entry.subgroups.iter().copied())
+                    .filter_map(|label| fuzzy_score(&query, label))
+                    .max();
+
+                [title, keywords, group]
+                    .into_iter()
+                    .flatten()
+                    .max()
+                    .map(|score| (score, entry))
+            })
+            .collect();
+
+        // Stable, so equal scores keep the order of the registry
+        matches.sort_by_key(|(score, _)| Reverse(*score));
+
+        matches.into_iter().map(|(_, entry)| entry).collect()
+    }
+}
+
+// Scores `candidate` if it contains the characters of the lowercase `query` in
+// order. Consecutive characters and characters at the start of a word score more.
+fn fuzzy_score(query: &str, candidate: &str) -> Option<u32> {
+    let mut query = query.chars().filter(|c| !c.is_whitespace()).peekable();
+    let mut score = 0;
+    let mut previous: Option<char> = None;
+    let mut previous_matched = false;
+
+    for c in candidate.chars() {
+        let Some(&wanted) = query.peek() else {
+            break;
+        };
+
+        let matched = c.to_lowercase().eq(wanted.to_lowercase());
+
+        if matched {
+            query.next();
+            score += 1;
+
+            if previous_matched {
+                score += 2;
+            }
+
+            if previous.is_none_or(|previous| !previous.is_alphanumeric()) {
+                score += 3;
+            }
+        }
+
+        previous = Some(c);
+        previous_matched = matched;
+    }
+
+    query.peek().is_none().then_some(score)
+}
+
+/// A [`GalleryPage`] with its message type erased, so the gallery can hold any page.
+pub trait AnyPage {
+    /// Messages sent by other pages are ignored.
+    fn update(&mut self, message: PageMessage);
+
+    fn view(&self) -> Element<'_, PageMessage>;
+
+    fn subscription(&self) -> Subscription<PageMessage>;
+
+    fn modal(&self) -> Option<Element<'_, PageMessage>>;
+
+    fn scrollable(&self) -> Option<(scrollable::Id, AbsoluteOffset)>;
+
+    fn scrolled_to(&mut self, offset: AbsoluteOffset);
+
+    #[cfg(test)]
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<P: GalleryPage> AnyPage for P {
+    fn update(&mut self, message: PageMessage) {
+        let message: &dyn Any = &*message.0;
+
+        if let Some(message) = message.downcast_ref::<P::Message>() {
+            GalleryPage::update(self, message.clone());
+        }
+    }
+
+    fn view(&self) -> Element<'_, PageMessage> {
+        GalleryPage::view(self).map(PageMessage::new)
+    }
+
+    fn subscription(&self) -> Subscription<PageMessage> {
+        GalleryPage::subscription(self).map(PageMessage::new)
+    }
+
+    fn modal(&self) -> Option<Element<'_, PageMessage>> {
+        GalleryPage::modal(self).map(|modal| modal.map(PageMessage::new))
+    }
+
+    fn scrollable(&self) -> Option<(scrollable::Id, AbsoluteOffset)> {
+        GalleryPage::scrollable(self)
+    }
+
+    fn scrolled_to(&mut self, offset: AbsoluteOffset) {
+        GalleryPage::scrolled_to(self, offset);
+    }
+
+    #[cfg(test)]
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Message of any page.
+#[derive(Clone, Debug)]
+pub struct PageMessage(Arc<dyn Message>);
+
+impl PageMessage {
+    fn new(message: impl Message) -> Self {
+        Self(Arc::new(message))
+    }
+}
+
+trait Message: Any + fmt::Debug + Send + Sync {}
+
+impl<T: Any + fmt::Debug + Send + Sync> Message for T {}
+
+/// Content of a page, below the title the gallery shows above it.
 pub fn page<'a, Message: 'a>(
-    title: &'a str,
     examples: impl IntoIterator<Item = Element<'a, Message>>,
 ) -> Element<'a, Message> {
-    column![text::title2(title),]
-        .spacing(32)
-        .extend(examples)
-        .into()
+    column![].spacing(32).extend(examples).into()
 }
 
 // Based on https://github.com/microsoft/WinUI-Gallery/blob/main/WinUIGallery/Controls/ControlExample.xaml
+//
+// The source is usually a constant of `source`, and is shown collapsed below the example
 pub fn widget_example<'a, Message: 'a + Clone>(
     title: &'a str,
     example: impl Into<Element<'a, Message>>,
     output: Option<impl Into<Element<'a, Message>>>,
     options: Option<impl Into<Element<'a, Message>>>,
+    source: Option<&'a str>,
 ) -> Element<'a, Message> {
     let mut content = vec![example.into()];
 
@@ -98,5 +420,8 @@ pub fn widget_example<'a, Message: 'a + Clone>(
     })
     .width(Length::Fill);
 
-    column![text::bold(title), presenter,].spacing(12).into()
+    column![text::bold(title), presenter,]
+        .push_maybe(source.map(CodeBlock::new))
+        .spacing(12)
+        .into()
 }
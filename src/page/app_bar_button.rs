@@ -1,6 +1,7 @@
-use crate::{
+use crate::page::{page, source, widget_example, GalleryPage, Group};
+
+use fluent_iced::{
     fluent_icon::FluentIcon,
-    page::{page, widget_example},
     widget::{button, text, Element},
 };
 
@@ -14,26 +15,33 @@ pub enum Message {
     Button1Pressed,
 }
 
-impl AppBarButton {
-    pub fn update(&mut self, message: Message) {
+impl GalleryPage for AppBarButton {
+    type Message = Message;
+
+    const TITLE: &'static str = "AppBar Button";
+    const ICON: FluentIcon = FluentIcon::Pin;
+    const GROUP: Group = Group::MenuAndToolbars;
+    const KEYWORDS: &'static [&'static str] = &["command bar", "toolbar", "icon button"];
+
+    fn update(&mut self, message: Message) {
         match message {
             Message::Button1Pressed => self.button1_pressed = true,
         }
     }
 
-    pub fn view(&self) -> Element<Message> {
-        page(
-            "AppBar Button",
-            [widget_example(
-                "An AppBar button with a symbol icon.",
-                button::app_bar("Icon", FluentIcon::Like).on_press(Message::Button1Pressed),
-                Some(text::body1(if self.button1_pressed {
-                    "You clicked: Button1"
-                } else {
-                    ""
-                })),
-                None::<Element<Message>>,
-            )],
-        )
+    fn view(&self) -> Element<'_, Message> {
+        page([widget_example(
+            "An AppBar button with a symbol icon.",
+            // #region symbol_icon
+            button::app_bar("Icon", FluentIcon::Like).on_press(Message::Button1Pressed),
+            // #endregion
+            Some(text::body1(if self.button1_pressed {
+                "You clicked: Button1"
+            } else {
+                ""
+            })),
+            None::<Element<Message>>,
+            Some(source::app_bar_button::SYMBOL_ICON),
+        )])
     }
 }
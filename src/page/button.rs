@@ -1,5 +1,7 @@
-use crate::{
-    page::{page, widget_example},
+use crate::page::{page, source, widget_example, GalleryPage, Group};
+
+use fluent_iced::{
+    fluent_icon::FluentIcon,
     widget::{self, button, checkbox, text, Element},
 };
 
@@ -29,8 +31,16 @@ pub enum Message {
     Button2Pressed,
 }
 
-impl Button {
-    pub fn update(&mut self, message: Message) {
+impl GalleryPage for Button {
+    type Message = Message;
+
+    const TITLE: &'static str = "Button";
+    const ICON: FluentIcon = FluentIcon::ButtonA;
+    const GROUP: Group = Group::BasicInput;
+    const SUBGROUPS: &'static [&'static str] = &["Buttons"];
+    const KEYWORDS: &'static [&'static str] = &["click", "press", "graphical content"];
+
+    fn update(&mut self, message: Message) {
         match message {
             Message::Button1Pressed => self.button1_pressed = true,
             Message::Button1EnabledToggled(checked) => self.button1_enabled = !checked,
@@ -38,47 +48,50 @@ impl Button {
         }
     }
 
-    pub fn view(&self) -> Element<Message> {
-        page(
-            "Button",
-            [
-                widget_example(
-                    "A simple Button with text content.",
-                    button::standard(text::body1("Standard button"))
-                        .on_press_maybe(self.button1_enabled.then_some(Message::Button1Pressed)),
-                    Some(text::body1(format![
-                        "Output: {}",
-                        if self.button1_pressed {
-                            "\nYou clicked: Button1"
-                        } else {
-                            ""
-                        }
-                    ])),
-                    Some(
-                        checkbox::two_state("Disable button", !self.button1_enabled)
-                            .on_toggle(Message::Button1EnabledToggled),
-                    ),
-                ),
-                widget_example(
-                    "A Button with graphical content.",
-                    widget::Button::new(image(format! {
-                        "{}/assets/images/Slices.png", env!("CARGO_MANIFEST_DIR")
-                    }))
-                    .width(50)
-                    .height(50)
-                    .padding(Padding::from(12))
-                    .on_press(Message::Button2Pressed),
-                    Some(text::body1(format![
-                        "Output: {}",
-                        if self.button2_pressed {
-                            "\nYou clicked: Button2"
-                        } else {
-                            ""
-                        }
-                    ])),
-                    None::<Element<Message>>,
+    fn view(&self) -> Element<'_, Message> {
+        page([
+            widget_example(
+                "A simple Button with text content.",
+                // #region text
+                button::standard(text::body1("Standard button"))
+                    .on_press_maybe(self.button1_enabled.then_some(Message::Button1Pressed)),
+                // #endregion
+                Some(text::body1(format![
+                    "Output: {}",
+                    if self.button1_pressed {
+                        "\nYou clicked: Button1"
+                    } else {
+                        ""
+                    }
+                ])),
+                Some(
+                    checkbox::two_state("Disable button", !self.button1_enabled)
+                        .on_toggle(Message::Button1EnabledToggled),
                 ),
-            ],
-        )
+                Some(source::button::TEXT),
+            ),
+            widget_example(
+                "A Button with graphical content.",
+                // #region graphical
+                widget::Button::new(image(format! {
+                    "{}/assets/images/Slices.png", env!("CARGO_MANIFEST_DIR")
+                }))
+                .width(50)
+                .height(50)
+                .padding(Padding::from(12))
+                .on_press(Message::Button2Pressed),
+                // #endregion
+                Some(text::body1(format![
+                    "Output: {}",
+                    if self.button2_pressed {
+                        "\nYou clicked: Button2"
+                    } else {
+                        ""
+                    }
+                ])),
+                None::<Element<Message>>,
+                Some(source::button::GRAPHICAL),
+            ),
+        ])
     }
 }
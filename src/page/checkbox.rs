@@ -1,5 +1,7 @@
-use crate::{
-    page::{page, widget_example},
+use crate::page::{page, source, widget_example, GalleryPage, Group};
+
+use fluent_iced::{
+    fluent_icon::FluentIcon,
     widget::{
         checkbox::{self, ThreeState},
         text, Element,
@@ -55,8 +57,17 @@ impl Checkbox {
             self.group_parent_state = ThreeState::Indeterminate;
         }
     }
+}
+
+impl GalleryPage for Checkbox {
+    type Message = Message;
+
+    const TITLE: &'static str = "Checkbox";
+    const ICON: FluentIcon = FluentIcon::Checkbox;
+    const GROUP: Group = Group::BasicInput;
+    const KEYWORDS: &'static [&'static str] = &["tick", "check", "three state", "indeterminate"];
 
-    pub fn update(&mut self, message: Message) {
+    fn update(&mut self, message: Message) {
         match message {
             Message::Checkbox1Toggled(checked) => self.checkbox1_checked = Some(checked),
             Message::Checkbox2Toggled => match self.checkbox2_state {
@@ -93,66 +104,72 @@ impl Checkbox {
         }
     }
 
-    pub fn view(&self) -> Element<Message> {
-        page(
-            "Checkbox",
-            [
-                widget_example(
-                    "A 2-state Checkbox.",
-                    checkbox::two_state(
-                        "Two-state Checkbox",
-                        self.checkbox1_checked.unwrap_or_default(),
-                    )
-                    .on_toggle(Message::Checkbox1Toggled),
-                    Some(text::body1(format![
-                        "Output: {}",
-                        match self.checkbox1_checked {
-                            Some(false) => "\nYou unchecked the box.",
-                            Some(true) => "\nYou checked the box.",
-                            None => "",
-                        }
-                    ])),
-                    None::<Element<Message>>,
-                ),
-                widget_example(
-                    "A 3-state Checkbox.",
-                    checkbox::three_state(
-                        "Three-state Checkbox",
-                        self.checkbox2_state.unwrap_or(ThreeState::Unchecked),
-                    )
-                    .on_toggle(|_| Message::Checkbox2Toggled),
-                    Some(text::body1(format![
-                        "Output: {}",
-                        match self.checkbox2_state {
-                            Some(ThreeState::Unchecked) => "\nCheckbox is unchecked.",
-                            Some(ThreeState::Checked) => "\nCheckbox is checked.",
-                            Some(ThreeState::Indeterminate) => "\nCheckbox is indeterminant.",
-                            None => "",
-                        }
-                    ])),
-                    None::<Element<Message>>,
-                ),
-                widget_example(
-                    "Using exclusive Checkboxes.",
+    fn view(&self) -> Element<'_, Message> {
+        page([
+            widget_example(
+                "A 2-state Checkbox.",
+                // #region two_state
+                checkbox::two_state(
+                    "Two-state Checkbox",
+                    self.checkbox1_checked.unwrap_or_default(),
+                )
+                .on_toggle(Message::Checkbox1Toggled),
+                // #endregion
+                Some(text::body1(format![
+                    "Output: {}",
+                    match self.checkbox1_checked {
+                        Some(false) => "\nYou unchecked the box.",
+                        Some(true) => "\nYou checked the box.",
+                        None => "",
+                    }
+                ])),
+                None::<Element<Message>>,
+                Some(source::checkbox::TWO_STATE),
+            ),
+            widget_example(
+                "A 3-state Checkbox.",
+                // #region three_state
+                checkbox::three_state(
+                    "Three-state Checkbox",
+                    self.checkbox2_state.unwrap_or(ThreeState::Unchecked),
+                )
+                .on_toggle(|_| Message::Checkbox2Toggled),
+                // #endregion
+                Some(text::body1(format![
+                    "Output: {}",
+                    match self.checkbox2_state {
+                        Some(ThreeState::Unchecked) => "\nCheckbox is unchecked.",
+                        Some(ThreeState::Checked) => "\nCheckbox is checked.",
+                        Some(ThreeState::Indeterminate) => "\nCheckbox is indeterminant.",
+                        None => "",
+                    }
+                ])),
+                None::<Element<Message>>,
+                Some(source::checkbox::THREE_STATE),
+            ),
+            widget_example(
+                "Using exclusive Checkboxes.",
+                // #region exclusive
+                column![
+                    checkbox::three_state("Select All", self.group_parent_state)
+                        .on_toggle(|_| Message::CheckboxParentToggled),
                     column![
-                        checkbox::three_state("Select All", self.group_parent_state)
-                            .on_toggle(|_| Message::CheckboxParentToggled),
-                        column![
-                            checkbox::two_state("Option 1", self.group_option1_checked)
-                                .on_toggle(Message::Option1Toggled),
-                            checkbox::two_state("Option 2", self.group_option2_checked)
-                                .on_toggle(Message::Option2Toggled),
-                            checkbox::two_state("Option 3", self.group_option3_checked)
-                                .on_toggle(Message::Option3Toggled),
-                        ]
-                        .padding(Padding::default().left(24))
-                        .spacing(12),
+                        checkbox::two_state("Option 1", self.group_option1_checked)
+                            .on_toggle(Message::Option1Toggled),
+                        checkbox::two_state("Option 2", self.group_option2_checked)
+                            .on_toggle(Message::Option2Toggled),
+                        checkbox::two_state("Option 3", self.group_option3_checked)
+                            .on_toggle(Message::Option3Toggled),
                     ]
+                    .padding(Padding::default().left(24))
                     .spacing(12),
-                    None::<Element<Message>>,
-                    None::<Element<Message>>,
-                ),
-            ],
-        )
+                ]
+                .spacing(12),
+                // #endregion
+                None::<Element<Message>>,
+                None::<Element<Message>>,
+                Some(source::checkbox::EXCLUSIVE),
+            ),
+        ])
     }
 }
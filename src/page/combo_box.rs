@@ -1,5 +1,7 @@
-use crate::{
-    page::{page, widget_example},
+use crate::page::{page, source, widget_example, GalleryPage, Group};
+
+use fluent_iced::{
+    fluent_icon::FluentIcon,
     widget::{canvas::Rectangle, combo_box, pick_list, text, Canvas, Container, Element},
 };
 
@@ -33,66 +35,76 @@ pub enum Message {
     FontSizeSelected(u16),
 }
 
-impl ComboBox {
-    pub fn update(&mut self, message: Message) {
+impl GalleryPage for ComboBox {
+    type Message = Message;
+
+    const TITLE: &'static str = "ComboBox";
+    const ICON: FluentIcon = FluentIcon::List;
+    const GROUP: Group = Group::BasicInput;
+    const KEYWORDS: &'static [&'static str] = &["dropdown", "pick list", "select", "options"];
+
+    fn update(&mut self, message: Message) {
         match message {
             Message::ColourSelected(colour) => self.selected_colour = Some(colour),
             Message::FontSizeSelected(size) => self.selected_font_size = size,
         }
     }
 
-    pub fn view(&self) -> Element<Message> {
-        page(
-            "ComboBox",
-            [
-                widget_example(
-                    "A PickList with items defined.",
-                    column![
-                        text::body1("Colours"),
-                        pick_list::standard(
-                            [Colour::Blue, Colour::Green, Colour::Red, Colour::Yellow],
-                            self.selected_colour,
-                            Message::ColourSelected,
-                        )
-                        .placeholder("Pick a colour")
-                        .width(200),
-                        Container::new(Canvas::new(
-                            Rectangle::new(108.0, 32.0).colour(
-                                self.selected_colour
-                                    .map_or(Color::TRANSPARENT, |colour| colour.into())
-                            )
-                        ))
-                        .center_x(Length::Fill)
-                        .height(32)
-                    ]
-                    .width(200)
-                    .spacing(8),
-                    None::<Element<Message>>,
-                    None::<Element<Message>>,
-                ),
-                widget_example(
-                    "An editable ComboBox.",
-                    column![
-                        text::body1("Font Size"),
-                        combo_box::standard(
-                            &self.font_sizes,
-                            "",
-                            Some(&self.selected_font_size),
-                            Message::FontSizeSelected
+    fn view(&self) -> Element<'_, Message> {
+        page([
+            widget_example(
+                "A PickList with items defined.",
+                // #region pick_list
+                column![
+                    text::body1("Colours"),
+                    pick_list::standard(
+                        [Colour::Blue, Colour::Green, Colour::Red, Colour::Yellow],
+                        self.selected_colour,
+                        Message::ColourSelected,
+                    )
+                    .placeholder("Pick a colour")
+                    .width(200),
+                    Container::new(Canvas::new(
+                        Rectangle::new(108.0, 32.0).colour(
+                            self.selected_colour
+                                .map_or(Color::TRANSPARENT, |colour| colour.into())
                         )
-                        .width(200),
-                        text::body1("You can set the font size used for this text.")
-                            .line_height(1.3)
-                            .width(Length::Fill)
-                            .size(self.selected_font_size)
-                    ]
-                    .width(Length::Fill)
-                    .spacing(8),
-                    None::<Element<Message>>,
-                    None::<Element<Message>>,
-                ),
-            ],
-        )
+                    ))
+                    .center_x(Length::Fill)
+                    .height(32)
+                ]
+                .width(200)
+                .spacing(8),
+                // #endregion
+                None::<Element<Message>>,
+                None::<Element<Message>>,
+                Some(source::combo_box::PICK_LIST),
+            ),
+            widget_example(
+                "An editable ComboBox.",
+                // #region editable
+                column![
+                    text::body1("Font Size"),
+                    combo_box::standard(
+                        &self.font_sizes,
+                        "",
+                        Some(&self.selected_font_size),
+                        Message::FontSizeSelected
+                    )
+                    .width(200),
+                    text::body1("You can set the font size used for this text.")
+                        .line_height(1.3)
+                        .width(Length::Fill)
+                        .size(self.selected_font_size)
+                ]
+                .width(Length::Fill)
+                .spacing(8),
+                // #endregion
+                None::<Element<Message>>,
+                None::<Element<Message>>,
+                Some(source::combo_box::EDITABLE),
+            ),
+        ])
     }
 }
 
@@ -1,5 +1,7 @@
-use crate::{
-    page::{page, widget_example},
+use crate::page::{page, source, widget_example, GalleryPage, Group};
+
+use fluent_iced::{
+    fluent_icon::FluentIcon,
     widget::{button, checkbox, dialog, text, Element},
 };
 
@@ -12,12 +14,6 @@ pub struct Dialog {
     dialog_open: bool,
 }
 
-impl Dialog {
-    pub fn is_dialog_open(&self) -> bool {
-        self.dialog_open
-    }
-}
-
 #[derive(Clone, Debug)]
 pub enum Message {
     Opened,
@@ -35,38 +31,11 @@ pub enum Action {
 }
 
 impl Dialog {
-    pub fn update(&mut self, message: Message) {
-        match message {
-            Message::Opened => self.dialog_open = true,
-            Message::Toggled(toggled) => self.is_checked = toggled,
-            Message::Action(action) => {
-                self.action = action;
-                self.dialog_open = false;
-            }
-        }
-    }
-
-    pub fn view(&self) -> Element<Message> {
-        page(
-            "Dialog",
-            [widget_example(
-                "A basic dialog with content.",
-                button::standard(text::body1("Show dialog")).on_press(Message::Opened),
-                Some(text::body1(match self.action {
-                    Action::None => "",
-                    Action::Saved => "User saved their work",
-                    Action::NotSaved => "User did not save their work",
-                    Action::Cancelled => "User cancelled the dialog",
-                })),
-                None::<Element<Message>>,
-            )],
-        )
-    }
-
-    pub fn dialog(&self) -> Element<'static, Message>
+    fn dialog(&self) -> Element<'static, Message>
     where
         Message: Clone,
     {
+        // #region basic
         dialog::content(
             Some("Save your work?"),
             column![
@@ -88,6 +57,46 @@ impl Dialog {
                 on_press: Message::Action(Action::Cancelled),
             },
         )
+        // #endregion
         .into()
     }
 }
+
+impl GalleryPage for Dialog {
+    type Message = Message;
+
+    const TITLE: &'static str = "Dialog";
+    const ICON: FluentIcon = FluentIcon::Message;
+    const GROUP: Group = Group::DialogsAndFlyouts;
+    const KEYWORDS: &'static [&'static str] = &["content dialog", "modal", "popup", "prompt"];
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Opened => self.dialog_open = true,
+            Message::Toggled(toggled) => self.is_checked = toggled,
+            Message::Action(action) => {
+                self.action = action;
+                self.dialog_open = false;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        page([widget_example(
+            "A basic dialog with content.",
+            button::standard(text::body1("Show dialog")).on_press(Message::Opened),
+            Some(text::body1(match self.action {
+                Action::None => "",
+                Action::Saved => "User saved their work",
+                Action::NotSaved => "User did not save their work",
+                Action::Cancelled => "User cancelled the dialog",
+            })),
+            None::<Element<Message>>,
+            Some(source::dialog::BASIC),
+        )])
+    }
+
+    fn modal(&self) -> Option<Element<'_, Message>> {
+        self.dialog_open.then(|| self.dialog())
+    }
+}
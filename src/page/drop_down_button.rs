@@ -1,6 +1,7 @@
-use crate::{
+use crate::page::{page, source, widget_example, GalleryPage, Group};
+
+use fluent_iced::{
     fluent_icon::FluentIcon,
-    page::{page, widget_example},
     style,
     widget::{button, text, Column, Container, DropDown, Element},
 };
@@ -25,7 +26,26 @@ pub enum Message {
 }
 
 impl DropDownButton {
-    pub fn update(&mut self, message: Message) {
+    fn is_open(&self, button: OpenButton) -> bool {
+        match &self.current_open {
+            Some(open_button) => *open_button == button,
+            None => false,
+        }
+    }
+}
+
+impl GalleryPage for DropDownButton {
+    type Message = Message;
+
+    const TITLE: &'static str = "Drop Down Button";
+    const ICON: FluentIcon = FluentIcon::ChevronDown;
+    const GROUP: Group = Group::BasicInput;
+    const SUBGROUPS: &'static [&'static str] = &["Buttons"];
+    const KEYWORDS: &'static [&'static str] = &["menu", "flyout", "dropdown"];
+    // Which flyout is open is the only state, and clicking anywhere else closes it
+    const RESETTABLE: bool = false;
+
+    fn update(&mut self, message: Message) {
         match message {
             Message::DropDownPressed(button) => match self.current_open {
                 None => self.current_open = Some(button),
@@ -35,7 +55,8 @@ impl DropDownButton {
         }
     }
 
-    pub fn view(&self) -> Element<'static, Message> {
+    fn view(&self) -> Element<'static, Message> {
+        // #region simple
         let simple_underlay = underlay(OpenButton::Simple, text::body1("Email"));
         let simple_overlay = overlay([
             simple_button("Send"),
@@ -50,10 +71,12 @@ impl DropDownButton {
         )
         .width(Length::Shrink)
         .on_dismiss(Message::DropDownDismissed);
+        // #endregion
 
+        // #region icons
         let icon_underlay = underlay(OpenButton::Icon, text::icon(FluentIcon::Mail.codepoint()));
         let icon_overlay = overlay([
-            icon_button(FluentIcon::SendFluentIconll, "Send"),
+            icon_button(FluentIcon::SendFill, "Send"),
             icon_button(FluentIcon::MailReply, "Reply"),
             icon_button(FluentIcon::MailReplyAll, "Reply All"),
         ])
@@ -62,31 +85,24 @@ impl DropDownButton {
             DropDown::new(icon_underlay, icon_overlay, self.is_open(OpenButton::Icon))
                 .width(Length::Shrink)
                 .on_dismiss(Message::DropDownDismissed);
+        // #endregion
 
-        page(
-            "Drop Down Button",
-            [
-                widget_example(
-                    "A simple Drop Down Button.",
-                    row![simple_drop_down],
-                    None::<Element<Message>>,
-                    None::<Element<Message>>,
-                ),
-                widget_example(
-                    "Drop Down Button with Icons",
-                    icon_drop_down,
-                    None::<Element<Message>>,
-                    None::<Element<Message>>,
-                ),
-            ],
-        )
-    }
-
-    fn is_open(&self, button: OpenButton) -> bool {
-        match &self.current_open {
-            Some(open_button) => *open_button == button,
-            None => false,
-        }
+        page([
+            widget_example(
+                "A simple Drop Down Button.",
+                row![simple_drop_down],
+                None::<Element<Message>>,
+                None::<Element<Message>>,
+                Some(source::drop_down_button::SIMPLE),
+            ),
+            widget_example(
+                "Drop Down Button with Icons",
+                icon_drop_down,
+                None::<Element<Message>>,
+                None::<Element<Message>>,
+                Some(source::drop_down_button::ICONS),
+            ),
+        ])
     }
 }
 
@@ -102,13 +118,13 @@ fn overlay<'a>(items: impl IntoIterator<Item = Element<'a, Message>>) -> Contain
         .style(style::container::overlay)
 }
 
-fn simple_button(label: &str) -> Element<Message> {
+fn simple_button(label: &str) -> Element<'_, Message> {
     button::menu_labelled(label)
         .on_press(Message::DropDownDismissed)
         .into()
 }
 
-fn icon_button(icon: FluentIcon, label: &str) -> Element<Message> {
+fn icon_button(icon: FluentIcon, label: &str) -> Element<'_, Message> {
     button::menu_icon(label, icon)
         .on_press(Message::DropDownDismissed)
         .into()
@@ -0,0 +1,428 @@
+use crate::page::{page, GalleryPage, Group};
+
+use fluent_iced::{
+    fluent_icon::FluentIcon,
+    font, style,
+    theme::Theme,
+    widget::{button, scrollable, text, text_input, Button, Column, Container, Element, Row},
+};
+
+use iced::{
+    alignment::{Horizontal, Vertical},
+    widget::{
+        button::Style,
+        column, container, responsive, row,
+        scrollable::{self as iced_scrollable, AbsoluteOffset, Viewport},
+        text::{self as iced_text, Wrapping},
+        vertical_space,
+    },
+    Border, Font, Length, Padding,
+};
+
+// Based on https://github.com/microsoft/WinUI-Gallery/blob/main/WinUIGallery/Samples/ControlPages/DesignGuidance/IconographyPage.xaml
+
+const TILE_WIDTH: f32 = 96.0;
+const TILE_HEIGHT: f32 = 84.0;
+const TILE_SPACING: f32 = 4.0;
+const PREVIEW_SIZES: [u16; 4] = [16, 24, 32, 48];
+
+#[derive(Clone, Debug)]
+pub struct Iconography {
+    query: String,
+    icons: Vec<FluentIcon>,
+    selected: Option<FluentIcon>,
+    scroll_offset: f32,
+}
+
+impl Default for Iconography {
+    fn default() -> Self {
+        Self {
+            query: String::new(),
+            icons: FluentIcon::ALL.to_vec(),
+            selected: None,
+            scroll_offset: 0.0,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Message {
+    QueryChanged(String),
+    TagPressed(&'static str),
+    IconSelected(FluentIcon),
+    Scrolled(Viewport),
+}
+
+impl Iconography {
+    fn filter(&mut self, query: String) {
+        let needle = query.trim().to_lowercase();
+
+        self.icons = FluentIcon::ALL
+            .into_iter()
+            .filter(|icon| {
+                needle.is_empty()
+                    || icon.name().to_lowercase().contains(&needle)
+                    || icon.tags().iter().any(|tag| tag.contains(&needle))
+            })
+            .collect();
+        self.query = query;
+
+        if self
+            .selected
+            .is_some_and(|icon| !self.icons.contains(&icon))
+        {
+            self.selected = None;
+        }
+    }
+
+    // Only the rows inside the viewport are built, the rest is replaced by spacers
+    // so scrolling through all the icons stays responsive.
+    fn grid(&self) -> Element<'_, Message> {
+        responsive(move |size| {
+            let columns = ((size.width + TILE_SPACING) / (TILE_WIDTH + TILE_SPACING))
+                .floor()
+                .max(1.0) as usize;
+            let rows = self.icons.len().div_ceil(columns);
+            let row_height = TILE_HEIGHT + TILE_SPACING;
+
+            // The scrollable clamps its offset to the content so do the same here
+            let content_height = rows as f32 * row_height;
+            let offset = self
+                .scroll_offset
+                .min((content_height - size.height).max(0.0));
+
+            let first = (offset / row_height).floor() as usize;
+            let last = (first + (size.height / row_height).ceil() as usize + 1).min(rows);
+
+            let visible_rows = self
+                .icons
+                .chunks(columns)
+                .skip(first)
+                .take(last.saturating_sub(first))
+                .map(|icons| {
+                    Row::with_children(icons.iter().map(|icon| self.tile(*icon)))
+                        .spacing(TILE_SPACING)
+                        .into()
+                });
+
+            let grid = Column::new()
+                .push(vertical_space().height(first as f32 * row_height))
+                .extend(visible_rows)
+                .push(vertical_space().height((rows - last.max(first)) as f32 * row_height))
+                .spacing(0)
+                .width(Length::Fill);
+
+            scrollable::standard(grid)
+                .id(grid_id())
+                .height(Length::Fill)
+                .on_scroll(Message::Scrolled)
+                .into()
+        })
+        .into()
+    }
+
+    fn tile(&self, icon: FluentIcon) -> Element<'_, Message> {
+        let selected = self.selected == Some(icon);
+
+        Container::new(
+            Button::new(
+                column![
+                    Container::new(glyph(icon, 24))
+                        .height(32)
+                        .align_y(Vertical::Center),
+                    text::caption1(icon.name()).wrapping(Wrapping::None),
+                ]
+                .width(Length::Fill)
+                .spacing(12)
+                .align_x(Horizontal::Center),
+            )
+            .width(TILE_WIDTH)
+            .height(TILE_HEIGHT)
+            .padding([16, 4])
+            .on_press(Message::IconSelected(icon))
+            .style(move |theme, status| {
+                let base = style::button::secondary(theme, status);
+
+                if selected {
+                    Style {
+                        border: Border {
+                            color: theme.palette().accent_fill_color_default,
+                            width: 2.0,
+                            ..base.border
+                        },
+                        ..base
+                    }
+                } else {
+                    base
+                }
+            }),
+        )
+        .padding(Padding::ZERO.bottom(TILE_SPACING))
+        .clip(true)
+        .into()
+    }
+
+    fn details(&self) -> Element<'_, Message> {
+        let content: Element<Message> = match self.selected {
+            None => text::body1("Select an icon to see its details.").into(),
+            Some(icon) => {
+                let previews: Element<Message> = if font::has_glyph(icon) {
+                    Row::with_children(PREVIEW_SIZES.map(|size| {
+                        column![
+                            text::icon(icon.codepoint()).size(size),
+                            text::caption1(format!("{size}px")),
+                        ]
+                        .spacing(4)
+                        .align_x(Horizontal::Center)
+                        .into()
+                    }))
+                    .spacing(16)
+                    .align_y(Vertical::Bottom)
+                    .into()
+                } else {
+                    text::body1(format!(
+                        "{} has no glyph for this icon.",
+                        font::active().icons.name()
+                    ))
+                    .into()
+                };
+
+                let tags = Row::with_children(icon.tags().iter().map(|tag| {
+                    button::standard(text::caption1(*tag))
+                        .height(24)
+                        .padding([2, 8])
+                        .on_press(Message::TagPressed(tag))
+                        .into()
+                }))
+                .spacing(4)
+                .wrap();
+
+                column![
+                    text::subtitle1(icon.name()),
+                    detail("Codepoint", format!("U+{:04X}", icon.codepoint() as u32)),
+                    detail("Rust", format!("FluentIcon::{icon:?}.codepoint()")),
+                    column![text::bold("Sizes"), previews].spacing(8),
+                    column![text::bold("Tags"), tags].spacing(8),
+                ]
+                .spacing(20)
+                .into()
+            }
+        };
+
+        Container::new(content)
+            .width(300)
+            .height(Length::Fill)
+            .padding(16)
+            .style(style::container::card)
+            .into()
+    }
+}
+
+impl GalleryPage for Iconography {
+    type Message = Message;
+
+    const TITLE: &'static str = "Iconography";
+    const ICON: FluentIcon = FluentIcon::Emoji2;
+    const GROUP: Group = Group::DesignGuidance;
+    const KEYWORDS: &'static [&'static str] = &["icon", "glyph", "symbol", "font"];
+    const SCROLLABLE: bool = false;
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::QueryChanged(query) => self.filter(query),
+            Message::TagPressed(tag) => self.filter(tag.to_owned()),
+            Message::IconSelected(icon) => self.selected = Some(icon),
+            Message::Scrolled(viewport) => self.scroll_offset = viewport.absolute_offset().y,
+        }
+    }
+
+    fn scrollable(&self) -> Option<(iced_scrollable::Id, AbsoluteOffset)> {
+        Some((
+            grid_id(),
+            AbsoluteOffset {
+                x: 0.0,
+                y: self.scroll_offset,
+            },
+        ))
+    }
+
+    // The visible rows are picked by the offset, which has to match the scrollable
+    fn scrolled_to(&mut self, offset: AbsoluteOffset) {
+        self.scroll_offset = offset.y;
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let search = text_input::underline(
+            text_input::standard("Search icons by name or tag", &self.query)
+                .width(320)
+                .on_input(Message::QueryChanged),
+        );
+
+        let missing = self
+            .icons
+            .iter()
+            .filter(|icon| !font::has_glyph(**icon))
+            .count();
+
+        let count = text::caption1(if missing == 0 {
+            format!("{} of {} icons", self.icons.len(), FluentIcon::ALL.len())
+        } else {
+            format!(
+                "{} of {} icons, {missing} without a glyph in {}",
+                self.icons.len(),
+                FluentIcon::ALL.len(),
+                font::active().icons.name()
+            )
+        });
+
+        let content = row![self.grid(), self.details()]
+            .height(Length::Fill)
+            .spacing(24);
+
+        page([column![search, count].spacing(8).into(), content.into()])
+    }
+}
+
+fn grid_id() -> iced_scrollable::Id {
+    iced_scrollable::Id::new("iconography_grid")
+}
+
+// Icons the icon font can't draw are marked instead of showing a box
+fn glyph<'a, Message: 'a>(icon: FluentIcon, size: u16) -> Element<'a, Message> {
+    if font::has_glyph(icon) {
+        text::icon(icon.codepoint()).size(size).into()
+    } else {
+        text::caption1("No glyph")
+            .style(|theme: &Theme| iced_text::Style {
+                color: Some(theme.palette().text_fill_color_tertiary),
+            })
+            .into()
+    }
+}
+
+fn detail<'a, Message: 'a>(label: &'a str, value: String) -> Element<'a, Message> {
+    column![
+        text::bold(label),
+        Container::new(text::body1(value).font(Font::MONOSPACE))
+            .width(Length::Fill)
+            .padding([6, 10])
+            .style(|theme| {
+                let palette = theme.palette();
+                container::Style::default()
+                    .background(palette.control_fill_color_default)
+                    .border(Border {
+                        color: palette.control_stroke_color_default,
+                        width: 1.0,
+                        radius: 4.0.into(),
+                    })
+            }),
+    ]
+    .spacing(8)
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::gallery::Page;
+    use crate::settings::Settings;
+    use crate::simulator::Simulator;
+
+    use iced::{
+        keyboard::{key::Named, Key, Modifiers},
+        mouse::{self, ScrollDelta},
+        Event,
+    };
+
+    fn simulator() -> Simulator {
+        Simulator::new(Settings {
+            page: Page::Sample(Iconography::TITLE),
+            ..Settings::default()
+        })
+    }
+
+    // Selects the first icon, to have the cursor over the grid, and scrolls down
+    fn scroll_grid(simulator: &mut Simulator) {
+        let tile = simulator.find(FluentIcon::ALL[0].name())[0];
+        simulator.click_at(tile.center());
+
+        simulator.dispatch(&[Event::Mouse(mouse::Event::WheelScrolled {
+            delta: ScrollDelta::Lines { x: 0.0, y: -20.0 },
+        })]);
+    }
+
+    // The selected first icon is left out as its name is shown in the details too
+    fn first_visible(simulator: &mut Simulator) -> Option<FluentIcon> {
+        FluentIcon::ALL[1..]
+            .iter()
+            .copied()
+            .find(|icon| !simulator.find(icon.name()).is_empty())
+    }
+
+    fn scroll_offset(simulator: &Simulator) -> f32 {
+        simulator
+            .gallery()
+            .page_state::<Iconography>()
+            .expect("page was opened")
+            .scroll_offset
+    }
+
+    #[test]
+    fn grid_is_at_the_top_when_navigating_to_the_page() {
+        let mut simulator = simulator();
+        scroll_grid(&mut simulator);
+        assert_ne!(first_visible(&mut simulator), Some(FluentIcon::ALL[1]));
+
+        simulator.click("Settings").unwrap();
+        simulator.click("Design guidance").unwrap();
+        simulator.click("Iconography").unwrap();
+
+        assert_eq!(scroll_offset(&simulator), 0.0);
+        assert_eq!(first_visible(&mut simulator), Some(FluentIcon::ALL[1]));
+    }
+
+    #[test]
+    fn grid_offset_is_restored_when_going_back() {
+        let mut simulator = simulator();
+        scroll_grid(&mut simulator);
+
+        let offset = scroll_offset(&simulator);
+        let visible = first_visible(&mut simulator);
+        assert!(offset > 0.0 && visible.is_some());
+
+        simulator.click("Settings").unwrap();
+        simulator.press_key(Key::Named(Named::ArrowLeft), Modifiers::ALT);
+
+        assert_eq!(scroll_offset(&simulator), offset);
+        assert_eq!(first_visible(&mut simulator), visible);
+    }
+
+    #[test]
+    fn icons_without_a_glyph_are_marked() {
+        let search = |query: &str| {
+            let mut simulator = simulator();
+            simulator.click("Search icons by name or tag").unwrap();
+            simulator.type_text(query);
+            simulator
+        };
+
+        let mut simulator = search(FluentIcon::StaplingLandscapeBookBinding.name());
+
+        assert_eq!(simulator.find("No glyph").len(), 1);
+        assert_eq!(
+            simulator
+                .find(&format!(
+                    "1 of {} icons, 1 without a glyph in {}",
+                    FluentIcon::ALL.len(),
+                    font::FLUENT_SYSTEM_ICONS.name
+                ))
+                .len(),
+            1
+        );
+
+        let mut simulator = search(FluentIcon::Home.name());
+
+        assert!(simulator.find("No glyph").is_empty());
+    }
+}
@@ -1,5 +1,7 @@
-use crate::{
-    page::{page, widget_example},
+use crate::page::{page, source, widget_example, GalleryPage, Group};
+
+use fluent_iced::{
+    fluent_icon::FluentIcon,
     widget::{radio, text, Element},
 };
 
@@ -18,73 +20,83 @@ pub enum Message {
     ContentFitChanged(ContentFit),
 }
 
-impl Image {
-    pub fn update(&mut self, message: Message) {
+impl GalleryPage for Image {
+    type Message = Message;
+
+    const TITLE: &'static str = "Image";
+    const ICON: FluentIcon = FluentIcon::Picture;
+    const GROUP: Group = Group::Media;
+    const KEYWORDS: &'static [&'static str] = &["picture", "photo", "bitmap", "stretch"];
+
+    fn update(&mut self, message: Message) {
         match message {
             Message::ContentFitChanged(content_fit) => self.content_fit = content_fit,
         }
     }
 
-    pub fn view(&self) -> Element<Message> {
-        page(
-            "Image",
-            [
-                widget_example(
-                    "A basic image from a local file.",
-                    image(format! {
-                        "{}/assets/images/treetops.jpg", env!("CARGO_MANIFEST_DIR")
-                    })
-                    .height(100),
-                    None::<Element<Message>>,
-                    None::<Element<Message>>,
-                ),
-                widget_example(
-                    "Image stretching.",
-                    image(format! {
-                        "{}/assets/images/valley.jpg", env!("CARGO_MANIFEST_DIR")
-                    })
-                    .width(100)
-                    .height(100)
-                    .content_fit(self.content_fit),
-                    None::<Element<Message>>,
-                    Some(
-                        column![
-                            text::body1("Image content fit"),
-                            radio::standard(
-                                "Contain",
-                                ContentFit::Contain,
-                                Some(self.content_fit),
-                                Message::ContentFitChanged
-                            ),
-                            radio::standard(
-                                "Cover",
-                                ContentFit::Cover,
-                                Some(self.content_fit),
-                                Message::ContentFitChanged
-                            ),
-                            radio::standard(
-                                "Fill",
-                                ContentFit::Fill,
-                                Some(self.content_fit),
-                                Message::ContentFitChanged
-                            ),
-                            radio::standard(
-                                "None",
-                                ContentFit::None,
-                                Some(self.content_fit),
-                                Message::ContentFitChanged
-                            ),
-                            radio::standard(
-                                "ScaleDown",
-                                ContentFit::ScaleDown,
-                                Some(self.content_fit),
-                                Message::ContentFitChanged
-                            ),
-                        ]
-                        .spacing(16),
-                    ),
+    fn view(&self) -> Element<'_, Message> {
+        page([
+            widget_example(
+                "A basic image from a local file.",
+                // #region local_file
+                image(format! {
+                    "{}/assets/images/treetops.jpg", env!("CARGO_MANIFEST_DIR")
+                })
+                .height(100),
+                // #endregion
+                None::<Element<Message>>,
+                None::<Element<Message>>,
+                Some(source::image::LOCAL_FILE),
+            ),
+            widget_example(
+                "Image stretching.",
+                // #region stretching
+                image(format! {
+                    "{}/assets/images/valley.jpg", env!("CARGO_MANIFEST_DIR")
+                })
+                .width(100)
+                .height(100)
+                .content_fit(self.content_fit),
+                // #endregion
+                None::<Element<Message>>,
+                Some(
+                    column![
+                        text::body1("Image content fit"),
+                        radio::standard(
+                            "Contain",
+                            ContentFit::Contain,
+                            Some(self.content_fit),
+                            Message::ContentFitChanged
+                        ),
+                        radio::standard(
+                            "Cover",
+                            ContentFit::Cover,
+                            Some(self.content_fit),
+                            Message::ContentFitChanged
+                        ),
+                        radio::standard(
+                            "Fill",
+                            ContentFit::Fill,
+                            Some(self.content_fit),
+                            Message::ContentFitChanged
+                        ),
+                        radio::standard(
+                            "None",
+                            ContentFit::None,
+                            Some(self.content_fit),
+                            Message::ContentFitChanged
+                        ),
+                        radio::standard(
+                            "ScaleDown",
+                            ContentFit::ScaleDown,
+                            Some(self.content_fit),
+                            Message::ContentFitChanged
+                        ),
+                    ]
+                    .spacing(16),
                 ),
-            ],
-        )
+                Some(source::image::STRETCHING),
+            ),
+        ])
     }
 }
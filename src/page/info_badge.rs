@@ -0,0 +1,210 @@
+use crate::page::{page, source, widget_example, GalleryPage, Group};
+
+use fluent_iced::{
+    fluent_icon::FluentIcon,
+    widget::{
+        info_badge::{self, Severity},
+        radio, ribbon, side_nav, slider, text, Container, Element, Row,
+    },
+};
+
+use iced::{
+    alignment::Vertical,
+    widget::{column, row},
+};
+
+#[derive(Clone, Debug)]
+pub struct InfoBadge {
+    value: u32,
+    severity: Severity,
+    nav_display_mode: side_nav::DisplayMode,
+    folders_expanded: bool,
+}
+
+#[derive(Clone, Debug)]
+pub enum Message {
+    ValueChanged(u32),
+    SeveritySelected(Severity),
+    NavButtonPressed,
+    FoldersToggled,
+    FoldersDismissed,
+    // Navigation items and ribbon buttons only show their badges
+    Pressed,
+}
+
+impl Default for InfoBadge {
+    fn default() -> Self {
+        Self {
+            value: 8,
+            severity: Severity::default(),
+            nav_display_mode: side_nav::DisplayMode::Full,
+            folders_expanded: true,
+        }
+    }
+}
+
+impl InfoBadge {
+    fn styles(&self) -> Element<'_, Message> {
+        // #region styles
+        // The icons of the icon styles of WinUI
+        fn severity_icon(severity: Severity) -> FluentIcon {
+            match severity {
+                Severity::Attention => FluentIcon::Important,
+                Severity::Informational => FluentIcon::Info,
+                Severity::Success => FluentIcon::CheckMark,
+                Severity::Critical => FluentIcon::Cancel,
+            }
+        }
+
+        let styles = |label: &'static str, badge: fn(Severity) -> Element<'static, Message>| {
+            row![text::body1(label).width(60)]
+                .extend(Severity::ALL.map(badge))
+                .spacing(24)
+                .align_y(Vertical::Center)
+        };
+
+        column![
+            styles("Dot", |severity| info_badge::dot(severity).into()),
+            styles("Icon", |severity| {
+                info_badge::icon(severity_icon(severity), severity).into()
+            }),
+            styles("Number", |severity| info_badge::number(1, severity).into()),
+        ]
+        .spacing(16)
+        .into()
+        // #endregion
+    }
+
+    fn navigation(&self) -> Element<'_, Message> {
+        // #region navigation
+        let folders = side_nav::Group::new(
+            FluentIcon::Folder.codepoint(),
+            "Folders",
+            Message::FoldersToggled,
+        )
+        .with_items([
+            side_nav::NavNode::new(
+                Some(FluentIcon::Mail.codepoint()),
+                "Inbox",
+                Message::Pressed,
+            )
+            .badge(info_badge::number(self.value, self.severity)),
+            side_nav::NavNode::new(Some(FluentIcon::Send.codepoint()), "Sent", Message::Pressed),
+        ])
+        .badge(info_badge::dot(self.severity))
+        .expanded(self.folders_expanded)
+        .overlay_width(160)
+        .on_overlay_dismiss(Message::FoldersDismissed);
+
+        let side_nav = side_nav::SideNav::new(self.nav_display_mode, Message::NavButtonPressed)
+            .with_groups([
+                side_nav::Group::new(
+                    FluentIcon::Calendar.codepoint(),
+                    "Calendar",
+                    Message::Pressed,
+                )
+                .badge(info_badge::icon(FluentIcon::Important, self.severity)),
+                folders,
+            ]);
+
+        Container::new(side_nav).height(240).into()
+        // #endregion
+    }
+
+    fn ribbon(&self) -> Element<'_, Message> {
+        // #region ribbon
+        row![
+            info_badge::attach(
+                ribbon::button::large("Mail", FluentIcon::Mail)
+                    .width(56)
+                    .on_press(Message::Pressed),
+                info_badge::number(self.value, self.severity),
+            ),
+            info_badge::attach(
+                ribbon::button::large("Sync", FluentIcon::Sync)
+                    .width(56)
+                    .on_press(Message::Pressed),
+                info_badge::dot(self.severity),
+            ),
+            info_badge::attach(
+                ribbon::button::medium("Alerts", FluentIcon::Ringer).on_press(Message::Pressed),
+                info_badge::dot(self.severity),
+            ),
+        ]
+        .spacing(4)
+        .align_y(Vertical::Center)
+        .into()
+        // #endregion
+    }
+
+    fn options(&self) -> Element<'_, Message> {
+        column![
+            text::body1(format!("Value: {}", self.value)),
+            slider::standard(0..=120, self.value, Message::ValueChanged).width(200),
+            text::body1("Severity"),
+        ]
+        .extend(Severity::ALL.map(|severity| {
+            radio::standard(
+                severity.to_string(),
+                severity,
+                Some(self.severity),
+                Message::SeveritySelected,
+            )
+            .into()
+        }))
+        .spacing(12)
+        .into()
+    }
+}
+
+impl GalleryPage for InfoBadge {
+    type Message = Message;
+
+    const TITLE: &'static str = "Info Badge";
+    const ICON: FluentIcon = FluentIcon::Ringer;
+    const GROUP: Group = Group::StatusAndInfo;
+    const KEYWORDS: &'static [&'static str] = &["badge", "notification", "count", "dot", "status"];
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::ValueChanged(value) => self.value = value,
+            Message::SeveritySelected(severity) => self.severity = severity,
+            Message::NavButtonPressed => {
+                self.nav_display_mode = match self.nav_display_mode {
+                    side_nav::DisplayMode::Full => side_nav::DisplayMode::Compact,
+                    _ => side_nav::DisplayMode::Full,
+                };
+                self.folders_expanded = false;
+            }
+            Message::FoldersToggled => self.folders_expanded = !self.folders_expanded,
+            Message::FoldersDismissed => self.folders_expanded = false,
+            Message::Pressed => (),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        page([
+            widget_example(
+                "Dot, icon and number InfoBadges in each severity.",
+                self.styles(),
+                None::<Element<Message>>,
+                None::<Element<Message>>,
+                Some(source::info_badge::STYLES),
+            ),
+            widget_example(
+                "InfoBadges in a navigation pane.",
+                Row::new().push(self.navigation()),
+                None::<Element<Message>>,
+                Some(self.options()),
+                Some(source::info_badge::NAVIGATION),
+            ),
+            widget_example(
+                "InfoBadges on ribbon buttons.",
+                self.ribbon(),
+                None::<Element<Message>>,
+                None::<Element<Message>>,
+                Some(source::info_badge::RIBBON),
+            ),
+        ])
+    }
+}
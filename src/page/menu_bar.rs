@@ -1,5 +1,7 @@
-use crate::{
-    page::{page, widget_example},
+use crate::page::{page, source, widget_example, GalleryPage, Group};
+
+use fluent_iced::{
+    fluent_icon::FluentIcon,
     style,
     widget::{button, menu, text, Element, Menu, MenuItem},
 };
@@ -99,8 +101,16 @@ pub enum Message {
     MenuBar3Selected(Action),
 }
 
-impl MenuBar {
-    pub fn update(&mut self, message: Message) {
+impl GalleryPage for MenuBar {
+    type Message = Message;
+
+    const TITLE: &'static str = "MenuBar";
+    const ICON: FluentIcon = FluentIcon::GlobalNavButton;
+    const GROUP: Group = Group::MenuAndToolbars;
+    const KEYWORDS: &'static [&'static str] =
+        &["menu", "shortcut", "keyboard accelerator", "command"];
+
+    fn update(&mut self, message: Message) {
         match message {
             Message::NoOp => (),
             Message::MenuBar1Selected(action) => self.menu_bar1_action = action,
@@ -120,7 +130,7 @@ impl MenuBar {
         }
     }
 
-    pub fn subscription(&self) -> Subscription<Message> {
+    fn subscription(&self) -> Subscription<Message> {
         fn action_msg(a: Action) -> Option<Message> {
             Some(Message::MenuBar2Selected(a))
         }
@@ -147,158 +157,152 @@ impl MenuBar {
         keyboard::on_key_press(handle_keys)
     }
 
-    pub fn view(&self) -> Element<Message> {
-        page(
-            "MenuBar",
-            [
-                widget_example(
-                    "A simple MenuBar.",
-                    menu::bar::standard(vec![
-                        menu_bar_item(
-                            "File",
-                            menu::standard(vec![
-                                item1(Action::New),
-                                item1(Action::Open),
-                                item1(Action::Save),
-                                item1(Action::Exit),
-                            ]),
-                        ),
-                        menu_bar_item(
-                            "Edit",
-                            menu::standard(vec![
-                                item1(Action::Undo),
-                                item1(Action::Cut),
-                                item1(Action::Copy),
-                                item1(Action::Paste),
-                            ]),
-                        ),
-                        menu_bar_item("Help", menu::standard(vec![item1(Action::About)])),
-                    ])
-                    .spacing(4.0),
-                    Some(text::body1(match self.menu_bar1_action {
-                        Action::None => String::from(""),
-                        _ => format!("You clicked: {}", self.menu_bar1_action),
-                    })),
-                    None::<Element<Message>>,
-                ),
-                widget_example(
-                    "A MenuBar with keyboard accelerators.",
-                    menu::bar::standard(vec![
-                        menu_bar_item(
-                            "File",
-                            menu::standard(vec![
-                                item2(Action::New, "Ctrl+N"),
-                                item2(Action::Open, "Ctrl+O"),
-                                item2(Action::Save, "Ctrl+S"),
-                                item2(Action::Exit, "Ctrl+E"),
-                            ])
-                            .width(120),
-                        ),
-                        menu_bar_item(
-                            "Edit",
-                            menu::standard(vec![
-                                item2(Action::Undo, "Ctrl+Z"),
-                                item2(Action::Cut, "Ctrl+X"),
-                                item2(Action::Copy, "Ctrl+C"),
-                                item2(Action::Paste, "Ctrl+V"),
-                            ])
-                            .width(120),
-                        ),
-                        menu_bar_item(
-                            "Help",
-                            menu::standard(vec![item2(Action::About, "Ctrl+I")]).width(120),
-                        ),
-                    ])
-                    .spacing(4.0),
-                    Some(text::body1(match self.menu_bar2_action {
-                        Action::None => String::from(""),
-                        _ => format!("You clicked: {}", self.menu_bar2_action),
-                    })),
-                    None::<Element<Message>>,
-                ),
-                widget_example(
-                    "MenuBar with submenus, separators, and radio items.",
-                    menu::bar::standard(vec![
-                        menu_bar_item(
-                            "File",
-                            menu::standard(vec![
-                                menu::item::submenu(
-                                    "New",
-                                    false,
-                                    menu::standard(vec![
-                                        item3(Action::PlainTextDocument),
-                                        item3(Action::RichTextDocument),
-                                        item3(Action::OtherFormats),
-                                    ])
-                                    .width(160)
-                                    .offset(-2.0),
-                                ),
-                                item3(Action::Open),
-                                item3(Action::Save),
-                                menu::item::separator(),
-                                item3(Action::Exit),
-                            ]),
-                        ),
-                        menu_bar_item(
-                            "Edit",
-                            menu::standard(vec![
-                                item3(Action::Undo),
-                                item3(Action::Cut),
-                                item3(Action::Copy),
-                                item3(Action::Paste),
-                            ])
-                            .width(120),
-                        ),
-                        menu_bar_item(
-                            "View",
-                            menu::standard(vec![
-                                menu::item::labelled(
-                                    Action::Output.name(),
-                                    true,
-                                    None,
-                                    Message::MenuBar3Selected(Action::Output),
-                                ),
-                                menu::item::separator(),
-                                item3_radio(
-                                    Action::Landscape,
-                                    Orientation::Landscape,
-                                    Some(self.orientation),
-                                ),
-                                item3_radio(
-                                    Action::Portait,
-                                    Orientation::Portait,
-                                    Some(self.orientation),
-                                ),
-                                menu::item::separator(),
-                                item3_radio(
-                                    Action::SmallIcon,
-                                    IconSize::Small,
-                                    Some(self.icon_size),
-                                ),
-                                item3_radio(
-                                    Action::MediumIcon,
-                                    IconSize::Medium,
-                                    Some(self.icon_size),
-                                ),
-                                item3_radio(
-                                    Action::LargeIcon,
-                                    IconSize::Large,
-                                    Some(self.icon_size),
-                                ),
-                            ])
-                            .width(150),
-                        ),
-                        menu_bar_item("Help", menu::standard(vec![item3(Action::About)])),
-                    ])
-                    .spacing(4.0),
-                    Some(text::body1(match self.menu_bar3_action {
-                        Action::None => String::from(""),
-                        _ => format!("You clicked: {}", self.menu_bar3_action),
-                    })),
-                    None::<Element<Message>>,
-                ),
-            ],
-        )
+    fn view(&self) -> Element<'_, Message> {
+        page([
+            widget_example(
+                "A simple MenuBar.",
+                // #region simple
+                menu::bar::standard(vec![
+                    menu_bar_item(
+                        "File",
+                        menu::standard(vec![
+                            item1(Action::New),
+                            item1(Action::Open),
+                            item1(Action::Save),
+                            item1(Action::Exit),
+                        ]),
+                    ),
+                    menu_bar_item(
+                        "Edit",
+                        menu::standard(vec![
+                            item1(Action::Undo),
+                            item1(Action::Cut),
+                            item1(Action::Copy),
+                            item1(Action::Paste),
+                        ]),
+                    ),
+                    menu_bar_item("Help", menu::standard(vec![item1(Action::About)])),
+                ])
+                .spacing(4.0),
+                // #endregion
+                Some(text::body1(match self.menu_bar1_action {
+                    Action::None => String::from(""),
+                    _ => format!("You clicked: {}", self.menu_bar1_action),
+                })),
+                None::<Element<Message>>,
+                Some(source::menu_bar::SIMPLE),
+            ),
+            widget_example(
+                "A MenuBar with keyboard accelerators.",
+                // #region accelerators
+                menu::bar::standard(vec![
+                    menu_bar_item(
+                        "File",
+                        menu::standard(vec![
+                            item2(Action::New, "Ctrl+N"),
+                            item2(Action::Open, "Ctrl+O"),
+                            item2(Action::Save, "Ctrl+S"),
+                            item2(Action::Exit, "Ctrl+E"),
+                        ])
+                        .width(120),
+                    ),
+                    menu_bar_item(
+                        "Edit",
+                        menu::standard(vec![
+                            item2(Action::Undo, "Ctrl+Z"),
+                            item2(Action::Cut, "Ctrl+X"),
+                            item2(Action::Copy, "Ctrl+C"),
+                            item2(Action::Paste, "Ctrl+V"),
+                        ])
+                        .width(120),
+                    ),
+                    menu_bar_item(
+                        "Help",
+                        menu::standard(vec![item2(Action::About, "Ctrl+I")]).width(120),
+                    ),
+                ])
+                .spacing(4.0),
+                // #endregion
+                Some(text::body1(match self.menu_bar2_action {
+                    Action::None => String::from(""),
+                    _ => format!("You clicked: {}", self.menu_bar2_action),
+                })),
+                None::<Element<Message>>,
+                Some(source::menu_bar::ACCELERATORS),
+            ),
+            widget_example(
+                "MenuBar with submenus, separators, and radio items.",
+                // #region submenus
+                menu::bar::standard(vec![
+                    menu_bar_item(
+                        "File",
+                        menu::standard(vec![
+                            menu::item::submenu(
+                                "New",
+                                false,
+                                menu::standard(vec![
+                                    item3(Action::PlainTextDocument),
+                                    item3(Action::RichTextDocument),
+                                    item3(Action::OtherFormats),
+                                ])
+                                .width(160)
+                                .offset(-2.0),
+                            ),
+                            item3(Action::Open),
+                            item3(Action::Save),
+                            menu::item::separator(),
+                            item3(Action::Exit),
+                        ]),
+                    ),
+                    menu_bar_item(
+                        "Edit",
+                        menu::standard(vec![
+                            item3(Action::Undo),
+                            item3(Action::Cut),
+                            item3(Action::Copy),
+                            item3(Action::Paste),
+                        ])
+                        .width(120),
+                    ),
+                    menu_bar_item(
+                        "View",
+                        menu::standard(vec![
+                            menu::item::labelled(
+                                Action::Output.name(),
+                                true,
+                                None,
+                                Message::MenuBar3Selected(Action::Output),
+                            ),
+                            menu::item::separator(),
+                            item3_radio(
+                                Action::Landscape,
+                                Orientation::Landscape,
+                                Some(self.orientation),
+                            ),
+                            item3_radio(
+                                Action::Portait,
+                                Orientation::Portait,
+                                Some(self.orientation),
+                            ),
+                            menu::item::separator(),
+                            item3_radio(Action::SmallIcon, IconSize::Small, Some(self.icon_size)),
+                            item3_radio(Action::MediumIcon, IconSize::Medium, Some(self.icon_size)),
+                            item3_radio(Action::LargeIcon, IconSize::Large, Some(self.icon_size)),
+                        ])
+                        .width(150),
+                    ),
+                    menu_bar_item("Help", menu::standard(vec![item3(Action::About)])),
+                ])
+                .spacing(4.0),
+                // #endregion
+                Some(text::body1(match self.menu_bar3_action {
+                    Action::None => String::from(""),
+                    _ => format!("You clicked: {}", self.menu_bar3_action),
+                })),
+                None::<Element<Message>>,
+                Some(source::menu_bar::SUBMENUS),
+            ),
+        ])
     }
 }
 
@@ -361,3 +365,89 @@ where
         Message::MenuBar3Selected(action),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::gallery::Page;
+    use crate::settings::Settings;
+    use crate::simulator::{Error, Simulator};
+
+    fn simulator() -> Simulator {
+        Simulator::new(Settings {
+            page: Page::Sample(MenuBar::TITLE),
+            ..Settings::default()
+        })
+    }
+
+    fn page(simulator: &Simulator) -> &MenuBar {
+        simulator
+            .gallery()
+            .page_state::<MenuBar>()
+            .expect("page was opened")
+    }
+
+    // Opens the menu `root` of the `index`th menu bar, top to bottom
+    fn open_menu(simulator: &mut Simulator, index: usize, root: &str) {
+        let roots = simulator.find(root);
+
+        simulator.click_at(roots[index].center());
+    }
+
+    #[test]
+    fn clicking_item_selects_action() {
+        let mut simulator = simulator();
+
+        open_menu(&mut simulator, 0, "File");
+        simulator.click("Open").unwrap();
+
+        assert!(matches!(page(&simulator).menu_bar1_action, Action::Open));
+        assert_eq!(simulator.find("You clicked: Open").len(), 1);
+    }
+
+    #[test]
+    fn items_are_hidden_until_menu_is_opened() {
+        let mut simulator = simulator();
+
+        assert_eq!(simulator.click("Undo"), Err(Error::NotFound("Undo".into())));
+
+        open_menu(&mut simulator, 0, "Edit");
+        simulator.click("Undo").unwrap();
+
+        assert!(matches!(page(&simulator).menu_bar1_action, Action::Undo));
+    }
+
+    #[test]
+    fn accelerator_selects_action() {
+        let mut simulator = simulator();
+
+        simulator.press_key(Key::Character("s".into()), Modifiers::CTRL);
+
+        assert!(matches!(page(&simulator).menu_bar2_action, Action::Save));
+        assert!(matches!(page(&simulator).menu_bar1_action, Action::None));
+    }
+
+    #[test]
+    fn accelerator_needs_ctrl() {
+        let mut simulator = simulator();
+
+        simulator.type_text("s");
+
+        assert!(matches!(page(&simulator).menu_bar2_action, Action::None));
+    }
+
+    #[test]
+    fn radio_item_selects_option() {
+        let mut simulator = simulator();
+
+        open_menu(&mut simulator, 0, "View");
+        simulator.click("Landscape").unwrap();
+
+        let page = page(&simulator);
+
+        assert!(matches!(page.menu_bar3_action, Action::Landscape));
+        assert_eq!(page.orientation, Orientation::Landscape);
+        assert_eq!(page.icon_size, IconSize::Medium);
+    }
+}
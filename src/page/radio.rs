@@ -1,5 +1,7 @@
-use crate::{
-    page::{page, widget_example},
+use crate::page::{page, source, widget_example, GalleryPage, Group};
+
+use fluent_iced::{
+    fluent_icon::FluentIcon,
     widget::{self, text, Element},
 };
 
@@ -22,53 +24,60 @@ pub enum Message {
     ChoiceSelected(Choice),
 }
 
-impl Radio {
-    pub fn update(&mut self, message: Message) {
+impl GalleryPage for Radio {
+    type Message = Message;
+
+    const TITLE: &'static str = "Radio";
+    const ICON: FluentIcon = FluentIcon::RadioBtnOn;
+    const GROUP: Group = Group::BasicInput;
+    const KEYWORDS: &'static [&'static str] = &["radio button", "option", "choice", "select"];
+
+    fn update(&mut self, message: Message) {
         match message {
             Message::ChoiceSelected(choice) => self.choice = Some(choice),
         }
     }
 
-    pub fn view(&self) -> Element<Message> {
-        page(
-            "Radio",
-            [widget_example(
-                "A group of Radios.",
-                column![
-                    text::body1("Options:"),
-                    widget::radio::standard(
-                        "Option 1",
-                        Choice::One,
-                        self.choice,
-                        Message::ChoiceSelected
-                    ),
-                    widget::radio::standard(
-                        "Option 2",
-                        Choice::Two,
-                        self.choice,
-                        Message::ChoiceSelected
-                    ),
-                    widget::radio::standard(
-                        "Option 3",
-                        Choice::Three,
-                        self.choice,
-                        Message::ChoiceSelected
-                    ),
-                ]
-                .spacing(20),
-                Some(text::body1(format![
-                    "Output:\n {}",
-                    match self.choice {
-                        Some(choice) => match choice {
-                            Choice::One => "You selected Option 1",
-                            Choice::Two => "You selected Option 2",
-                            Choice::Three => "You selected Option 3",
-                        },
-                        None => "Select an option.",
-                    }
-                ])),
-                None::<Element<Message>>,
-            )],
-        )
+    fn view(&self) -> Element<'_, Message> {
+        page([widget_example(
+            "A group of Radios.",
+            // #region group
+            column![
+                text::body1("Options:"),
+                widget::radio::standard(
+                    "Option 1",
+                    Choice::One,
+                    self.choice,
+                    Message::ChoiceSelected
+                ),
+                widget::radio::standard(
+                    "Option 2",
+                    Choice::Two,
+                    self.choice,
+                    Message::ChoiceSelected
+                ),
+                widget::radio::standard(
+                    "Option 3",
+                    Choice::Three,
+                    self.choice,
+                    Message::ChoiceSelected
+                ),
+            ]
+            .spacing(20),
+            // #endregion
+            Some(text::body1(format![
+                "Output:\n {}",
+                match self.choice {
+                    Some(choice) => match choice {
+                        Choice::One => "You selected Option 1",
+                        Choice::Two => "You selected Option 2",
+                        Choice::Three => "You selected Option 3",
+                    },
+                    None => "Select an option.",
+                }
+            ])),
+            None::<Element<Message>>,
+            Some(source::radio::GROUP),
+        )])
     }
 }
@@ -1,6 +1,7 @@
-use crate::{
+use crate::page::{page, source, widget_example, GalleryPage, Group};
+
+use fluent_iced::{
     fluent_icon::FluentIcon,
-    page::{page, widget_example},
     style,
     widget::{button, ribbon, text, Element},
 };
@@ -46,8 +47,15 @@ impl Message {
     }
 }
 
-impl Ribbon {
-    pub fn update(&mut self, message: Message) {
+impl GalleryPage for Ribbon {
+    type Message = Message;
+
+    const TITLE: &'static str = "Ribbon";
+    const ICON: FluentIcon = FluentIcon::ViewAll;
+    const GROUP: Group = Group::MenuAndToolbars;
+    const KEYWORDS: &'static [&'static str] = &["toolbar", "commands", "tabs"];
+
+    fn update(&mut self, message: Message) {
         if let Some(button) = message.button_pressed() {
             self.last_button = Some(button);
         }
@@ -63,7 +71,7 @@ impl Ribbon {
         }
     }
 
-    pub fn view(&self) -> Element<Message> {
+    fn view(&self) -> Element<'_, Message> {
         let mail_button_flyout = Container::new(column![
             button::menu_icon("Mail", FluentIcon::Mail).on_press(Message::MailButtonPressed),
             button::menu_icon("Event", FluentIcon::Calendar).on_press(Message::EventButtonPressed),
@@ -71,50 +79,50 @@ impl Ribbon {
         ])
         .style(style::container::overlay);
 
-        page(
-            "Ribbon",
-            [widget_example(
-                "A sample of Ribbon buttons.",
-                row![
-                    ribbon::split_button::large(
-                        "New",
-                        FluentIcon::Mail,
-                        mail_button_flyout,
-                        Message::MailButtonPressed,
-                        Message::MailSplitButtonOpened,
-                        Message::MailSplitButtonClosed,
-                        self.mail_button_open
-                    )
-                    .width(100),
-                    ribbon::separator(),
-                    ribbon::button::large("Delete", FluentIcon::Delete)
-                        .width(48)
-                        .on_press(Message::DeleteButtonPressed),
-                    column![
-                        ribbon::button::medium("Cut", FluentIcon::Cut)
-                            .on_press(Message::CutButtonPressed),
-                        ribbon::button::medium("Copy", FluentIcon::Copy)
-                            .on_press(Message::CopyButtonPressed),
-                        ribbon::button::medium("Paste", FluentIcon::Paste)
-                            .on_press(Message::PasteButtonPressed),
-                    ],
-                    ribbon::separator(),
-                    ribbon::button::large("Reply", FluentIcon::Reply)
-                        .on_press(Message::ReplyButtonPressed)
-                        .width(44),
-                    ribbon::button::large("Forward", FluentIcon::Forward)
-                        .width(56)
-                        .on_press(Message::ForwardButtonPressed),
-                ]
-                .height(80)
-                .spacing(4),
-                Some(text::body1(if let Some(button) = self.last_button {
-                    format!("You clicked: {button}")
-                } else {
-                    String::new()
-                })),
-                None::<Element<Message>>,
-            )],
-        )
+        page([widget_example(
+            "A sample of Ribbon buttons.",
+            // #region buttons
+            row![
+                ribbon::split_button::large(
+                    "New",
+                    FluentIcon::Mail,
+                    mail_button_flyout,
+                    Message::MailButtonPressed,
+                    Message::MailSplitButtonOpened,
+                    Message::MailSplitButtonClosed,
+                    self.mail_button_open
+                )
+                .width(100),
+                ribbon::separator(),
+                ribbon::button::large("Delete", FluentIcon::Delete)
+                    .width(48)
+                    .on_press(Message::DeleteButtonPressed),
+                column![
+                    ribbon::button::medium("Cut", FluentIcon::Cut)
+                        .on_press(Message::CutButtonPressed),
+                    ribbon::button::medium("Copy", FluentIcon::Copy)
+                        .on_press(Message::CopyButtonPressed),
+                    ribbon::button::medium("Paste", FluentIcon::Paste)
+                        .on_press(Message::PasteButtonPressed),
+                ],
+                ribbon::separator(),
+                ribbon::button::large("Reply", FluentIcon::Reply)
+                    .on_press(Message::ReplyButtonPressed)
+                    .width(44),
+                ribbon::button::large("Forward", FluentIcon::Forward)
+                    .width(56)
+                    .on_press(Message::ForwardButtonPressed),
+            ]
+            .height(80)
+            .spacing(4),
+            // #endregion
+            Some(text::body1(if let Some(button) = self.last_button {
+                format!("You clicked: {button}")
+            } else {
+                String::new()
+            })),
+            None::<Element<Message>>,
+            Some(source::ribbon::BUTTONS),
+        )])
     }
 }
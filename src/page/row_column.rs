@@ -1,5 +1,7 @@
-use crate::{
-    page::{page, widget_example},
+use crate::page::{page, source, widget_example, GalleryPage, Group};
+
+use fluent_iced::{
+    fluent_icon::FluentIcon,
     widget::{canvas::Rectangle, radio, text, Canvas, Column, Element, Row},
 };
 
@@ -22,14 +24,28 @@ pub enum Message {
     OrientationSelected(Orientation),
 }
 
-impl RowColumn {
-    pub fn update(&mut self, message: Message) {
+impl GalleryPage for RowColumn {
+    type Message = Message;
+
+    const TITLE: &'static str = "Row & Column";
+    const ICON: FluentIcon = FluentIcon::Tiles;
+    const GROUP: Group = Group::Layout;
+    const KEYWORDS: &'static [&'static str] = &[
+        "stack panel",
+        "stack",
+        "orientation",
+        "horizontal",
+        "vertical",
+    ];
+    const SCROLLABLE: bool = false;
+
+    fn update(&mut self, message: Message) {
         match message {
             Message::OrientationSelected(orientation) => self.orientation = orientation,
         }
     }
 
-    pub fn view(&self) -> Element<Message> {
+    fn view(&self) -> Element<'_, Message> {
         let squares = vec![
             Color::from_rgb(1.0, 0.0, 0.0),
             Color::from_rgb(0.0, 0.0, 1.0),
@@ -44,42 +60,42 @@ impl RowColumn {
                 .into()
         });
 
-        page(
-            "Row & Column",
-            [widget_example(
-                "Row and Column widgets.",
-                match self.orientation {
-                    Orientation::Row => Element::new(
-                        Row::with_children(squares)
-                            .height(180)
-                            .spacing(0)
-                            .padding([10, 0]),
-                    ),
-                    Orientation::Column => {
-                        Element::new(Column::with_children(squares).padding([10, 0]))
-                    }
-                },
-                None::<Element<Message>>,
-                Some(
-                    column![
-                        text::body1("Orientation"),
-                        radio::standard(
-                            "Horizontal",
-                            Orientation::Row,
-                            Some(self.orientation),
-                            Message::OrientationSelected,
-                        ),
-                        radio::standard(
-                            "Vertical",
-                            Orientation::Column,
-                            Some(self.orientation),
-                            Message::OrientationSelected,
-                        ),
-                    ]
-                    .height(Length::Fill)
-                    .spacing(16),
+        page([widget_example(
+            "Row and Column widgets.",
+            // #region row_column
+            match self.orientation {
+                Orientation::Row => Element::new(
+                    Row::with_children(squares)
+                        .height(180)
+                        .spacing(0)
+                        .padding([10, 0]),
                 ),
-            )],
-        )
+                Orientation::Column => {
+                    Element::new(Column::with_children(squares).padding([10, 0]))
+                }
+            },
+            // #endregion
+            None::<Element<Message>>,
+            Some(
+                column![
+                    text::body1("Orientation"),
+                    radio::standard(
+                        "Horizontal",
+                        Orientation::Row,
+                        Some(self.orientation),
+                        Message::OrientationSelected,
+                    ),
+                    radio::standard(
+                        "Vertical",
+                        Orientation::Column,
+                        Some(self.orientation),
+                        Message::OrientationSelected,
+                    ),
+                ]
+                .height(Length::Fill)
+                .spacing(16),
+            ),
+            Some(source::row_column::ROW_COLUMN),
+        )])
     }
 }
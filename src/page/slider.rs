@@ -1,5 +1,7 @@
-use crate::{
-    page::{page, widget_example},
+use crate::page::{page, source, widget_example, GalleryPage, Group};
+
+use fluent_iced::{
+    fluent_icon::FluentIcon,
     widget::{
         self,
         number_input::{self, NumberInput},
@@ -43,8 +45,15 @@ pub enum Message {
     ValueChanged(u32),
 }
 
-impl Slider {
-    pub fn update(&mut self, message: Message) {
+impl GalleryPage for Slider {
+    type Message = Message;
+
+    const TITLE: &'static str = "Slider";
+    const ICON: FluentIcon = FluentIcon::Equalizer;
+    const GROUP: Group = Group::BasicInput;
+    const KEYWORDS: &'static [&'static str] = &["range", "value", "track"];
+
+    fn update(&mut self, message: Message) {
         match message {
             Message::SimpleValueChanged(value) => self.simple_value = value,
             Message::MinChanged(min) => {
@@ -66,66 +75,61 @@ impl Slider {
         }
     }
 
-    pub fn view(&self) -> Element<Message> {
-        page(
-            "Slider",
-            [
-                widget_example(
-                    "A simple Slider.",
-                    widget::slider::standard(
-                        0..=100,
-                        self.simple_value,
-                        Message::SimpleValueChanged,
-                    ),
-                    Some(text::body1(format!["Output: \n{}", self.simple_value])),
-                    None::<Element<Message>>,
-                ),
-                widget_example(
-                    "A Slider with range and steps specified.",
-                    widget::slider::standard(
-                        self.min..=self.max,
-                        self.value,
-                        Message::ValueChanged,
-                    )
+    fn view(&self) -> Element<'_, Message> {
+        page([
+            widget_example(
+                "A simple Slider.",
+                // #region simple
+                widget::slider::standard(0..=100, self.simple_value, Message::SimpleValueChanged),
+                // #endregion
+                Some(text::body1(format!["Output: \n{}", self.simple_value])),
+                None::<Element<Message>>,
+                Some(source::slider::SIMPLE),
+            ),
+            widget_example(
+                "A Slider with range and steps specified.",
+                // #region range
+                widget::slider::standard(self.min..=self.max, self.value, Message::ValueChanged)
                     .step(self.step),
-                    Some(text::body1(format!["Output: \n{}", self.value])),
-                    Some(
-                        column![
-                            row![
-                                text::body1("Minimum:"),
-                                number_input::underline(NumberInput::new(
-                                    self.min,
-                                    ..=self.max,
-                                    Message::MinChanged
-                                ))
-                            ]
-                            .align_y(alignment::Vertical::Center)
-                            .spacing(16),
-                            row![
-                                text::body1("Maximum:"),
-                                number_input::underline(NumberInput::new(
-                                    self.max,
-                                    self.min..,
-                                    Message::MaxChanged
-                                ))
-                            ]
-                            .align_y(alignment::Vertical::Center)
-                            .spacing(14),
-                            row![
-                                text::body1("Step:"),
-                                number_input::underline(NumberInput::new(
-                                    self.step,
-                                    0..=(self.max - self.min),
-                                    Message::StepChanged
-                                ))
-                            ]
-                            .align_y(alignment::Vertical::Center)
-                            .spacing(48),
+                // #endregion
+                Some(text::body1(format!["Output: \n{}", self.value])),
+                Some(
+                    column![
+                        row![
+                            text::body1("Minimum:"),
+                            number_input::underline(NumberInput::new(
+                                self.min,
+                                ..=self.max,
+                                Message::MinChanged
+                            ))
+                        ]
+                        .align_y(alignment::Vertical::Center)
+                        .spacing(16),
+                        row![
+                            text::body1("Maximum:"),
+                            number_input::underline(NumberInput::new(
+                                self.max,
+                                self.min..,
+                                Message::MaxChanged
+                            ))
+                        ]
+                        .align_y(alignment::Vertical::Center)
+                        .spacing(14),
+                        row![
+                            text::body1("Step:"),
+                            number_input::underline(NumberInput::new(
+                                self.step,
+                                0..=(self.max - self.min),
+                                Message::StepChanged
+                            ))
                         ]
-                        .spacing(8),
-                    ),
+                        .align_y(alignment::Vertical::Center)
+                        .spacing(48),
+                    ]
+                    .spacing(8),
                 ),
-            ],
-        )
+                Some(source::slider::RANGE),
+            ),
+        ])
     }
 }
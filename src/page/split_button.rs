@@ -1,12 +1,14 @@
-use crate::{
-    page::{page, widget_example},
+use crate::page::{page, source, widget_example, GalleryPage, Group};
+
+use fluent_iced::{
+    fluent_icon::FluentIcon,
     style,
     widget::{button, Button, Container, Element, Wrap},
 };
 
 use iced::{
     border::Radius,
-    widget::{button::Style, vertical_space},
+    widget::{button::Style, container, vertical_space},
     Border, Color, Padding,
 };
 
@@ -46,8 +48,16 @@ pub enum Message {
     NoOp,
 }
 
-impl SplitButton {
-    pub fn update(&mut self, message: Message) {
+impl GalleryPage for SplitButton {
+    type Message = Message;
+
+    const TITLE: &'static str = "Split Button";
+    const ICON: FluentIcon = FluentIcon::OpenPane;
+    const GROUP: Group = Group::BasicInput;
+    const SUBGROUPS: &'static [&'static str] = &["Buttons"];
+    const KEYWORDS: &'static [&'static str] = &["menu", "flyout", "dropdown", "colour"];
+
+    fn update(&mut self, message: Message) {
         let mut close_all = || self.open_splitbutton = OpenSplitButton::None;
 
         match message {
@@ -79,12 +89,12 @@ impl SplitButton {
         }
     }
 
-    pub fn view(&self) -> Element<Message> {
-        page(
-            "Split Button",
-            [
-                widget_example(
-                    "A Split Button.",
+    fn view(&self) -> Element<'_, Message> {
+        page([
+            widget_example(
+                "A Split Button.",
+                Container::new(
+                    // #region simple
                     button::split(
                         Button::new(vertical_space()).width(32).height(32).style(
                             move |theme, status| {
@@ -105,11 +115,17 @@ impl SplitButton {
                         Message::SplitButton1Closed,
                         matches!(self.open_splitbutton, OpenSplitButton::One),
                     ),
-                    None::<Element<Message>>,
-                    None::<Element<Message>>,
-                ),
-                widget_example(
-                    "A Split Button with text.",
+                    // #endregion
+                )
+                .id(split_button_id(1)),
+                None::<Element<Message>>,
+                None::<Element<Message>>,
+                Some(source::split_button::SIMPLE),
+            ),
+            widget_example(
+                "A Split Button with text.",
+                Container::new(
+                    // #region text
                     button::split(
                         button::standard("Choose color")
                             .style(style::button::split_content)
@@ -119,14 +135,22 @@ impl SplitButton {
                         Message::SplitButton2Closed,
                         matches!(self.open_splitbutton, OpenSplitButton::Two),
                     ),
-                    None::<Element<Message>>,
-                    None::<Element<Message>>,
-                ),
-            ],
-        )
+                    // #endregion
+                )
+                .id(split_button_id(2)),
+                None::<Element<Message>>,
+                None::<Element<Message>>,
+                Some(source::split_button::TEXT),
+            ),
+        ])
     }
 }
 
+// Id of the split button of the example `number`, for tests to find it
+fn split_button_id(number: usize) -> container::Id {
+    container::Id::new(format!("split_button_{number}"))
+}
+
 const COLOURS: [Color; 9] = [
     Color {
         r: 1.0,
@@ -211,3 +235,100 @@ where
     .style(style::container::overlay)
     .into()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::gallery::Page;
+    use crate::settings::Settings;
+    use crate::simulator::Simulator;
+
+    use iced::{
+        mouse::{self, ScrollDelta},
+        Event, Point, Size,
+    };
+
+    fn simulator() -> Simulator {
+        Simulator::new(Settings {
+            page: Page::Sample(SplitButton::TITLE),
+            ..Settings::default()
+        })
+    }
+
+    fn open(simulator: &Simulator) -> OpenSplitButton {
+        let page = simulator.gallery().page_state::<SplitButton>();
+
+        page.expect("page was opened").open_splitbutton.clone()
+    }
+
+    // Points on the indicators of the split buttons, top to bottom. The indicator
+    // is the right end of a split button.
+    fn indicators(simulator: &mut Simulator) -> [Point; 2] {
+        [1, 2].map(|number| {
+            let bounds = simulator
+                .bounds(split_button_id(number))
+                .expect("split button is in the page");
+
+            Point::new(bounds.x + bounds.width - 8.0, bounds.center_y())
+        })
+    }
+
+    #[test]
+    fn indicator_toggles_flyout() {
+        let mut simulator = simulator();
+        let indicators = indicators(&mut simulator);
+
+        // Each id is on its own split button
+        assert!(indicators[0].y < indicators[1].y);
+
+        simulator.click_at(indicators[1]);
+        assert_eq!(open(&simulator), OpenSplitButton::Two);
+
+        simulator.click_at(indicators[1]);
+        assert_eq!(open(&simulator), OpenSplitButton::None);
+    }
+
+    #[test]
+    fn indicator_is_found_on_scrolled_page() {
+        // Short enough for the page to scroll
+        let mut simulator = Simulator::new(Settings {
+            page: Page::Sample(SplitButton::TITLE),
+            window_size: Size::new(1024.0, 400.0),
+            ..Settings::default()
+        });
+        let top = indicators(&mut simulator);
+
+        simulator.click(SplitButton::TITLE).unwrap();
+        simulator.dispatch(&[Event::Mouse(mouse::Event::WheelScrolled {
+            delta: ScrollDelta::Pixels { x: 0.0, y: -40.0 },
+        })]);
+
+        let scrolled = indicators(&mut simulator);
+
+        assert_eq!(scrolled[1].y, top[1].y - 40.0);
+
+        simulator.click_at(scrolled[1]);
+        assert_eq!(open(&simulator), OpenSplitButton::Two);
+    }
+
+    #[test]
+    fn other_indicator_switches_flyout() {
+        let mut simulator = simulator();
+        let indicators = indicators(&mut simulator);
+
+        simulator.click_at(indicators[1]);
+        simulator.click_at(indicators[0]);
+        assert_eq!(open(&simulator), OpenSplitButton::One);
+    }
+
+    #[test]
+    fn clicking_outside_closes_flyout() {
+        let mut simulator = simulator();
+        let indicators = indicators(&mut simulator);
+
+        simulator.click_at(indicators[0]);
+        simulator.click(SplitButton::TITLE).unwrap();
+        assert_eq!(open(&simulator), OpenSplitButton::None);
+    }
+}
@@ -1,5 +1,7 @@
-use crate::{
-    page::{page, widget_example},
+use crate::page::{page, source, widget_example, GalleryPage, Group};
+
+use fluent_iced::{
+    fluent_icon::FluentIcon,
     widget::{radio, text, Element},
 };
 
@@ -18,72 +20,82 @@ pub enum Message {
     ContentFitChanged(ContentFit),
 }
 
-impl Svg {
-    pub fn update(&mut self, message: Message) {
+impl GalleryPage for Svg {
+    type Message = Message;
+
+    const TITLE: &'static str = "Svg";
+    const ICON: FluentIcon = FluentIcon::Draw;
+    const GROUP: Group = Group::Media;
+    const KEYWORDS: &'static [&'static str] = &["vector", "graphics", "icon"];
+
+    fn update(&mut self, message: Message) {
         match message {
             Message::ContentFitChanged(content_fit) => self.content_fit = content_fit,
         }
     }
 
-    pub fn view(&self) -> Element<Message> {
+    fn view(&self) -> Element<'_, Message> {
         let handle = svg::Handle::from_path(format! {
             "{}/assets/images/MirrorPCConsent.svg", env!("CARGO_MANIFEST_DIR")
         });
 
-        page(
-            "Svg",
-            [
-                widget_example(
-                    "An SVG image.",
-                    svg(handle.clone()).width(Length::Shrink).height(100),
-                    None::<Element<Message>>,
-                    None::<Element<Message>>,
-                ),
-                widget_example(
-                    "SVG image stretching.",
-                    svg(handle)
-                        .width(Length::Shrink)
-                        .height(100)
-                        .content_fit(self.content_fit),
-                    None::<Element<Message>>,
-                    Some(
-                        column![
-                            text::body1("Image content fit"),
-                            radio::standard(
-                                "Contain",
-                                ContentFit::Contain,
-                                Some(self.content_fit),
-                                Message::ContentFitChanged
-                            ),
-                            radio::standard(
-                                "Cover",
-                                ContentFit::Cover,
-                                Some(self.content_fit),
-                                Message::ContentFitChanged
-                            ),
-                            radio::standard(
-                                "Fill",
-                                ContentFit::Fill,
-                                Some(self.content_fit),
-                                Message::ContentFitChanged
-                            ),
-                            radio::standard(
-                                "None",
-                                ContentFit::None,
-                                Some(self.content_fit),
-                                Message::ContentFitChanged
-                            ),
-                            radio::standard(
-                                "ScaleDown",
-                                ContentFit::ScaleDown,
-                                Some(self.content_fit),
-                                Message::ContentFitChanged
-                            ),
-                        ]
-                        .spacing(16),
-                    ),
+        page([
+            widget_example(
+                "An SVG image.",
+                // #region svg
+                svg(handle.clone()).width(Length::Shrink).height(100),
+                // #endregion
+                None::<Element<Message>>,
+                None::<Element<Message>>,
+                Some(source::svg::SVG),
+            ),
+            widget_example(
+                "SVG image stretching.",
+                // #region stretching
+                svg(handle)
+                    .width(Length::Shrink)
+                    .height(100)
+                    .content_fit(self.content_fit),
+                // #endregion
+                None::<Element<Message>>,
+                Some(
+                    column![
+                        text::body1("Image content fit"),
+                        radio::standard(
+                            "Contain",
+                            ContentFit::Contain,
+                            Some(self.content_fit),
+                            Message::ContentFitChanged
+                        ),
+                        radio::standard(
+                            "Cover",
+                            ContentFit::Cover,
+                            Some(self.content_fit),
+                            Message::ContentFitChanged
+                        ),
+                        radio::standard(
+                            "Fill",
+                            ContentFit::Fill,
+                            Some(self.content_fit),
+                            Message::ContentFitChanged
+                        ),
+                        radio::standard(
+                            "None",
+                            ContentFit::None,
+                            Some(self.content_fit),
+                            Message::ContentFitChanged
+                        ),
+                        radio::standard(
+                            "ScaleDown",
+                            ContentFit::ScaleDown,
+                            Some(self.content_fit),
+                            Message::ContentFitChanged
+                        ),
+                    ]
+                    .spacing(16),
                 ),
-            ],
-        )
+                Some(source::svg::STRETCHING),
+            ),
+        ])
     }
 }
@@ -1,5 +1,7 @@
-use crate::{
-    page::{page, widget_example},
+use crate::page::{page, source, widget_example, GalleryPage, Group};
+
+use fluent_iced::{
+    fluent_icon::FluentIcon,
     theme::Theme,
     widget::{text, text_input, Element},
 };
@@ -34,8 +36,18 @@ impl TextInput {
         style: FontStyle::Italic,
         ..Font::DEFAULT
     };
+}
+
+impl GalleryPage for TextInput {
+    type Message = Message;
 
-    pub fn update(&mut self, message: Message) {
+    const TITLE: &'static str = "Text Input";
+    const ICON: FluentIcon = FluentIcon::Edit;
+    const GROUP: Group = Group::Text;
+    const KEYWORDS: &'static [&'static str] =
+        &["text box", "input", "edit", "placeholder", "read only"];
+
+    fn update(&mut self, message: Message) {
         match message {
             Message::TextInput1ContentChanged(content) => self.text_input1_content = content,
             Message::TextInput2ContentChanged(content) => self.text_input2_content = content,
@@ -43,61 +55,67 @@ impl TextInput {
         }
     }
 
-    pub fn view(&self) -> Element<Message> {
-        page(
-            "Text Input",
-            [
-                widget_example(
-                    "A simple TextInput.",
-                    text_input::underline(
-                        text_input::standard("", &self.text_input1_content)
-                            .width(64)
-                            .on_input(Message::TextInput1ContentChanged),
-                    ),
-                    None::<Element<Message>>,
-                    None::<Element<Message>>,
-                ),
-                widget_example(
-                    "A TextInput with a header and placeholder text.",
-                    column![
-                        text::body1("Enter your name:"),
-                        text_input::underline(
-                            text_input::standard("Name", &self.text_input2_content)
-                                .width(104)
-                                .on_input(Message::TextInput2ContentChanged)
-                        ),
-                    ]
-                    .spacing(8),
-                    None::<Element<Message>>,
-                    None::<Element<Message>>,
+    fn view(&self) -> Element<'_, Message> {
+        page([
+            widget_example(
+                "A simple TextInput.",
+                // #region simple
+                text_input::underline(
+                    text_input::standard("", &self.text_input1_content)
+                        .width(64)
+                        .on_input(Message::TextInput1ContentChanged),
                 ),
-                widget_example(
-                    "A read-only TextInput with a different style set.",
+                // #endregion
+                None::<Element<Message>>,
+                None::<Element<Message>>,
+                Some(source::text_input::SIMPLE),
+            ),
+            widget_example(
+                "A TextInput with a header and placeholder text.",
+                // #region header
+                column![
+                    text::body1("Enter your name:"),
                     text_input::underline(
-                        text_input::standard("", "I am super excited to be here!")
-                            .font(Self::EXAMPLE_FONT)
-                            .line_height(LineHeight::Absolute(Pixels(32.0)))
-                            .size(24)
-                            .width(328)
-                            .on_input(|_| Message::NoOp)
-                            .style(|theme: &Theme, status| {
-                                let style = (<Theme as Catalog>::default())(theme, status);
-
-                                match status {
-                                    Status::Active => Style {
-                                        value: color!(0x5178BE),
-                                        ..style
-                                    },
-                                    Status::Hovered => style,
-                                    Status::Focused => style,
-                                    Status::Disabled => style,
-                                }
-                            }),
+                        text_input::standard("Name", &self.text_input2_content)
+                            .width(104)
+                            .on_input(Message::TextInput2ContentChanged)
                     ),
-                    None::<Element<Message>>,
-                    None::<Element<Message>>,
+                ]
+                .spacing(8),
+                // #endregion
+                None::<Element<Message>>,
+                None::<Element<Message>>,
+                Some(source::text_input::HEADER),
+            ),
+            widget_example(
+                "A read-only TextInput with a different style set.",
+                // #region read_only
+                text_input::underline(
+                    text_input::standard("", "I am super excited to be here!")
+                        .font(Self::EXAMPLE_FONT)
+                        .line_height(LineHeight::Absolute(Pixels(32.0)))
+                        .size(24)
+                        .width(328)
+                        .on_input(|_| Message::NoOp)
+                        .style(|theme: &Theme, status| {
+                            let style = (<Theme as Catalog>::default())(theme, status);
+
+                            match status {
+                                Status::Active => Style {
+                                    value: color!(0x5178BE),
+                                    ..style
+                                },
+                                Status::Hovered => style,
+                                Status::Focused => style,
+                                Status::Disabled => style,
+                            }
+                        }),
                 ),
-            ],
-        )
+                // #endregion
+                None::<Element<Message>>,
+                None::<Element<Message>>,
+                Some(source::text_input::READ_ONLY),
+            ),
+        ])
     }
 }
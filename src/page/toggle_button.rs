@@ -1,5 +1,7 @@
-use crate::{
-    page::{page, widget_example},
+use crate::page::{page, source, widget_example, GalleryPage, Group};
+
+use fluent_iced::{
+    fluent_icon::FluentIcon,
     style,
     widget::{self, checkbox, text, Element},
 };
@@ -25,38 +27,46 @@ pub enum Message {
     Button1EnabledToggled(bool),
 }
 
-impl ToggleButton {
-    pub fn update(&mut self, message: Message) {
+impl GalleryPage for ToggleButton {
+    type Message = Message;
+
+    const TITLE: &'static str = "Toggle Button";
+    const ICON: FluentIcon = FluentIcon::ToggleFilled;
+    const GROUP: Group = Group::BasicInput;
+    const SUBGROUPS: &'static [&'static str] = &["Buttons"];
+    const KEYWORDS: &'static [&'static str] = &["on", "off", "state"];
+
+    fn update(&mut self, message: Message) {
         match message {
             Message::Button1Toggled => self.button1_on = !self.button1_on,
             Message::Button1EnabledToggled(checked) => self.button1_enabled = !checked,
         }
     }
 
-    pub fn view(&self) -> Element<Message> {
+    fn view(&self) -> Element<'_, Message> {
         let style = if self.button1_on {
             style::button::primary
         } else {
             style::button::secondary
         };
 
-        page(
-            "Toggle Button",
-            [widget_example(
-                "A simple Toggle Button with text content.",
-                widget::Button::new(text::body1("Toggle Button"))
-                    .height(32)
-                    .style(style)
-                    .on_press_maybe(self.button1_enabled.then_some(Message::Button1Toggled)),
-                Some(text::body1(format![
-                    "Output: {}",
-                    if self.button1_on { "\nOn" } else { "\nOff" }
-                ])),
-                Some(
-                    checkbox::two_state("Disable Toggle Button", !self.button1_enabled)
-                        .on_toggle(Message::Button1EnabledToggled),
-                ),
-            )],
-        )
+        page([widget_example(
+            "A simple Toggle Button with text content.",
+            // #region text
+            widget::Button::new(text::body1("Toggle Button"))
+                .height(32)
+                .style(style)
+                .on_press_maybe(self.button1_enabled.then_some(Message::Button1Toggled)),
+            // #endregion
+            Some(text::body1(format![
+                "Output: {}",
+                if self.button1_on { "\nOn" } else { "\nOff" }
+            ])),
+            Some(
+                checkbox::two_state("Disable Toggle Button", !self.button1_enabled)
+                    .on_toggle(Message::Button1EnabledToggled),
+            ),
+            Some(source::toggle_button::TEXT),
+        )])
     }
 }
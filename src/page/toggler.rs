@@ -1,5 +1,7 @@
-use crate::{
-    page::{page, widget_example},
+use crate::page::{page, source, widget_example, GalleryPage, Group};
+
+use fluent_iced::{
+    fluent_icon::FluentIcon,
     widget::{toggler, Element},
 };
 
@@ -13,24 +15,31 @@ pub enum Message {
     Toggled(bool),
 }
 
-impl Toggler {
-    pub fn update(&mut self, message: Message) {
+impl GalleryPage for Toggler {
+    type Message = Message;
+
+    const TITLE: &'static str = "Toggler";
+    const ICON: FluentIcon = FluentIcon::ToggleRight;
+    const GROUP: Group = Group::BasicInput;
+    const KEYWORDS: &'static [&'static str] = &["toggle switch", "switch", "on", "off"];
+
+    fn update(&mut self, message: Message) {
         match message {
             Message::Toggled(toggled) => self.is_toggled = toggled,
         }
     }
 
-    pub fn view(&self) -> Element<Message> {
-        page(
-            "Toggler",
-            [widget_example(
-                "A simple Toggler.",
-                toggler::standard(self.is_toggled)
-                    .label(if self.is_toggled { "On" } else { "Off" })
-                    .on_toggle(Message::Toggled),
-                None::<Element<Message>>,
-                None::<Element<Message>>,
-            )],
-        )
+    fn view(&self) -> Element<'_, Message> {
+        page([widget_example(
+            "A simple Toggler.",
+            // #region simple
+            toggler::standard(self.is_toggled)
+                .label(if self.is_toggled { "On" } else { "Off" })
+                .on_toggle(Message::Toggled),
+            // #endregion
+            None::<Element<Message>>,
+            None::<Element<Message>>,
+            Some(source::toggler::SIMPLE),
+        )])
     }
 }
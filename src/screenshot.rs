@@ -0,0 +1,180 @@
+//! Renders pages of the gallery to PNG files with the tiny-skia software renderer,
+//! so images for the docs can be made without a GPU or a display.
+
+use crate::cli::{Args, ThemeArg};
+use crate::gallery::{Gallery, Page};
+use crate::settings::{self, Settings};
+
+use fluent_iced::{
+    font,
+    theme::{self, Theme},
+};
+
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use iced::{
+    advanced::{graphics::Viewport, renderer},
+    application::DefaultStyle,
+    mouse, Font, Pixels, Rectangle, Renderer, Size,
+};
+use iced_runtime::user_interface::{self, UserInterface};
+use tiny_skia::{Mask, Pixmap};
+
+#[derive(Debug)]
+pub enum Error {
+    Io(PathBuf, io::Error),
+    Encoding(PathBuf, String),
+    ThemeFile(PathBuf, Vec<theme::file::Error>),
+    InvalidSize(Size),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(path, error) => write!(f, "{}: {error}", path.display()),
+            Error::Encoding(path, error) => write!(f, "{}: {error}", path.display()),
+            Error::ThemeFile(path, errors) => {
+                write!(f, "{}:", path.display())?;
+
+                for error in errors {
+                    write!(f, "\n    {error}")?;
+                }
+
+                Ok(())
+            }
+            Error::InvalidSize(size) => {
+                write!(f, "cannot render at {}x{}", size.width, size.height)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Writes a `<page id>-<theme>.png` file into `dir` for the page of `args`, or for
+/// every page, in the theme of `args`, or in the light and dark themes. The other
+/// arguments apply as they do when running the gallery, and everything else takes
+/// its default rather than the saved settings so the images don't depend on who
+/// made them. Returns the paths of the files, in the order of [`Page::all`].
+pub fn export(args: &Args, dir: &Path) -> Result<Vec<PathBuf>, Error> {
+    let pages = match args.page {
+        Some(page) => vec![page],
+        None => Page::all(),
+    };
+
+    let themes = match &args.theme {
+        Some(theme) => vec![theme.clone()],
+        None => vec![
+            ThemeArg::BuiltIn(Theme::Light),
+            ThemeArg::BuiltIn(Theme::Dark),
+        ],
+    };
+
+    // The gallery keeps its theme when the file fails to load, which would quietly
+    // give images in the wrong theme
+    for theme in &themes {
+        if let ThemeArg::File(path) = theme {
+            theme::file::load(path).map_err(|errors| Error::ThemeFile(path.clone(), errors))?;
+        }
+    }
+
+    fs::create_dir_all(dir).map_err(|error| Error::Io(dir.to_owned(), error))?;
+
+    font::load_now(font::detect(Some(font::FLUENT_SYSTEM_ICONS)));
+
+    let mut written = Vec::with_capacity(pages.len() * themes.len());
+
+    for page in pages {
+        for theme in &themes {
+            let settings = Args {
+                page: Some(page),
+                theme: Some(theme.clone()),
+                ..args.clone()
+            }
+            .apply(Settings::default());
+
+            let path = dir.join(format!("{}-{}.png", page.slug(), theme_name(theme)));
+
+            let pixmap = render(settings)?;
+            let png = pixmap
+                .encode_png()
+                .map_err(|error| Error::Encoding(path.clone(), error.to_string()))?;
+
+            fs::write(&path, png).map_err(|error| Error::Io(path.clone(), error))?;
+
+            written.push(path);
+        }
+    }
+
+    Ok(written)
+}
+
+/// Draws the gallery opened with `settings` at the size of its window.
+pub fn render(settings: Settings) -> Result<Pixmap, Error> {
+    let size = settings.window_size;
+    let invalid_size = || Error::InvalidSize(size);
+
+    let mut pixmap = Pixmap::new(size.width as u32, size.height as u32).ok_or_else(invalid_size)?;
+    let mut clip_mask = Mask::new(pixmap.width(), pixmap.height()).ok_or_else(invalid_size)?;
+
+    // Fonts are loaded by `export` rather than by the task
+    let (gallery, _) = Gallery::new(settings.clone(), settings);
+
+    let theme = gallery.theme();
+    let appearance = theme.default_style();
+
+    let mut renderer =
+        Renderer::Secondary(iced_tiny_skia::Renderer::new(Font::DEFAULT, Pixels(16.0)));
+
+    let mut interface = UserInterface::build(
+        gallery.view(),
+        size,
+        user_interface::Cache::default(),
+        &mut renderer,
+    );
+
+    let _ = interface.draw(
+        &mut renderer,
+        &theme,
+        &renderer::Style {
+            text_color: appearance.text_color,
+        },
+        mouse::Cursor::Unavailable,
+    );
+
+    let viewport = Viewport::with_physical_size(Size::new(pixmap.width(), pixmap.height()), 1.0);
+
+    let Renderer::Secondary(renderer) = &mut renderer else {
+        unreachable!("the renderer was created as tiny-skia");
+    };
+
+    renderer.draw(
+        &mut pixmap.as_mut(),
+        &mut clip_mask,
+        &viewport,
+        &[Rectangle::with_size(size)],
+        appearance.background_color,
+        &[] as &[&str],
+    );
+
+    // The renderer swaps red and blue for the BGRA window surfaces
+    for pixel in pixmap.data_mut().chunks_exact_mut(4) {
+        pixel.swap(0, 2);
+    }
+
+    Ok(pixmap)
+}
+
+// Built-in themes go by their `--theme` value and theme files by their file name
+fn theme_name(theme: &ThemeArg) -> String {
+    match theme {
+        ThemeArg::BuiltIn(theme) => settings::theme_key(theme).to_owned(),
+        ThemeArg::File(path) => path.file_stem().map_or_else(
+            || String::from("custom"),
+            |stem| stem.to_string_lossy().into_owned(),
+        ),
+    }
+}
@@ -0,0 +1,617 @@
+//! Settings of the gallery, kept between runs in a TOML file in the user's
+//! config directory.
+//!
+//! ```toml
+//! version = 1
+//! theme = "dark"
+//! accent = "#0063B1"
+//! explain = false
+//! page = "button"
+//!
+//! [side_nav]
+//! display_mode = "full"
+//! width = 300.0
+//! expanded_groups = [["Basic input"], ["Basic input", "Buttons"]]
+//!
+//! [window]
+//! width = 1024.0
+//! height = 768.0
+//! x = 40.0
+//! y = 40.0
+//! ```
+//!
+//! Keys which are missing take their default, and keys which are unknown are
+//! ignored, so files written by an older version still load. The `version` is
+//! only bumped when the meaning of an existing key changes.
+
+use crate::gallery::Page;
+use crate::page::Entry;
+
+use fluent_iced::{
+    theme::{file::parse_colour, Contrast, Theme},
+    widget::side_nav,
+};
+
+use std::{
+    env, fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use iced::{window, Color, Point, Size};
+
+/// Version of the file written by [`Settings::save`].
+pub const VERSION: i64 = 1;
+
+const FILE_NAME: &str = "settings.toml";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Settings {
+    /// Light, dark or a contrast theme. A custom theme is loaded from `theme_file`.
+    pub theme: Theme,
+    pub theme_file: Option<PathBuf>,
+    pub accent: Option<Color>,
+    pub explain: bool,
+    pub page: Page,
+    pub side_nav_display_mode: side_nav::DisplayMode,
+    pub side_nav_width: f32,
+    /// Paths of labels to the expanded groups, starting with the label of their
+    /// page group.
+    pub expanded_groups: Vec<Vec<String>>,
+    pub window_size: Size,
+    pub window_position: Option<Point>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            theme: Theme::default(),
+            theme_file: None,
+            accent: None,
+            explain: false,
+            page: Page::default(),
+            side_nav_display_mode: side_nav::DisplayMode::Full,
+            side_nav_width: 300.0,
+            expanded_groups: Vec::new(),
+            window_size: window::Settings::default().size,
+            window_position: None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Syntax(String),
+    /// The file was written by a newer version of the gallery.
+    UnsupportedVersion(i64),
+    InvalidValue {
+        key: &'static str,
+        value: String,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(error) => write!(f, "{error}"),
+            Error::Syntax(error) => write!(f, "{error}"),
+            Error::UnsupportedVersion(version) => {
+                write!(f, "version {version} is newer than the supported {VERSION}")
+            }
+            Error::InvalidValue { key, value } => write!(f, "invalid value {value} for \"{key}\""),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl Settings {
+    /// Path of the settings file, in `$XDG_CONFIG_HOME` or `~/.config`, or in
+    /// `%APPDATA%` on Windows.
+    pub fn path() -> Option<PathBuf> {
+        let config_dir = if cfg!(windows) {
+            env::var_os("APPDATA").map(PathBuf::from)
+        } else {
+            env::var_os("XDG_CONFIG_HOME")
+                .map(PathBuf::from)
+                .filter(|path| path.is_absolute())
+                .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        };
+
+        config_dir.map(|dir| dir.join(env!("CARGO_PKG_NAME")).join(FILE_NAME))
+    }
+
+    /// Loads the settings file, falling back to the defaults with a warning if it
+    /// can't be read. A missing file isn't an error.
+    pub fn load_or_default() -> Self {
+        match Self::path() {
+            Some(path) => Self::load_or_default_from(&path),
+            None => Self::default(),
+        }
+    }
+
+    fn load_or_default_from(path: &Path) -> Self {
+        match Self::load(path) {
+            Ok(settings) => settings,
+            Err(Error::Io(error)) if error.kind() == io::ErrorKind::NotFound => Self::default(),
+            Err(error) => {
+                eprintln!(
+                    "Ignoring settings file {}, using the defaults: {error}",
+                    path.display()
+                );
+                Self::default()
+            }
+        }
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let source = fs::read_to_string(path).map_err(Error::Io)?;
+
+        Self::parse(&source)
+    }
+
+    pub fn parse(source: &str) -> Result<Self, Error> {
+        let table: toml::Table = source
+            .parse()
+            .map_err(|error: toml::de::Error| Error::Syntax(error.message().to_owned()))?;
+
+        match table.get("version") {
+            None => (),
+            Some(toml::Value::Integer(version)) if *version <= VERSION => (),
+            Some(toml::Value::Integer(version)) => return Err(Error::UnsupportedVersion(*version)),
+            Some(value) => return Err(invalid("version", value)),
+        }
+
+        let mut settings = Self::default();
+        let side_nav = table_of(&table, "side_nav")?;
+        let window = table_of(&table, "window")?;
+
+        if let Some(value) = table.get("theme") {
+            match value.as_str() {
+                Some("custom") => (),
+                Some(key) => {
+                    settings.theme = themes()
+                        .find(|theme| theme_key(theme) == key)
+                        .ok_or_else(|| invalid("theme", value))?;
+                }
+                None => return Err(invalid("theme", value)),
+            }
+        }
+
+        if let Some(value) = table.get("theme_file") {
+            let path = value.as_str().ok_or_else(|| invalid("theme_file", value))?;
+            settings.theme_file = Some(PathBuf::from(path));
+        }
+
+        if let Some(value) = table.get("accent") {
+            settings.accent = Some(
+                value
+                    .as_str()
+                    .and_then(parse_colour)
+                    .filter(|accent| accent.a == 1.0)
+                    .ok_or_else(|| invalid("accent", value))?,
+            );
+        }
+
+        if let Some(value) = table.get("explain") {
+            settings.explain = value.as_bool().ok_or_else(|| invalid("explain", value))?;
+        }
+
+        // Pages are stored by slug, older files have their title. Pages which were
+        // removed since are ignored.
+        if let Some(value) = table.get("page") {
+            let page = value.as_str().ok_or_else(|| invalid("page", value))?;
+
+            settings.page = Page::from_slug(page)
+                .or_else(|| Entry::find(page).map(|entry| Page::Sample(entry.title)))
+                .unwrap_or_default();
+        }
+
+        if let Some(value) = side_nav.get("display_mode") {
+            settings.side_nav_display_mode = side_nav::DisplayMode::ALL
+                .into_iter()
+                .find(|display_mode| Some(display_mode_key(*display_mode)) == value.as_str())
+                .ok_or_else(|| invalid("side_nav.display_mode", value))?;
+        }
+
+        if let Some(value) = side_nav.get("width") {
+            settings.side_nav_width =
+                as_f32(value).ok_or_else(|| invalid("side_nav.width", value))?;
+        }
+
+        if let Some(value) = side_nav.get("expanded_groups") {
+            settings.expanded_groups = value
+                .as_array()
+                .and_then(|paths| paths.iter().map(as_path).collect())
+                .ok_or_else(|| invalid("side_nav.expanded_groups", value))?;
+        }
+
+        if let (Some(width), Some(height)) = (window.get("width"), window.get("height")) {
+            // Like --window, a window needs an area
+            settings.window_size = Size::new(
+                as_f32(width)
+                    .filter(|width| *width > 0.0)
+                    .ok_or_else(|| invalid("window.width", width))?,
+                as_f32(height)
+                    .filter(|height| *height > 0.0)
+                    .ok_or_else(|| invalid("window.height", height))?,
+            );
+        }
+
+        if let (Some(x), Some(y)) = (window.get("x"), window.get("y")) {
+            settings.window_position = Some(Point::new(
+                as_f32(x).ok_or_else(|| invalid("window.x", x))?,
+                as_f32(y).ok_or_else(|| invalid("window.y", y))?,
+            ));
+        }
+
+        Ok(settings)
+    }
+
+    /// Writes the settings to [`Settings::path`].
+    pub fn save(&self) -> io::Result<()> {
+        let path = Self::path()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no config directory found"))?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        // Written next to the file then renamed over it, so exiting half way
+        // through writing doesn't leave a corrupt file
+        let temporary = path.with_extension("toml.tmp");
+        fs::write(&temporary, self.to_toml())?;
+        fs::rename(&temporary, &path)
+    }
+
+    /// `saved` with the values which changed since `launched` taken from `self`.
+    ///
+    /// The gallery is launched with the saved settings overridden by the command
+    /// line arguments, which only last for the session, so only what the user
+    /// changes during the session is saved over the file.
+    pub fn merge_changes(self, launched: &Settings, saved: Settings) -> Settings {
+        fn changed<T: PartialEq>(current: T, launched: &T, saved: T) -> T {
+            if current == *launched {
+                saved
+            } else {
+                current
+            }
+        }
+
+        // The theme file replaces the theme so they change together
+        let (theme, theme_file) = changed(
+            (self.theme, self.theme_file),
+            &(launched.theme.clone(), launched.theme_file.clone()),
+            (saved.theme, saved.theme_file),
+        );
+
+        Settings {
+            theme,
+            theme_file,
+            accent: changed(self.accent, &launched.accent, saved.accent),
+            explain: changed(self.explain, &launched.explain, saved.explain),
+            page: changed(self.page, &launched.page, saved.page),
+            side_nav_display_mode: changed(
+                self.side_nav_display_mode,
+                &launched.side_nav_display_mode,
+                saved.side_nav_display_mode,
+            ),
+            side_nav_width: changed(
+                self.side_nav_width,
+                &launched.side_nav_width,
+                saved.side_nav_width,
+            ),
+            expanded_groups: changed(
+                self.expanded_groups,
+                &launched.expanded_groups,
+                saved.expanded_groups,
+            ),
+            window_size: changed(self.window_size, &launched.window_size, saved.window_size),
+            window_position: changed(
+                self.window_position,
+                &launched.window_position,
+                saved.window_position,
+            ),
+        }
+    }
+
+    pub fn to_toml(&self) -> String {
+        let mut table = toml::Table::new();
+
+        table.insert("version".into(), VERSION.into());
+
+        let theme = match (&self.theme, &self.theme_file) {
+            (_, Some(_)) | (Theme::Custom(_), None) => "custom",
+            (theme, None) => theme_key(theme),
+        };
+        table.insert("theme".into(), theme.into());
+
+        if let Some(path) = &self.theme_file {
+            table.insert("theme_file".into(), path.to_string_lossy().as_ref().into());
+        }
+
+        if let Some(accent) = self.accent {
+            let [r, g, b, _] = accent.into_rgba8();
+            table.insert("accent".into(), format!("#{r:02X}{g:02X}{b:02X}").into());
+        }
+
+        table.insert("explain".into(), self.explain.into());
+
+        table.insert("page".into(), self.page.slug().into());
+
+        let mut side_nav = toml::Table::new();
+        side_nav.insert(
+            "display_mode".into(),
+            display_mode_key(self.side_nav_display_mode).into(),
+        );
+        side_nav.insert("width".into(), f64::from(self.side_nav_width).into());
+        side_nav.insert(
+            "expanded_groups".into(),
+            self.expanded_groups
+                .iter()
+                .map(|path| toml::Value::from(path.clone()))
+                .collect::<Vec<_>>()
+                .into(),
+        );
+        table.insert("side_nav".into(), side_nav.into());
+
+        let mut window = toml::Table::new();
+        window.insert("width".into(), f64::from(self.window_size.width).into());
+        window.insert("height".into(), f64::from(self.window_size.height).into());
+
+        if let Some(position) = self.window_position {
+            window.insert("x".into(), f64::from(position.x).into());
+            window.insert("y".into(), f64::from(position.y).into());
+        }
+
+        table.insert("window".into(), window.into());
+
+        table.to_string()
+    }
+}
+
+/// The built-in themes, which the file refers to by [`theme_key`].
+pub fn themes() -> impl Iterator<Item = Theme> {
+    [Theme::Light, Theme::Dark]
+        .into_iter()
+        .chain(Contrast::ALL.map(Theme::HighContrast))
+}
+
+pub fn theme_key(theme: &Theme) -> &'static str {
+    match theme {
+        Theme::Light => "light",
+        Theme::Dark => "dark",
+        Theme::HighContrast(Contrast::Aquatic) => "aquatic",
+        Theme::HighContrast(Contrast::Desert) => "desert",
+        Theme::HighContrast(Contrast::Dusk) => "dusk",
+        Theme::HighContrast(Contrast::NightSky) => "night_sky",
+        Theme::Custom(_) => "custom",
+    }
+}
+
+fn display_mode_key(display_mode: side_nav::DisplayMode) -> &'static str {
+    match display_mode {
+        side_nav::DisplayMode::Full => "full",
+        side_nav::DisplayMode::Compact => "compact",
+        side_nav::DisplayMode::Minimal => "minimal",
+        side_nav::DisplayMode::Top => "top",
+    }
+}
+
+// A missing table is empty, as every key of it takes its default
+fn table_of(table: &toml::Table, key: &'static str) -> Result<toml::Table, Error> {
+    match table.get(key) {
+        None => Ok(toml::Table::new()),
+        Some(toml::Value::Table(table)) => Ok(table.clone()),
+        Some(value) => Err(invalid(key, value)),
+    }
+}
+
+fn invalid(key: &'static str, value: &toml::Value) -> Error {
+    Error::InvalidValue {
+        key,
+        value: value.to_string(),
+    }
+}
+
+// Integers are accepted too, as `width = 300` is easier to write by hand
+fn as_f32(value: &toml::Value) -> Option<f32> {
+    match value {
+        toml::Value::Float(value) => Some(*value as f32),
+        toml::Value::Integer(value) => Some(*value as f32),
+        _ => None,
+    }
+    .filter(|value| value.is_finite())
+}
+
+fn as_path(value: &toml::Value) -> Option<Vec<String>> {
+    value
+        .as_array()?
+        .iter()
+        .map(|label| label.as_str().map(ToOwned::to_owned))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::page::{drop_down_button::DropDownButton, GalleryPage};
+
+    use iced::color;
+
+    #[test]
+    fn round_trips_through_toml() {
+        let settings = Settings {
+            theme: Theme::HighContrast(Contrast::Dusk),
+            theme_file: None,
+            accent: Some(color!(0x0063B1)),
+            explain: true,
+            page: Page::Sample(DropDownButton::TITLE),
+            side_nav_display_mode: side_nav::DisplayMode::Top,
+            side_nav_width: 250.0,
+            expanded_groups: vec![
+                vec!["Basic input".to_owned()],
+                vec!["Basic input".to_owned(), "Buttons".to_owned()],
+            ],
+            window_size: Size::new(1280.0, 800.0),
+            window_position: Some(Point::new(40.0, 60.0)),
+        };
+
+        assert_eq!(Settings::parse(&settings.to_toml()).unwrap(), settings);
+    }
+
+    #[test]
+    fn only_changes_made_in_the_session_are_merged() {
+        let saved = Settings {
+            theme_file: Some(PathBuf::from("orchid.toml")),
+            page: Page::Sample(DropDownButton::TITLE),
+            ..Settings::default()
+        };
+
+        // As with --theme light --explain --window 1280x800
+        let launched = Settings {
+            theme: Theme::Light,
+            theme_file: None,
+            explain: true,
+            window_size: Size::new(1280.0, 800.0),
+            ..saved.clone()
+        };
+
+        assert_eq!(
+            launched.clone().merge_changes(&launched, saved.clone()),
+            saved
+        );
+
+        let session = Settings {
+            explain: false,
+            page: Page::Settings,
+            ..launched.clone()
+        };
+
+        assert_eq!(
+            session.merge_changes(&launched, saved.clone()),
+            Settings {
+                explain: false,
+                page: Page::Settings,
+                ..saved.clone()
+            }
+        );
+
+        // Choosing a theme replaces the theme file
+        let session = Settings {
+            theme: Theme::Dark,
+            ..launched.clone()
+        };
+
+        assert_eq!(
+            session.merge_changes(&launched, saved.clone()),
+            Settings {
+                theme: Theme::Dark,
+                theme_file: None,
+                ..saved
+            }
+        );
+    }
+
+    #[test]
+    fn page_is_stored_by_slug() {
+        let settings = Settings {
+            page: Page::Sample(DropDownButton::TITLE),
+            ..Settings::default()
+        };
+
+        assert!(settings.to_toml().contains("page = \"drop-down-button\""));
+
+        let parsed = Settings::parse("page = \"Drop Down Button\"").unwrap();
+        assert_eq!(parsed.page, Page::Sample(DropDownButton::TITLE));
+
+        let parsed = Settings::parse("page = \"removed-page\"").unwrap();
+        assert_eq!(parsed.page, Page::Home);
+    }
+
+    #[test]
+    fn newer_version_is_unsupported() {
+        let error = Settings::parse(&format!("version = {}", VERSION + 1)).unwrap_err();
+
+        assert!(matches!(error, Error::UnsupportedVersion(version) if version == VERSION + 1));
+    }
+
+    #[test]
+    fn syntax_error_is_reported() {
+        let error = Settings::parse("theme = \"dark").unwrap_err();
+
+        assert!(matches!(error, Error::Syntax(_)));
+    }
+
+    #[test]
+    fn window_size_must_be_positive() {
+        for (source, invalid_key) in [
+            ("[window]\nwidth = -5\nheight = 600", "window.width"),
+            ("[window]\nwidth = 800\nheight = 0", "window.height"),
+        ] {
+            match Settings::parse(source) {
+                Err(Error::InvalidValue { key, .. }) => assert_eq!(key, invalid_key, "{source}"),
+                result => panic!("{source} parsed to {result:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn values_of_the_wrong_type_are_invalid() {
+        for (source, invalid_key) in [
+            ("version = \"1\"", "version"),
+            ("explain = \"yes\"", "explain"),
+            ("theme = \"sepia\"", "theme"),
+            ("accent = \"#+12345\"", "accent"),
+            ("accent = \"#800063B1\"", "accent"),
+            ("page = 3", "page"),
+            ("side_nav = 3", "side_nav"),
+            ("[side_nav]\nwidth = \"wide\"", "side_nav.width"),
+            (
+                "[side_nav]\nexpanded_groups = [[1]]",
+                "side_nav.expanded_groups",
+            ),
+            ("[window]\nwidth = 800\nheight = true", "window.height"),
+        ] {
+            match Settings::parse(source) {
+                Err(Error::InvalidValue { key, .. }) => assert_eq!(key, invalid_key, "{source}"),
+                result => panic!("{source} parsed to {result:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn unknown_keys_are_ignored() {
+        let parsed = Settings::parse(
+            "explain = true
+            favourite_page = \"button\"
+
+            [window]
+            width = 800
+            height = 600
+            maximised = true",
+        )
+        .unwrap();
+
+        assert_eq!(
+            parsed,
+            Settings {
+                explain: true,
+                window_size: Size::new(800.0, 600.0),
+                ..Settings::default()
+            }
+        );
+    }
+
+    #[test]
+    fn corrupt_file_falls_back_to_the_defaults() {
+        let path = env::temp_dir().join(format!("{}-corrupt.toml", env!("CARGO_PKG_NAME")));
+        fs::write(&path, "explain = tru").unwrap();
+
+        let settings = Settings::load_or_default_from(&path);
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(settings, Settings::default());
+    }
+}
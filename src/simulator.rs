@@ -0,0 +1,499 @@
+//! Drives the [`Gallery`] like a user would, for tests: clicking on text, typing
+//! and pressing keys, without a window.
+//!
+//! Each interaction builds the interface from [`Gallery::view`], sends it the
+//! events, and passes the messages it produces to [`Gallery::update`]. Widgets
+//! with an id are found by an operation over the interface. The text widgets of
+//! iced don't take part in operations, so text is found by drawing the interface
+//! with the tiny-skia renderer and looking through what it would draw instead;
+//! prefer ids for widgets which aren't told apart by their text. Subscriptions
+//! which listen to events see the events too. Of the tasks returned by
+//! [`Gallery::update`], the widget operations, such as scrolling, are run and the
+//! messages are passed back; other actions are dropped.
+
+use crate::gallery::{Gallery, Message};
+use crate::settings::Settings;
+
+use fluent_iced::{
+    font::{self, Fonts, IconFont, TextFont},
+    theme::Theme,
+};
+
+use std::{collections::VecDeque, fmt, sync::Once};
+
+use iced::{
+    advanced::{
+        clipboard,
+        graphics::text::Text,
+        renderer,
+        subscription::{self, EventStream},
+        widget::{self, operation::Outcome, Operation},
+    },
+    alignment::{Horizontal, Vertical},
+    application::DefaultStyle,
+    futures::{
+        stream::{self, StreamExt},
+        task::{noop_waker_ref, Context, Poll},
+    },
+    keyboard::{
+        self,
+        key::{NativeCode, Physical},
+        Key, Location, Modifiers,
+    },
+    mouse, window, Event, Font, Pixels, Point, Rectangle, Renderer, Size, Task, Vector,
+};
+use iced_runtime::{
+    task,
+    user_interface::{self, UserInterface},
+    Action,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    NotFound(String),
+    /// More than one text on top matches, use [`Simulator::find`] to pick one.
+    Ambiguous(String, usize),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NotFound(label) => write!(f, "no text \"{label}\" is visible"),
+            Error::Ambiguous(label, count) => write!(f, "{count} texts \"{label}\" are visible"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub struct Simulator {
+    gallery: Gallery,
+    size: Size,
+    cache: user_interface::Cache,
+    cursor: mouse::Cursor,
+    window: window::Id,
+}
+
+impl Simulator {
+    /// The gallery opened with `settings`, in a window of their size. The text is
+    /// rendered in the bundled Inter font so the layout is the same everywhere.
+    pub fn new(settings: Settings) -> Self {
+        static FONTS: Once = Once::new();
+
+        FONTS.call_once(|| {
+            font::load_now(Fonts {
+                text: TextFont::Inter,
+                icons: IconFont::Fallback(font::FLUENT_SYSTEM_ICONS),
+            })
+        });
+
+        let size = settings.window_size;
+        let (gallery, _) = Gallery::new(settings.clone(), settings);
+
+        Self {
+            gallery,
+            size,
+            cache: user_interface::Cache::default(),
+            cursor: mouse::Cursor::Unavailable,
+            window: window::Id::unique(),
+        }
+    }
+
+    pub fn gallery(&self) -> &Gallery {
+        &self.gallery
+    }
+
+    /// Bounds of the widget with `id` on screen, if it's in the interface.
+    pub fn bounds(&mut self, id: impl Into<widget::Id>) -> Option<Rectangle> {
+        let mut renderer = renderer();
+        let mut operation = FindBounds {
+            id: id.into(),
+            bounds: None,
+            translation: Vector::ZERO,
+            scrolled: Vector::ZERO,
+        };
+
+        let mut interface = UserInterface::build(
+            self.gallery.view(),
+            self.size,
+            std::mem::take(&mut self.cache),
+            &mut renderer,
+        );
+
+        interface.operate(&renderer, &mut operation);
+
+        self.cache = interface.into_cache();
+
+        operation.bounds
+    }
+
+    /// Bounds of the visible texts which read `label`, in the order they are drawn
+    /// so the ones on top come last.
+    pub fn find(&mut self, label: &str) -> Vec<Rectangle> {
+        self.texts()
+            .into_iter()
+            .filter(|(_, content)| content == label)
+            .map(|(bounds, _)| bounds.rectangle)
+            .collect()
+    }
+
+    /// Clicks the middle of the text which reads `label`. When texts in different
+    /// layers match, such as one in a menu over the page, the one drawn last is
+    /// clicked.
+    pub fn click(&mut self, label: &str) -> Result<(), Error> {
+        let texts = self.texts();
+
+        let layer = texts
+            .iter()
+            .filter(|(_, content)| content == label)
+            .map(|(bounds, _)| bounds.layer)
+            .max()
+            .ok_or_else(|| Error::NotFound(label.to_owned()))?;
+
+        let matches: Vec<Rectangle> = texts
+            .into_iter()
+            .filter(|(bounds, content)| content == label && bounds.layer == layer)
+            .map(|(bounds, _)| bounds.rectangle)
+            .collect();
+
+        match matches.as_slice() {
+            [bounds] => {
+                self.click_at(bounds.center());
+
+                Ok(())
+            }
+            _ => Err(Error::Ambiguous(label.to_owned(), matches.len())),
+        }
+    }
+
+    /// Moves the cursor to `position` and clicks the left mouse button.
+    pub fn click_at(&mut self, position: Point) {
+        self.cursor = mouse::Cursor::Available(position);
+
+        self.dispatch(&[
+            Event::Mouse(mouse::Event::CursorMoved { position }),
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ]);
+    }
+
+    /// Types `text` into the focused text input, one key press per character.
+    pub fn type_text(&mut self, text: &str) {
+        let events: Vec<Event> = text
+            .chars()
+            .map(|character| {
+                let character = keyboard::key::Key::Character(character.to_string().into());
+
+                key_pressed(character, Modifiers::empty())
+            })
+            .collect();
+
+        self.dispatch(&events);
+    }
+
+    /// Presses and releases `key` while holding `modifiers`.
+    pub fn press_key(&mut self, key: impl Into<Key>, modifiers: Modifiers) {
+        let key = key.into();
+
+        self.dispatch(&[
+            key_pressed(key.clone(), modifiers),
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key,
+                location: Location::Standard,
+                modifiers,
+            }),
+        ]);
+    }
+
+    /// Sends `events` to the interface and the subscriptions, then updates the
+    /// gallery with the messages they produce.
+    pub fn dispatch(&mut self, events: &[Event]) {
+        let mut renderer = renderer();
+        let mut messages = Vec::new();
+
+        let mut interface = UserInterface::build(
+            self.gallery.view(),
+            self.size,
+            std::mem::take(&mut self.cache),
+            &mut renderer,
+        );
+
+        let (_, statuses) = interface.update(
+            events,
+            self.cursor,
+            &mut renderer,
+            &mut clipboard::Null,
+            &mut messages,
+        );
+
+        self.cache = interface.into_cache();
+
+        let window = self.window;
+        let input: Vec<subscription::Event> = events
+            .iter()
+            .cloned()
+            .zip(statuses)
+            .map(|(event, status)| subscription::Event::Interaction {
+                window,
+                event,
+                status,
+            })
+            .collect();
+
+        messages.extend(listen(&self.gallery, input));
+
+        let mut messages = VecDeque::from(messages);
+
+        while let Some(message) = messages.pop_front() {
+            let task = self.gallery.update(message);
+
+            messages.extend(self.run(task));
+        }
+    }
+
+    // Runs the widget operations of `task` and returns its messages. Only the
+    // actions which are ready are run, the task isn't waited on.
+    fn run(&mut self, task: Task<Message>) -> Vec<Message> {
+        let Some(mut actions) = task::into_stream(task) else {
+            return Vec::new();
+        };
+
+        let mut context = Context::from_waker(noop_waker_ref());
+        let mut messages = Vec::new();
+
+        while let Poll::Ready(Some(action)) = actions.poll_next_unpin(&mut context) {
+            match action {
+                Action::Output(message) => messages.push(message),
+                Action::Widget(operation) => self.operate(operation),
+                _ => (),
+            }
+        }
+
+        messages
+    }
+
+    fn operate(&mut self, operation: Box<dyn Operation>) {
+        let mut renderer = renderer();
+
+        let mut interface = UserInterface::build(
+            self.gallery.view(),
+            self.size,
+            std::mem::take(&mut self.cache),
+            &mut renderer,
+        );
+
+        let mut operation = Some(operation);
+
+        while let Some(mut current) = operation.take() {
+            interface.operate(&renderer, current.as_mut());
+
+            if let Outcome::Chain(next) = current.finish() {
+                operation = Some(next);
+            }
+        }
+
+        self.cache = interface.into_cache();
+    }
+
+    // The visible texts, with the layer they are drawn in
+    fn texts(&mut self) -> Vec<(Bounds, String)> {
+        let mut renderer = renderer();
+        let theme: Theme = self.gallery.theme();
+
+        let mut interface = UserInterface::build(
+            self.gallery.view(),
+            self.size,
+            std::mem::take(&mut self.cache),
+            &mut renderer,
+        );
+
+        let _ = interface.draw(
+            &mut renderer,
+            &theme,
+            &renderer::Style {
+                text_color: theme.default_style().text_color,
+            },
+            self.cursor,
+        );
+
+        let Renderer::Secondary(tiny_skia) = &mut renderer else {
+            unreachable!("the renderer was created as tiny-skia");
+        };
+
+        let window = Rectangle::with_size(self.size);
+        let mut texts = Vec::new();
+
+        for (layer, layer_texts) in tiny_skia.layers().iter().enumerate() {
+            let visible = layer_texts.bounds.intersection(&window);
+
+            for item in &layer_texts.text {
+                for text in item.as_slice() {
+                    let Some((rectangle, content)) = bounds_and_content(text) else {
+                        continue;
+                    };
+
+                    let rectangle = rectangle * item.transformation();
+
+                    if visible.is_some_and(|visible| visible.contains(rectangle.center())) {
+                        texts.push((Bounds { rectangle, layer }, content));
+                    }
+                }
+            }
+        }
+
+        // The paragraphs belong to the widget tree so it has to outlive the search
+        self.cache = interface.into_cache();
+
+        texts
+    }
+}
+
+// Looks for the widget with `id`, moving the bounds of the widgets in scrollables
+// by how far they are scrolled
+struct FindBounds {
+    id: widget::Id,
+    bounds: Option<Rectangle>,
+    translation: Vector,
+    // Translation of the scrollable reported last, which its content is moved by
+    scrolled: Vector,
+}
+
+impl Operation for FindBounds {
+    fn container(
+        &mut self,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        if id == Some(&self.id) {
+            self.bounds = Some(bounds - self.translation);
+        }
+
+        let scrolled = std::mem::take(&mut self.scrolled);
+
+        self.translation = self.translation + scrolled;
+        operate_on_children(self);
+        self.translation = self.translation - scrolled;
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn widget::operation::Scrollable,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+        _content_bounds: Rectangle,
+        translation: Vector,
+    ) {
+        if id == Some(&self.id) {
+            self.bounds = Some(bounds - self.translation);
+        }
+
+        self.scrolled = translation;
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Bounds {
+    rectangle: Rectangle,
+    layer: usize,
+}
+
+fn renderer() -> Renderer {
+    Renderer::Secondary(iced_tiny_skia::Renderer::new(Font::DEFAULT, Pixels(16.0)))
+}
+
+fn key_pressed(key: Key, modifiers: Modifiers) -> Event {
+    let text = match &key {
+        Key::Character(character) if !modifiers.control() => Some(character.clone()),
+        _ => None,
+    };
+
+    Event::Keyboard(keyboard::Event::KeyPressed {
+        modified_key: key.clone(),
+        key,
+        physical_key: Physical::Unidentified(NativeCode::Unidentified),
+        location: Location::Standard,
+        modifiers,
+        text,
+    })
+}
+
+// Runs the subscriptions of the gallery over `input`. The streams are polled until
+// they wait, so subscriptions which don't listen to events produce nothing.
+fn listen(gallery: &Gallery, input: Vec<subscription::Event>) -> Vec<Message> {
+    let mut context = Context::from_waker(noop_waker_ref());
+    let mut messages = Vec::new();
+
+    for recipe in subscription::into_recipes(gallery.subscription()) {
+        let input: EventStream = stream::iter(input.clone()).boxed();
+        let mut output = recipe.stream(input);
+
+        while let Poll::Ready(Some(message)) = output.poll_next_unpin(&mut context) {
+            messages.push(message);
+        }
+    }
+
+    messages
+}
+
+fn bounds_and_content(text: &Text) -> Option<(Rectangle, String)> {
+    match text {
+        Text::Paragraph {
+            paragraph,
+            position,
+            transformation,
+            ..
+        } => {
+            let bounds = align(
+                *position,
+                paragraph.min_bounds,
+                paragraph.horizontal_alignment,
+                paragraph.vertical_alignment,
+            );
+
+            let content = paragraph
+                .upgrade()?
+                .buffer()
+                .lines
+                .iter()
+                .map(|line| line.text())
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            Some((bounds * *transformation, content))
+        }
+        Text::Cached {
+            content,
+            bounds,
+            horizontal_alignment,
+            vertical_alignment,
+            ..
+        } => Some((
+            align(
+                bounds.position(),
+                bounds.size(),
+                *horizontal_alignment,
+                *vertical_alignment,
+            ),
+            content.clone(),
+        )),
+        Text::Editor { .. } | Text::Raw { .. } => None,
+    }
+}
+
+// Text is drawn from a point on its edge or in its middle, by its alignment
+fn align(position: Point, size: Size, horizontal: Horizontal, vertical: Vertical) -> Rectangle {
+    let x = match horizontal {
+        Horizontal::Left => position.x,
+        Horizontal::Center => position.x - size.width / 2.0,
+        Horizontal::Right => position.x - size.width,
+    };
+
+    let y = match vertical {
+        Vertical::Top => position.y,
+        Vertical::Center => position.y - size.height / 2.0,
+        Vertical::Bottom => position.y - size.height,
+    };
+
+    Rectangle::new(Point::new(x, y), size)
+}
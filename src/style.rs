@@ -1,16 +0,0 @@
-pub mod button;
-pub mod checkbox;
-pub mod combo_box;
-pub mod container;
-pub mod menu;
-pub mod menu_bar;
-pub mod number_input;
-pub mod pick_list;
-pub mod quad;
-pub mod radio;
-pub mod scrollable;
-pub mod slider;
-pub mod svg;
-pub mod text_input;
-pub mod toggler;
-pub mod underline;
\ No newline at end of file